@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: BSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! HMAC-SHA256 request signature verification.
+//!
+//! Only consulted when [`CfConfig::require_signature`](crate::CfConfig::require_signature)
+//! is `true`. Each agent is issued a shared secret (stored in the
+//! [`CfConfig::secret_kv_binding`](crate::CfConfig::secret_kv_binding) KV
+//! namespace, keyed by agent ID) and signs a canonical string built from the
+//! agent ID, HTTP method, path, millisecond timestamp, and a hash of the
+//! request body:
+//!
+//! ```text
+//! agent_id\nMETHOD\n/path\ntimestamp_ms\nbody_sha256_hex
+//! ```
+//!
+//! This mirrors S3-style presigned-request validation: the signature proves
+//! both the caller's identity and that the specific request wasn't altered
+//! or replayed outside the clock-skew window.
+
+use sha2::{Digest, Sha256};
+
+/// Build the canonical string an agent signs (and the middleware
+/// recomputes) for a given request.
+pub fn canonical_string(
+    agent_id: &str,
+    method: &str,
+    path: &str,
+    timestamp_ms: u64,
+    body_hash_hex: &str,
+) -> String {
+    format!("{}\n{}\n{}\n{}\n{}", agent_id, method, path, timestamp_ms, body_hash_hex)
+}
+
+/// SHA-256 of `body`, hex-encoded.
+pub fn hash_body(body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(body);
+    hex_encode(&hasher.finalize())
+}
+
+/// Compute the hex-encoded HMAC-SHA256 of `canonical` under `secret`.
+pub fn sign(secret: &str, canonical: &str) -> String {
+    use hmac::{Hmac, Mac};
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts a key of any length");
+    mac.update(canonical.as_bytes());
+    hex_encode(&mac.finalize().into_bytes())
+}
+
+/// Verify that `signature_hex` is the correct HMAC-SHA256 of `canonical`
+/// under `secret`, using a constant-time comparison.
+pub fn verify(secret: &str, canonical: &str, signature_hex: &str) -> bool {
+    let expected = sign(secret, canonical);
+    constant_time_eq(expected.as_bytes(), signature_hex.as_bytes())
+}
+
+/// Whether `timestamp_ms` falls within `max_skew_ms` of `now_ms`, in either
+/// direction. Bounds replay: a signed request can't be reused long after
+/// it was issued, and clock drift between caller and middleware doesn't
+/// spuriously reject requests.
+pub fn clock_skew_ok(timestamp_ms: u64, now_ms: u64, max_skew_ms: u64) -> bool {
+    let delta = if timestamp_ms >= now_ms {
+        timestamp_ms - now_ms
+    } else {
+        now_ms - timestamp_ms
+    };
+    delta <= max_skew_ms
+}
+
+/// Compare two byte slices in time proportional to their length, not their
+/// contents -- avoids leaking how many leading bytes of a guessed signature
+/// matched via a timing side channel.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        out.push_str(&format!("{:02x}", byte));
+    }
+    out
+}
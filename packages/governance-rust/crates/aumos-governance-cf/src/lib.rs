@@ -40,11 +40,28 @@
 //! - `budget_category` -- category name for the spending envelope
 //! - `budget_limit` -- maximum spend per period
 //! - `require_consent` -- whether the consent gate is enforced
+//! - `otlp_endpoint` -- OTLP collector endpoint; enables telemetry when set (`otel` feature)
+//! - `require_signature` -- whether `X-Agent-Signature` is required (see [`auth`])
+//! - `secret_kv_binding` -- KV namespace holding per-agent HMAC secrets
+//! - `max_clock_skew_ms` -- allowed drift between `X-Agent-Timestamp` and the server clock
+//! - `policies` -- per-action/path [`PolicyRule`] overrides of the flat defaults above
+//! - `wasm_policies` -- WASM policy plugins evaluated after the built-in gates pass (`wasm-policies` feature)
+//! - `role_scope_grants` -- role -> granted-scopes map consulted by [`query_audit`](CfGovernanceMiddleware::query_audit)
+//! - `breakglass_secret` -- operator secret for `X-Agent-Breakglass` tokens; `None` disables the feature
+//! - `breakglass_ceiling_trust_level` -- trust level a valid break-glass grant elevates to
+//! - `breakglass_max_ttl_ms` -- maximum TTL a break-glass grant may request
 //!
 //! ## Fire Line
 //!
 //! Trust levels stored in KV are set manually by operators. There is no
 //! automatic promotion, no behavioural analysis, and no adaptive logic.
+//!
+//! ## Observability
+//!
+//! Behind the `otel` feature, [`CfConfig::otlp_endpoint`] enables OpenTelemetry
+//! tracing and metrics for every evaluation -- see the [`telemetry`] module.
+
+use std::collections::{BTreeMap, BTreeSet};
 
 use aumos_governance_core::{
     config::Config,
@@ -52,8 +69,17 @@ use aumos_governance_core::{
     storage::InMemoryStorage,
     types::{AuditFilter, Context, TrustLevel},
 };
+#[cfg(feature = "otel")]
+use aumos_governance_core::types::Envelope;
+use aumos_governance_core::types::Decision;
 use serde::{Deserialize, Serialize};
 
+pub mod auth;
+#[cfg(feature = "wasm-policies")]
+pub mod plugins;
+#[cfg(feature = "otel")]
+pub mod telemetry;
+
 // ---------------------------------------------------------------------------
 // Configuration
 // ---------------------------------------------------------------------------
@@ -98,6 +124,180 @@ pub struct CfConfig {
     /// Whether the consent gate is enforced. Defaults to `false`.
     #[serde(default)]
     pub require_consent: bool,
+
+    /// OTLP collector endpoint (e.g. `"https://otel-collector.example.com:4317"`).
+    /// Only consulted when the `otel` feature is enabled; `None` disables
+    /// telemetry entirely. Defaults to `None`.
+    #[serde(default)]
+    pub otlp_endpoint: Option<String>,
+
+    /// Whether requests must carry a valid `X-Agent-Signature` (see [`auth`]).
+    /// Defaults to `false` so existing deployments are unaffected.
+    #[serde(default)]
+    pub require_signature: bool,
+
+    /// Name of the KV namespace binding that stores per-agent HMAC secrets,
+    /// keyed by agent ID. Only consulted when `require_signature` is `true`.
+    /// Defaults to `"AGENT_SECRETS"`.
+    #[serde(default = "default_secret_kv_binding")]
+    pub secret_kv_binding: String,
+
+    /// Maximum allowed difference, in milliseconds, between a signed
+    /// request's `X-Agent-Timestamp` and the middleware's clock, in either
+    /// direction. Bounds replay of an otherwise-valid signature. Defaults to
+    /// `300_000` (±300s).
+    #[serde(default = "default_max_clock_skew_ms")]
+    pub max_clock_skew_ms: u64,
+
+    /// Per-action/path policy overrides, evaluated before falling back to
+    /// `required_trust_level`/`budget_category`/`budget_limit`/`require_consent`
+    /// above. See [`PolicyRule`]. Defaults to empty (flat config governs
+    /// every action).
+    #[serde(default)]
+    pub policies: Vec<PolicyRule>,
+
+    /// WASM policy plugins, invoked in order after the built-in trust/budget/
+    /// consent gates pass. Only consulted when the `wasm-policies` feature is
+    /// enabled. Defaults to empty.
+    #[cfg(feature = "wasm-policies")]
+    #[serde(default)]
+    pub wasm_policies: Vec<plugins::WasmPolicyConfig>,
+
+    /// Maps a role name to the scopes it may view via
+    /// [`query_audit`](CfGovernanceMiddleware::query_audit). A grant of
+    /// `"*"` permits every scope. The built-in `"admin"` role always sees
+    /// every record, regardless of this map. Defaults to empty, so callers
+    /// with no matching role fall back to their own agent scope.
+    #[serde(default)]
+    pub role_scope_grants: BTreeMap<String, Vec<String>>,
+
+    /// Shared secret operators sign break-glass tokens with (see
+    /// [`CfGovernanceMiddleware::authenticate_breakglass`]). `None` (the
+    /// default) disables the break-glass path entirely -- every
+    /// `X-Agent-Breakglass` header is then rejected.
+    #[serde(default)]
+    pub breakglass_secret: Option<String>,
+
+    /// Trust level a valid break-glass grant temporarily elevates the agent
+    /// to, expressed as a `u8` discriminant (`0..=5`). Defaults to `5`
+    /// (Autonomous).
+    #[serde(default = "default_breakglass_ceiling_trust_level")]
+    pub breakglass_ceiling_trust_level: u8,
+
+    /// Maximum TTL, in milliseconds, a break-glass grant may request.
+    /// Defaults to `900_000` (15 minutes).
+    #[serde(default = "default_breakglass_max_ttl_ms")]
+    pub breakglass_max_ttl_ms: u64,
+}
+
+impl CfConfig {
+    /// The set of scopes granted to a caller holding `roles`, per
+    /// `role_scope_grants`.
+    fn granted_scopes(&self, roles: &[String]) -> BTreeSet<String> {
+        let mut scopes = BTreeSet::new();
+        for role in roles {
+            if let Some(role_scopes) = self.role_scope_grants.get(role) {
+                scopes.extend(role_scopes.iter().cloned());
+            }
+        }
+        scopes
+    }
+}
+
+/// Identifies the caller of [`CfGovernanceMiddleware::query_audit`], used to
+/// scope which audit records they may see.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewerContext {
+    /// Stable identifier for the caller (an operator or an agent acting on
+    /// its own behalf). When no role grants any scope, the caller sees only
+    /// records whose `agent_id` equals this value.
+    pub principal: String,
+    /// Roles held by the caller, matched against `CfConfig::role_scope_grants`.
+    /// The `"admin"` role bypasses scope filtering entirely.
+    pub roles: Vec<String>,
+}
+
+/// A validated break-glass trust elevation, produced by
+/// [`CfGovernanceMiddleware::authenticate_breakglass`] and consumed by
+/// [`CfGovernanceMiddleware::evaluate_agent_with_breakglass`].
+#[derive(Debug, Clone)]
+pub struct BreakglassGrant {
+    /// Identifier of the operator who issued the grant (signed the token).
+    pub granted_by: String,
+    /// When the grant was issued, in epoch milliseconds.
+    pub issued_at_ms: u64,
+    /// How long the grant remains valid from `issued_at_ms`, in milliseconds.
+    pub ttl_ms: u64,
+}
+
+/// A declarative override of the flat [`CfConfig`] defaults for actions
+/// matching `path_prefix` and/or `action_glob`.
+///
+/// When more than one rule matches a request, [`CfGovernanceMiddleware`]
+/// picks the most specific: the rule with the longest `path_prefix` wins,
+/// and an exact (non-wildcard) `action_glob` match breaks ties over a
+/// wildcard one. A rule with both fields `None` matches everything but
+/// loses to any more specific rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyRule {
+    /// Only matches actions whose path starts with this prefix (e.g.
+    /// `"/admin"`). `None` matches any path.
+    #[serde(default)]
+    pub path_prefix: Option<String>,
+
+    /// Only matches actions whose name matches this glob (a single `*`
+    /// wildcard is supported, e.g. `"delete_*"`). `None` matches any action.
+    #[serde(default)]
+    pub action_glob: Option<String>,
+
+    /// Minimum trust level required by this rule. Expressed as a `u8`
+    /// discriminant (`0..=5`).
+    pub required_trust_level: u8,
+
+    /// Budget envelope category charged by actions matching this rule.
+    pub budget_category: String,
+
+    /// Maximum spend per period for `budget_category`'s envelope.
+    pub budget_limit: f64,
+
+    /// Whether the consent gate is enforced for actions matching this rule.
+    #[serde(default)]
+    pub require_consent: bool,
+}
+
+impl PolicyRule {
+    fn matches(&self, action: &str) -> bool {
+        let path_matches = match &self.path_prefix {
+            Some(prefix) => action.starts_with(prefix.as_str()),
+            None => true,
+        };
+        let action_matches = match &self.action_glob {
+            Some(glob) => glob_match(glob, action),
+            None => true,
+        };
+        path_matches && action_matches
+    }
+
+    /// Specificity key for breaking ties between matching rules: a longer
+    /// `path_prefix` wins, then an exact (non-wildcard) `action_glob` match
+    /// wins over a wildcard one.
+    fn specificity(&self, action: &str) -> (usize, bool) {
+        let prefix_len = self.path_prefix.as_deref().map(str::len).unwrap_or(0);
+        let exact_action = self.action_glob.as_deref() == Some(action);
+        (prefix_len, exact_action)
+    }
+}
+
+/// Match `text` against a glob pattern containing at most one `*` wildcard.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((prefix, suffix)) => {
+            text.len() >= prefix.len() + suffix.len()
+                && text.starts_with(prefix)
+                && text.ends_with(suffix)
+        }
+    }
 }
 
 fn default_required_trust() -> u8 {
@@ -108,10 +308,26 @@ fn default_budget_category() -> String {
     "api-calls".to_string()
 }
 
+fn default_secret_kv_binding() -> String {
+    "AGENT_SECRETS".to_string()
+}
+
+fn default_max_clock_skew_ms() -> u64 {
+    300_000
+}
+
 fn default_budget_limit() -> f64 {
     1000.0
 }
 
+fn default_breakglass_ceiling_trust_level() -> u8 {
+    5
+}
+
+fn default_breakglass_max_ttl_ms() -> u64 {
+    900_000
+}
+
 impl Default for CfConfig {
     fn default() -> Self {
         Self {
@@ -121,6 +337,17 @@ impl Default for CfConfig {
             budget_category: default_budget_category(),
             budget_limit: default_budget_limit(),
             require_consent: false,
+            otlp_endpoint: None,
+            require_signature: false,
+            secret_kv_binding: default_secret_kv_binding(),
+            max_clock_skew_ms: default_max_clock_skew_ms(),
+            policies: Vec::new(),
+            #[cfg(feature = "wasm-policies")]
+            wasm_policies: Vec::new(),
+            role_scope_grants: BTreeMap::new(),
+            breakglass_secret: None,
+            breakglass_ceiling_trust_level: default_breakglass_ceiling_trust_level(),
+            breakglass_max_ttl_ms: default_breakglass_max_ttl_ms(),
         }
     }
 }
@@ -149,6 +376,8 @@ impl Default for CfConfig {
 pub struct CfGovernanceMiddleware {
     config: CfConfig,
     engine: GovernanceEngine<InMemoryStorage>,
+    #[cfg(feature = "otel")]
+    telemetry: Option<telemetry::Telemetry>,
 }
 
 /// The result of middleware evaluation.
@@ -161,6 +390,11 @@ pub enum MiddlewareDecision {
         agent_trust_level: u8,
         /// Human-readable reason.
         reason: String,
+        /// `true` if this permit was granted under a break-glass trust
+        /// elevation (see [`CfGovernanceMiddleware::authenticate_breakglass`])
+        /// rather than the agent's ordinary trust level.
+        #[serde(default)]
+        elevated: bool,
     },
     /// The request is denied.
     Deny {
@@ -178,6 +412,14 @@ pub enum MiddlewareDecision {
         /// Human-readable reason.
         reason: String,
     },
+    /// The request's signature is missing, malformed, expired, or invalid.
+    /// Only produced when [`CfConfig::require_signature`] is `true`.
+    Unauthenticated {
+        /// HTTP status code to return (always 401).
+        status: u16,
+        /// Human-readable reason.
+        reason: String,
+    },
 }
 
 impl CfGovernanceMiddleware {
@@ -190,25 +432,188 @@ impl CfGovernanceMiddleware {
             require_consent: config.require_consent,
             default_observer_on_missing: true,
             pass_on_missing_envelope: true,
+            ..Config::default()
         };
 
         let mut engine = GovernanceEngine::new(engine_config, InMemoryStorage::new());
 
-        // Pre-create the budget envelope from config.
+        // Pre-create the budget envelope from config, plus one per distinct
+        // policy-rule category -- a matched rule may charge a category the
+        // flat defaults never mention.
         engine.budget.create_envelope(
             &config.budget_category,
             config.budget_limit,
             86_400_000, // 24-hour period
             0,
         );
+        for rule in &config.policies {
+            engine.budget.create_envelope(
+                &rule.budget_category,
+                rule.budget_limit,
+                86_400_000,
+                0,
+            );
+        }
+
+        #[cfg(feature = "otel")]
+        let telemetry = config.otlp_endpoint.as_deref().map(telemetry::Telemetry::init);
+
+        Self {
+            config,
+            engine,
+            #[cfg(feature = "otel")]
+            telemetry,
+        }
+    }
+
+    /// Verify a request's HMAC signature, per [`auth`].
+    ///
+    /// A no-op (always `Ok`) when `config.require_signature` is `false`, so
+    /// deployments that haven't provisioned per-agent secrets are unaffected.
+    /// Otherwise checks the clock-skew window first (cheaper, and avoids
+    /// doing HMAC work for an obviously-expired request), then recomputes the
+    /// HMAC over the canonical string and compares it to `signature_hex` in
+    /// constant time.
+    ///
+    /// # Arguments
+    ///
+    /// * `agent_id` -- stable agent identifier extracted from the request
+    /// * `agent_secret` -- the agent's shared secret, looked up from
+    ///   [`CfConfig::secret_kv_binding`]
+    /// * `method` / `path` -- the HTTP method and path covered by the signature
+    /// * `timestamp_ms` -- the request's `X-Agent-Timestamp` header value
+    /// * `body` -- the raw request body, hashed into the canonical string
+    /// * `signature_hex` -- the request's `X-Agent-Signature` header value
+    /// * `now_ms` -- the middleware's current clock, for skew validation
+    pub fn authenticate_signature(
+        &self,
+        agent_id: &str,
+        agent_secret: &str,
+        method: &str,
+        path: &str,
+        timestamp_ms: u64,
+        body: &[u8],
+        signature_hex: &str,
+        now_ms: u64,
+    ) -> Result<(), MiddlewareDecision> {
+        if !self.config.require_signature {
+            return Ok(());
+        }
+
+        if !auth::clock_skew_ok(timestamp_ms, now_ms, self.config.max_clock_skew_ms) {
+            return Err(MiddlewareDecision::Unauthenticated {
+                status: 401,
+                reason: format!(
+                    "Request timestamp is outside the allowed clock-skew window of {}ms.",
+                    self.config.max_clock_skew_ms
+                ),
+            });
+        }
 
-        Self { config, engine }
+        let body_hash = auth::hash_body(body);
+        let canonical = auth::canonical_string(agent_id, method, path, timestamp_ms, &body_hash);
+
+        if auth::verify(agent_secret, &canonical, signature_hex) {
+            Ok(())
+        } else {
+            Err(MiddlewareDecision::Unauthenticated {
+                status: 401,
+                reason: "Signature verification failed.".to_string(),
+            })
+        }
+    }
+
+    /// Validate a break-glass trust-elevation token (`X-Agent-Breakglass`).
+    ///
+    /// Lets an operator grant an agent a bounded, independently-audited
+    /// trust elevation during an incident, without editing trust KV directly.
+    /// The token is four colon-separated fields: `granted_by`, `issued_at_ms`,
+    /// `ttl_ms`, and a hex HMAC-SHA256 signature (via [`auth::sign`]) over the
+    /// canonical string `agent_id\ngranted_by\nissued_at_ms\nttl_ms`, keyed by
+    /// [`CfConfig::breakglass_secret`].
+    ///
+    /// Returns `Err` (as a [`MiddlewareDecision::Unauthenticated`]) when the
+    /// feature is disabled (`breakglass_secret` unset), the token is
+    /// malformed, its TTL exceeds `breakglass_max_ttl_ms`, `now_ms` falls
+    /// outside `[issued_at_ms, issued_at_ms + ttl_ms]`, or the signature does
+    /// not verify.
+    pub fn authenticate_breakglass(
+        &self,
+        agent_id: &str,
+        token: &str,
+        now_ms: u64,
+    ) -> Result<BreakglassGrant, MiddlewareDecision> {
+        let secret = self.config.breakglass_secret.as_deref().ok_or_else(|| {
+            MiddlewareDecision::Unauthenticated {
+                status: 401,
+                reason: "Break-glass trust elevation is not configured.".to_string(),
+            }
+        })?;
+
+        let mut parts = token.splitn(4, ':');
+        let (granted_by, issued_at_ms, ttl_ms, signature_hex) =
+            match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                (Some(granted_by), Some(issued_at_ms), Some(ttl_ms), Some(signature_hex)) => {
+                    (granted_by, issued_at_ms, ttl_ms, signature_hex)
+                }
+                _ => {
+                    return Err(MiddlewareDecision::Unauthenticated {
+                        status: 401,
+                        reason: "Break-glass token is malformed.".to_string(),
+                    })
+                }
+            };
+
+        let issued_at_ms: u64 = issued_at_ms.parse().map_err(|_| {
+            MiddlewareDecision::Unauthenticated {
+                status: 401,
+                reason: "Break-glass token has a malformed issued_at_ms field.".to_string(),
+            }
+        })?;
+        let ttl_ms: u64 = ttl_ms.parse().map_err(|_| MiddlewareDecision::Unauthenticated {
+            status: 401,
+            reason: "Break-glass token has a malformed ttl_ms field.".to_string(),
+        })?;
+
+        if ttl_ms > self.config.breakglass_max_ttl_ms {
+            return Err(MiddlewareDecision::Unauthenticated {
+                status: 401,
+                reason: format!(
+                    "Break-glass token TTL of {}ms exceeds the configured maximum of {}ms.",
+                    ttl_ms, self.config.breakglass_max_ttl_ms
+                ),
+            });
+        }
+
+        let expires_at_ms = issued_at_ms.saturating_add(ttl_ms);
+        if now_ms < issued_at_ms || now_ms > expires_at_ms {
+            return Err(MiddlewareDecision::Unauthenticated {
+                status: 401,
+                reason: "Break-glass token has expired or is not yet valid.".to_string(),
+            });
+        }
+
+        let canonical = format!("{agent_id}\n{granted_by}\n{issued_at_ms}\n{ttl_ms}");
+        if !auth::verify(secret, &canonical, signature_hex) {
+            return Err(MiddlewareDecision::Unauthenticated {
+                status: 401,
+                reason: "Break-glass token signature verification failed.".to_string(),
+            });
+        }
+
+        Ok(BreakglassGrant {
+            granted_by: granted_by.to_string(),
+            issued_at_ms,
+            ttl_ms,
+        })
     }
 
     /// Evaluate an agent's request against the governance policy.
     ///
     /// This is the core logic, usable both in native tests and within the
-    /// Cloudflare Workers `cf-worker` feature path.
+    /// Cloudflare Workers `cf-worker` feature path. A thin shim over
+    /// [`evaluate_agent_with_breakglass`](Self::evaluate_agent_with_breakglass)
+    /// with no break-glass grant.
     ///
     /// # Arguments
     ///
@@ -223,12 +628,67 @@ impl CfGovernanceMiddleware {
         estimated_cost: f64,
         action: &str,
     ) -> MiddlewareDecision {
+        self.evaluate_agent_with_breakglass(agent_id, trust_level_value, estimated_cost, action, None)
+    }
+
+    /// Evaluate an agent's request against the governance policy, optionally
+    /// under a validated break-glass trust elevation.
+    ///
+    /// When `breakglass` is `Some`, the agent's effective trust level is
+    /// temporarily raised to `config.breakglass_ceiling_trust_level` for this
+    /// evaluation only -- the KV-sourced `trust_level_value` is never mutated.
+    /// If the resulting decision is a permit, it is tagged `elevated: true`
+    /// and a distinguished `BREAKGLASS_USED` record is written to the audit
+    /// trail, capturing the granting operator, the grant's TTL, and the
+    /// agent's original (unelevated) trust level, so every emergency override
+    /// is independently reviewable.
+    ///
+    /// # Arguments
+    ///
+    /// * `agent_id` -- stable agent identifier extracted from the request
+    /// * `trust_level_value` -- the agent's trust level as a `u8` (from KV)
+    /// * `estimated_cost` -- estimated cost of the request
+    /// * `action` -- human-readable action name
+    /// * `breakglass` -- a grant from [`authenticate_breakglass`](Self::authenticate_breakglass), if any
+    pub fn evaluate_agent_with_breakglass(
+        &mut self,
+        agent_id: &str,
+        trust_level_value: u8,
+        estimated_cost: f64,
+        action: &str,
+        breakglass: Option<BreakglassGrant>,
+    ) -> MiddlewareDecision {
+        #[cfg(feature = "otel")]
+        let started_at = std::time::Instant::now();
+
         // Resolve trust level from the raw u8, falling back to Observer.
-        let trust_level = TrustLevel::from_u8(trust_level_value)
+        let original_trust_level = TrustLevel::from_u8(trust_level_value)
             .unwrap_or(TrustLevel::Observer);
+        let trust_level = match &breakglass {
+            Some(_) => TrustLevel::from_u8(self.config.breakglass_ceiling_trust_level)
+                .unwrap_or(original_trust_level),
+            None => original_trust_level,
+        };
 
-        let required = TrustLevel::from_u8(self.config.required_trust_level)
-            .unwrap_or(TrustLevel::Suggest);
+        // Select the most specific policy rule for this action, falling back
+        // to the flat config defaults when nothing matches.
+        let rule = self
+            .config
+            .policies
+            .iter()
+            .filter(|rule| rule.matches(action))
+            .max_by_key(|rule| rule.specificity(action));
+
+        let (required_trust_level, budget_category, require_consent) = match rule {
+            Some(rule) => (rule.required_trust_level, rule.budget_category.as_str(), rule.require_consent),
+            None => (
+                self.config.required_trust_level,
+                self.config.budget_category.as_str(),
+                self.config.require_consent,
+            ),
+        };
+
+        let required = TrustLevel::from_u8(required_trust_level).unwrap_or(TrustLevel::Suggest);
 
         // Set the agent's trust level in the engine (manual assignment).
         self.engine.trust.set_level(agent_id, "default", trust_level, "kv-lookup");
@@ -242,33 +702,195 @@ impl CfGovernanceMiddleware {
             } else {
                 None
             },
-            category: self.config.budget_category.clone(),
-            data_type: None,
+            category: budget_category.to_string(),
+            data_type: if require_consent {
+                Some(action.to_string())
+            } else {
+                None
+            },
             purpose: None,
         };
 
         let decision = self.engine.check(action, &context);
 
-        if decision.permitted {
+        let result = if decision.permitted {
             MiddlewareDecision::Allow {
                 agent_trust_level: trust_level as u8,
-                reason: decision.reason,
+                reason: decision.reason.clone(),
+                elevated: breakglass.is_some(),
             }
         } else {
             MiddlewareDecision::Deny {
                 status: 403,
                 code: "GOVERNANCE_DENIED".to_string(),
-                reason: decision.reason,
+                reason: decision.reason.clone(),
+            }
+        };
+
+        #[cfg(feature = "wasm-policies")]
+        let result = self.evaluate_wasm_policies(action, &context, &decision, result);
+
+        if let (Some(grant), true) = (&breakglass, matches!(result, MiddlewareDecision::Allow { .. })) {
+            self.engine.audit.log(Decision {
+                permitted: true,
+                action: format!("{action}:breakglass"),
+                reason: format!(
+                    "BREAKGLASS_USED: granted_by={}, ttl_ms={}, original_trust_level={}",
+                    grant.granted_by, grant.ttl_ms, original_trust_level as u8
+                ),
+                ..decision.clone()
+            });
+        }
+
+        #[cfg(feature = "otel")]
+        if let Some(telemetry) = &self.telemetry {
+            let elapsed_ms = started_at.elapsed().as_secs_f64() * 1000.0;
+            let (outcome, code) = match &result {
+                MiddlewareDecision::Allow { .. } => ("allow", "OK"),
+                MiddlewareDecision::Deny { code, .. } => ("deny", code.as_str()),
+                MiddlewareDecision::MissingAgent { .. } => ("missing_agent", "MISSING_AGENT"),
+                MiddlewareDecision::Unauthenticated { .. } => ("unauthenticated", "UNAUTHENTICATED"),
+            };
+            telemetry.record_decision(agent_id, trust_level as u8, action, outcome, code, elapsed_ms);
+            if let Some(envelope) = self.engine.budget.get_envelope(budget_category) {
+                let remaining = envelope.available(Envelope::DEFAULT_DIMENSION);
+                telemetry.record_budget_remaining(budget_category, remaining);
             }
         }
+
+        result
     }
 
-    /// Query the audit trail of governance decisions.
+    /// Compile and cache a WASM policy module's bytes under `name`, in the
+    /// process-wide [`plugins::runtime`] cache. Only available under the
+    /// `wasm-policies` feature.
+    #[cfg(feature = "wasm-policies")]
+    pub fn register_plugin_module(&self, name: &str, bytes: &[u8]) -> Result<(), String> {
+        let mut runtime = plugins::runtime()
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        runtime.register(name, bytes)
+    }
+
+    /// Invoke each configured [`CfConfig::wasm_policies`] plugin, in order,
+    /// against `context`. Only called once the built-in trust/budget/consent
+    /// gates have already permitted the action -- a plugin can still veto
+    /// (`Deny`) or override the reported trust level (`Mutate`) of an
+    /// already-permitted decision, but it never runs against one the built-in
+    /// gates already denied. A `Mutate` cost is written to the audit record
+    /// only; the budget already charged by the built-in gate is not adjusted. The first `Deny` (or plugin error) short-circuits the
+    /// remaining plugins. Every plugin invocation is recorded to the audit
+    /// trail, tagged with the plugin's name.
+    #[cfg(feature = "wasm-policies")]
+    fn evaluate_wasm_policies(
+        &mut self,
+        action: &str,
+        context: &Context,
+        gate_decision: &Decision,
+        result: MiddlewareDecision,
+    ) -> MiddlewareDecision {
+        if self.config.wasm_policies.is_empty() || !matches!(result, MiddlewareDecision::Allow { .. }) {
+            return result;
+        }
+
+        let context_json = match serde_json::to_string(context) {
+            Ok(json) => json,
+            Err(_) => return result,
+        };
+
+        let mut result = result;
+        for plugin in &self.config.wasm_policies {
+            let verdict = {
+                let runtime = plugins::runtime()
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+                runtime.evaluate(&plugin.name, &context_json, plugin.fuel_limit)
+            };
+
+            let audit_reason = match &verdict {
+                Ok(plugins::PluginDecision::Allow) => {
+                    format!("Plugin '{}' allowed.", plugin.name)
+                }
+                Ok(plugins::PluginDecision::Deny { reason }) => {
+                    result = MiddlewareDecision::Deny {
+                        status: 403,
+                        code: "PLUGIN_DENIED".to_string(),
+                        reason: format!("Plugin '{}' denied: {}", plugin.name, reason),
+                    };
+                    format!("Plugin '{}' denied: {}", plugin.name, reason)
+                }
+                Ok(plugins::PluginDecision::Mutate { trust_level, cost }) => {
+                    if let MiddlewareDecision::Allow { agent_trust_level, reason, .. } = &mut result {
+                        if let Some(level) = trust_level {
+                            *agent_trust_level = *level;
+                        }
+                        *reason = format!("{} (mutated by plugin '{}')", reason, plugin.name);
+                    }
+                    format!(
+                        "Plugin '{}' mutated trust_level={:?} cost={:?}.",
+                        plugin.name, trust_level, cost
+                    )
+                }
+                Err(error) => {
+                    result = MiddlewareDecision::Deny {
+                        status: 403,
+                        code: "PLUGIN_ERROR".to_string(),
+                        reason: format!("Plugin '{}' failed: {}", plugin.name, error),
+                    };
+                    format!("Plugin '{}' failed: {}", plugin.name, error)
+                }
+            };
+
+            self.engine.audit.log(Decision {
+                permitted: matches!(result, MiddlewareDecision::Allow { .. }),
+                action: format!("{action}:plugin:{}", plugin.name),
+                reason: audit_reason,
+                ..gate_decision.clone()
+            });
+
+            if !matches!(result, MiddlewareDecision::Allow { .. }) {
+                break;
+            }
+        }
+
+        result
+    }
+
+    /// Query the audit trail of governance decisions, scoped to what `viewer`
+    /// is authorised to see.
+    ///
+    /// The `"admin"` role sees every record unfiltered. Otherwise, the
+    /// viewer's roles are resolved to a set of granted scopes via
+    /// `CfConfig::role_scope_grants` (a grant of `"*"` permits every scope);
+    /// records are kept only if their `Decision::scope` is in that set. When
+    /// no role grants any scope, the viewer falls back to seeing only
+    /// records whose `Decision::agent_id` matches their own `principal` --
+    /// i.e. their own activity, regardless of scope.
     ///
     /// Returns a JSON-serialisable vector of audit records.
-    pub fn query_audit(&self, filter: &AuditFilter) -> String {
+    pub fn query_audit(&self, viewer: &ViewerContext, filter: &AuditFilter) -> String {
         let records = self.engine.audit.query(filter);
-        serde_json::to_string(&records).unwrap_or_else(|_| "[]".to_string())
+
+        let visible = if viewer.roles.iter().any(|role| role == "admin") {
+            records
+        } else {
+            let granted_scopes = self.config.granted_scopes(&viewer.roles);
+            if granted_scopes.is_empty() {
+                records
+                    .into_iter()
+                    .filter(|record| record.decision.agent_id == viewer.principal)
+                    .collect()
+            } else if granted_scopes.contains("*") {
+                records
+            } else {
+                records
+                    .into_iter()
+                    .filter(|record| granted_scopes.contains(&record.decision.scope))
+                    .collect()
+            }
+        };
+
+        serde_json::to_string(&visible).unwrap_or_else(|_| "[]".to_string())
     }
 
     /// Access the current configuration.
@@ -289,9 +911,17 @@ impl CfGovernanceMiddleware {
 /// # Protocol
 ///
 /// 1. Extract `X-Agent-Id` header from the request.
-/// 2. Look up the agent's trust level from the configured KV namespace.
-/// 3. Evaluate the governance engine.
-/// 4. Return `403` JSON on deny, or proxy to origin on allow.
+/// 2. If `config.require_signature` is set, verify `X-Agent-Signature` against
+///    the agent's secret from `config.secret_kv_binding`.
+/// 3. Look up the agent's trust level from the configured KV namespace.
+/// 4. If the request carries an `X-Agent-Breakglass` header, validate it
+///    against `config.breakglass_secret`.
+/// 5. If any `config.wasm_policies` are registered, load each module's bytes
+///    (from KV or a URL, per its `source`) and compile it into the
+///    process-wide plugin cache (`wasm-policies` feature).
+/// 6. Evaluate the governance engine, including any WASM policy plugins and
+///    any break-glass trust elevation.
+/// 7. Return `401`/`403` JSON on deny, or proxy to origin on allow.
 ///
 /// # Errors
 ///
@@ -299,7 +929,7 @@ impl CfGovernanceMiddleware {
 /// constructed.
 #[cfg(feature = "cf-worker")]
 pub async fn handle_request(
-    req: worker::Request,
+    mut req: worker::Request,
     env: worker::Env,
     config: &CfConfig,
 ) -> worker::Result<worker::Response> {
@@ -317,19 +947,131 @@ pub async fn handle_request(
         }
     };
 
-    // Step 2: Look up trust level from Cloudflare KV.
+    let mut middleware = CfGovernanceMiddleware::new(config.clone());
+    let action = req.path();
+
+    // Step 2: Verify the request signature, if required.
+    if config.require_signature {
+        let signature = req.headers().get("X-Agent-Signature")?;
+        let timestamp = req.headers().get("X-Agent-Timestamp")?;
+        let (signature, timestamp) = match (signature, timestamp) {
+            (Some(signature), Some(timestamp)) => (signature, timestamp),
+            _ => {
+                let decision = MiddlewareDecision::Unauthenticated {
+                    status: 401,
+                    reason: "Missing X-Agent-Signature or X-Agent-Timestamp header".to_string(),
+                };
+                return worker::Response::from_json(&decision).map(|resp| resp.with_status(401));
+            }
+        };
+        let timestamp_ms: u64 = match timestamp.parse() {
+            Ok(value) => value,
+            Err(_) => {
+                let decision = MiddlewareDecision::Unauthenticated {
+                    status: 401,
+                    reason: "X-Agent-Timestamp is not a valid millisecond timestamp".to_string(),
+                };
+                return worker::Response::from_json(&decision).map(|resp| resp.with_status(401));
+            }
+        };
+
+        let secrets_kv = env.kv(&config.secret_kv_binding)?;
+        let secret = match secrets_kv.get(&agent_id).text().await? {
+            Some(secret) => secret,
+            None => {
+                let decision = MiddlewareDecision::Unauthenticated {
+                    status: 401,
+                    reason: format!("No signing secret configured for agent '{}'", agent_id),
+                };
+                return worker::Response::from_json(&decision).map(|resp| resp.with_status(401));
+            }
+        };
+
+        let body = req.bytes().await?;
+        let now_ms = worker::Date::now().as_millis();
+        if let Err(MiddlewareDecision::Unauthenticated { status, reason }) = middleware
+            .authenticate_signature(
+                &agent_id,
+                &secret,
+                req.method().to_string().as_str(),
+                &action,
+                timestamp_ms,
+                &body,
+                &signature,
+                now_ms,
+            )
+        {
+            let decision = MiddlewareDecision::Unauthenticated { status, reason };
+            return worker::Response::from_json(&decision).map(|resp| resp.with_status(status));
+        }
+    }
+
+    // Step 3: Look up trust level from Cloudflare KV.
     let kv = env.kv(&config.trust_kv_binding)?;
     let trust_value: u8 = match kv.get(&agent_id).text().await? {
         Some(value) => value.parse::<u8>().unwrap_or(config.default_trust_level),
         None => config.default_trust_level,
     };
 
-    // Step 3: Evaluate governance.
-    let mut middleware = CfGovernanceMiddleware::new(config.clone());
-    let action = req.path();
-    let decision = middleware.evaluate_agent(&agent_id, trust_value, 1.0, &action);
+    // Step 4: Validate a break-glass trust elevation, if the request carries
+    // one (skipped entirely when the header is absent).
+    let mut breakglass = None;
+    if let Some(token) = req.headers().get("X-Agent-Breakglass")? {
+        let now_ms = worker::Date::now().as_millis();
+        match middleware.authenticate_breakglass(&agent_id, &token, now_ms) {
+            Ok(grant) => breakglass = Some(grant),
+            Err(MiddlewareDecision::Unauthenticated { status, reason }) => {
+                let decision = MiddlewareDecision::Unauthenticated { status, reason };
+                return worker::Response::from_json(&decision).map(|resp| resp.with_status(status));
+            }
+            Err(decision) => return worker::Response::from_json(&decision),
+        }
+    }
+
+    // Step 5: Load any configured WASM policy plugins into the process-wide
+    // compiled-module cache (skipped entirely if none are configured).
+    #[cfg(feature = "wasm-policies")]
+    for plugin in &config.wasm_policies {
+        let bytes = match &plugin.source {
+            plugins::PluginSource::Kv { binding, key } => {
+                let kv = env.kv(binding)?;
+                match kv.get(key).bytes().await? {
+                    Some(bytes) => bytes,
+                    None => {
+                        let decision = MiddlewareDecision::Deny {
+                            status: 403,
+                            code: "PLUGIN_UNAVAILABLE".to_string(),
+                            reason: format!(
+                                "Plugin module '{}' not found at KV key '{}'",
+                                plugin.name, key
+                            ),
+                        };
+                        return worker::Response::from_json(&decision)
+                            .map(|resp| resp.with_status(403));
+                    }
+                }
+            }
+            plugins::PluginSource::Url { url } => {
+                let mut response = worker::Fetch::Url(url.parse()?).send().await?;
+                response.bytes().await?
+            }
+        };
+        if let Err(error) = middleware.register_plugin_module(&plugin.name, &bytes) {
+            let decision = MiddlewareDecision::Deny {
+                status: 403,
+                code: "PLUGIN_UNAVAILABLE".to_string(),
+                reason: format!("Plugin module '{}' failed to compile: {}", plugin.name, error),
+            };
+            return worker::Response::from_json(&decision).map(|resp| resp.with_status(403));
+        }
+    }
+
+    // Step 6: Evaluate governance, including any WASM policy plugins and any
+    // break-glass trust elevation.
+    let decision =
+        middleware.evaluate_agent_with_breakglass(&agent_id, trust_value, 1.0, &action, breakglass);
 
-    // Step 4: Return result.
+    // Step 7: Return result.
     match decision {
         MiddlewareDecision::Allow { .. } => {
             // In a real deployment, this would proxy to the origin using
@@ -345,6 +1087,10 @@ pub async fn handle_request(
             worker::Response::from_json(&decision)
                 .map(|resp| resp.with_status(status))
         }
+        MiddlewareDecision::Unauthenticated { status, .. } => {
+            worker::Response::from_json(&decision)
+                .map(|resp| resp.with_status(status))
+        }
     }
 }
 
@@ -420,8 +1166,85 @@ mod tests {
         let config = CfConfig::default();
         let mut middleware = CfGovernanceMiddleware::new(config);
         let _ = middleware.evaluate_agent("agent-001", 3, 1.0, "test_action");
-        let trail = middleware.query_audit(&AuditFilter::default());
+        let admin = ViewerContext { principal: "agent-001".to_string(), roles: vec!["admin".to_string()] };
+        let trail = middleware.query_audit(&admin, &AuditFilter::default());
+        assert_ne!(trail, "[]");
+    }
+
+    #[test]
+    fn test_query_audit_admin_role_sees_everything() {
+        let config = CfConfig::default();
+        let mut middleware = CfGovernanceMiddleware::new(config);
+        middleware.engine.trust.set_level("agent-001", "tenant-a", TrustLevel::ActAndReport, "owner");
+        let _ = middleware.evaluate_agent("agent-001", 4, 0.0, "read_data");
+
+        let admin = ViewerContext { principal: "someone-else".to_string(), roles: vec!["admin".to_string()] };
+        let trail = middleware.query_audit(&admin, &AuditFilter::default());
+        assert_ne!(trail, "[]");
+    }
+
+    #[test]
+    fn test_query_audit_with_no_granted_role_falls_back_to_own_scope() {
+        let config = CfConfig::default();
+        let mut middleware = CfGovernanceMiddleware::new(config);
+        let _ = middleware.evaluate_agent("agent-001", 3, 1.0, "test_action");
+
+        // No roles, no grants: a different principal sees nothing.
+        let stranger = ViewerContext { principal: "agent-999".to_string(), roles: vec![] };
+        let trail = middleware.query_audit(&stranger, &AuditFilter::default());
+        assert_eq!(trail, "[]");
+    }
+
+    #[test]
+    fn test_query_audit_with_no_granted_role_sees_own_records() {
+        let config = CfConfig::default();
+        let mut middleware = CfGovernanceMiddleware::new(config);
+        let _ = middleware.evaluate_agent("agent-001", 3, 1.0, "test_action");
+
+        // No roles, no grants: the agent that generated the record can still
+        // see its own activity via the own-scope fallback.
+        let owner = ViewerContext { principal: "agent-001".to_string(), roles: vec![] };
+        let trail = middleware.query_audit(&owner, &AuditFilter::default());
         assert_ne!(trail, "[]");
+        assert!(trail.contains("agent-001"));
+    }
+
+    #[test]
+    fn test_query_audit_role_grant_restricts_to_scope() {
+        let mut role_scope_grants = BTreeMap::new();
+        role_scope_grants.insert("auditor".to_string(), vec!["tenant-a".to_string()]);
+        let config = CfConfig { role_scope_grants, ..CfConfig::default() };
+        let mut middleware = CfGovernanceMiddleware::new(config);
+
+        let context_a = Context {
+            agent_id: "agent-001".to_string(),
+            scope: "tenant-a".to_string(),
+            required_trust: TrustLevel::Observer,
+            cost: None,
+            category: "api-calls".to_string(),
+            data_type: None,
+            purpose: None,
+        };
+        middleware.engine.check("read_data", &context_a);
+
+        let context_b = Context {
+            agent_id: "agent-002".to_string(),
+            scope: "tenant-b".to_string(),
+            required_trust: TrustLevel::Observer,
+            cost: None,
+            category: "api-calls".to_string(),
+            data_type: None,
+            purpose: None,
+        };
+        middleware.engine.check("read_data", &context_b);
+
+        let auditor = ViewerContext {
+            principal: "ops-team".to_string(),
+            roles: vec!["auditor".to_string()],
+        };
+        let trail = middleware.query_audit(&auditor, &AuditFilter::default());
+        assert!(trail.contains("tenant-a"));
+        assert!(!trail.contains("tenant-b"));
     }
 
     #[test]
@@ -443,4 +1266,236 @@ mod tests {
         let decision = middleware.evaluate_agent("agent-001", 4, 1.0, "action_3");
         assert!(matches!(decision, MiddlewareDecision::Deny { .. }));
     }
+
+    #[test]
+    fn test_signature_optional_by_default() {
+        // `require_signature` is false by default, so a garbage signature is ignored.
+        let middleware = CfGovernanceMiddleware::new(CfConfig::default());
+        let result = middleware.authenticate_signature(
+            "agent-001", "secret", "GET", "/read_data", 0, b"", "not-a-real-signature", 0,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_signature_accepted_when_valid() {
+        let config = CfConfig { require_signature: true, ..CfConfig::default() };
+        let middleware = CfGovernanceMiddleware::new(config);
+
+        let body = b"{}";
+        let body_hash = auth::hash_body(body);
+        let canonical = auth::canonical_string("agent-001", "POST", "/call_llm", 1_000, &body_hash);
+        let signature = auth::sign("shared-secret", &canonical);
+
+        let result = middleware.authenticate_signature(
+            "agent-001", "shared-secret", "POST", "/call_llm", 1_000, body, &signature, 1_000,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_signature_rejected_when_tampered() {
+        let config = CfConfig { require_signature: true, ..CfConfig::default() };
+        let middleware = CfGovernanceMiddleware::new(config);
+
+        let result = middleware.authenticate_signature(
+            "agent-001", "shared-secret", "POST", "/call_llm", 1_000, b"{}", "deadbeef", 1_000,
+        );
+        assert!(matches!(result, Err(MiddlewareDecision::Unauthenticated { .. })));
+    }
+
+    #[test]
+    fn test_signature_rejected_outside_clock_skew_window() {
+        let config = CfConfig {
+            require_signature: true,
+            max_clock_skew_ms: 300_000,
+            ..CfConfig::default()
+        };
+        let middleware = CfGovernanceMiddleware::new(config);
+
+        let body_hash = auth::hash_body(b"{}");
+        let canonical = auth::canonical_string("agent-001", "POST", "/call_llm", 0, &body_hash);
+        let signature = auth::sign("shared-secret", &canonical);
+
+        // Request was signed at t=0 but arrives far outside the skew window.
+        let result = middleware.authenticate_signature(
+            "agent-001", "shared-secret", "POST", "/call_llm", 0, b"{}", &signature, 1_000_000,
+        );
+        assert!(matches!(result, Err(MiddlewareDecision::Unauthenticated { .. })));
+    }
+
+    #[test]
+    fn test_breakglass_rejected_when_not_configured() {
+        // `breakglass_secret` is `None` by default, so any token is rejected.
+        let middleware = CfGovernanceMiddleware::new(CfConfig::default());
+        let result = middleware.authenticate_breakglass("agent-001", "op-1:0:60000:deadbeef", 0);
+        assert!(matches!(result, Err(MiddlewareDecision::Unauthenticated { .. })));
+    }
+
+    #[test]
+    fn test_breakglass_accepted_when_valid() {
+        let config = CfConfig {
+            breakglass_secret: Some("operator-secret".to_string()),
+            ..CfConfig::default()
+        };
+        let middleware = CfGovernanceMiddleware::new(config);
+
+        let canonical = "agent-001\nop-1\n1000\n60000".to_string();
+        let signature = auth::sign("operator-secret", &canonical);
+        let token = format!("op-1:1000:60000:{signature}");
+
+        let grant = middleware
+            .authenticate_breakglass("agent-001", &token, 1_500)
+            .expect("valid token should be accepted");
+        assert_eq!(grant.granted_by, "op-1");
+        assert_eq!(grant.ttl_ms, 60_000);
+    }
+
+    #[test]
+    fn test_breakglass_rejected_when_expired() {
+        let config = CfConfig {
+            breakglass_secret: Some("operator-secret".to_string()),
+            ..CfConfig::default()
+        };
+        let middleware = CfGovernanceMiddleware::new(config);
+
+        let canonical = "agent-001\nop-1\n1000\n60000".to_string();
+        let signature = auth::sign("operator-secret", &canonical);
+        let token = format!("op-1:1000:60000:{signature}");
+
+        let result = middleware.authenticate_breakglass("agent-001", &token, 1_000_000);
+        assert!(matches!(result, Err(MiddlewareDecision::Unauthenticated { .. })));
+    }
+
+    #[test]
+    fn test_breakglass_rejected_when_ttl_exceeds_max() {
+        let config = CfConfig {
+            breakglass_secret: Some("operator-secret".to_string()),
+            breakglass_max_ttl_ms: 60_000,
+            ..CfConfig::default()
+        };
+        let middleware = CfGovernanceMiddleware::new(config);
+
+        let canonical = "agent-001\nop-1\n1000\n900000".to_string();
+        let signature = auth::sign("operator-secret", &canonical);
+        let token = format!("op-1:1000:900000:{signature}");
+
+        let result = middleware.authenticate_breakglass("agent-001", &token, 1_500);
+        assert!(matches!(result, Err(MiddlewareDecision::Unauthenticated { .. })));
+    }
+
+    #[test]
+    fn test_breakglass_elevates_trust_and_records_audit() {
+        let config = CfConfig {
+            required_trust_level: TrustLevel::Autonomous as u8,
+            breakglass_secret: Some("operator-secret".to_string()),
+            ..CfConfig::default()
+        };
+        let mut middleware = CfGovernanceMiddleware::new(config);
+
+        let canonical = "agent-001\nop-1\n1000\n60000".to_string();
+        let signature = auth::sign("operator-secret", &canonical);
+        let token = format!("op-1:1000:60000:{signature}");
+        let grant = middleware
+            .authenticate_breakglass("agent-001", &token, 1_500)
+            .expect("valid token should be accepted");
+
+        // Observer-level agent would ordinarily be denied Autonomous-gated work.
+        let decision = middleware.evaluate_agent_with_breakglass(
+            "agent-001",
+            TrustLevel::Observer as u8,
+            0.0,
+            "incident_response",
+            Some(grant),
+        );
+        assert!(matches!(
+            decision,
+            MiddlewareDecision::Allow { elevated: true, .. }
+        ));
+
+        let records = middleware.engine.audit.query(&AuditFilter::default());
+        assert!(records
+            .iter()
+            .any(|record| record.decision.reason.contains("BREAKGLASS_USED")));
+    }
+
+    #[test]
+    fn test_policy_rule_overrides_trust_for_matching_action() {
+        // The flat default requires trust 2, but `delete_*` actions demand trust 5.
+        let config = CfConfig {
+            required_trust_level: 2,
+            policies: vec![PolicyRule {
+                path_prefix: None,
+                action_glob: Some("delete_*".to_string()),
+                required_trust_level: 5,
+                budget_category: "api-calls".to_string(),
+                budget_limit: 1000.0,
+                require_consent: false,
+            }],
+            ..CfConfig::default()
+        };
+        let mut middleware = CfGovernanceMiddleware::new(config);
+
+        // Trust 2 clears the flat default but not the rule's trust 5.
+        let decision = middleware.evaluate_agent("agent-001", 2, 0.0, "delete_resource");
+        assert!(matches!(decision, MiddlewareDecision::Deny { .. }));
+
+        let decision = middleware.evaluate_agent("agent-002", 5, 0.0, "delete_resource");
+        assert!(matches!(decision, MiddlewareDecision::Allow { .. }));
+
+        // An unrelated action still falls back to the flat default.
+        let decision = middleware.evaluate_agent("agent-003", 2, 0.0, "read_data");
+        assert!(matches!(decision, MiddlewareDecision::Allow { .. }));
+    }
+
+    #[test]
+    fn test_policy_rule_selects_most_specific_path_prefix() {
+        let config = CfConfig {
+            policies: vec![
+                PolicyRule {
+                    path_prefix: Some("/admin".to_string()),
+                    action_glob: None,
+                    required_trust_level: 3,
+                    budget_category: "api-calls".to_string(),
+                    budget_limit: 1000.0,
+                    require_consent: false,
+                },
+                PolicyRule {
+                    path_prefix: Some("/admin/danger".to_string()),
+                    action_glob: None,
+                    required_trust_level: 5,
+                    budget_category: "api-calls".to_string(),
+                    budget_limit: 1000.0,
+                    require_consent: false,
+                },
+            ],
+            ..CfConfig::default()
+        };
+        let mut middleware = CfGovernanceMiddleware::new(config);
+
+        // Trust 3 satisfies "/admin" but not the longer, more specific "/admin/danger".
+        let decision = middleware.evaluate_agent("agent-001", 3, 0.0, "/admin/danger/wipe");
+        assert!(matches!(decision, MiddlewareDecision::Deny { .. }));
+
+        let decision = middleware.evaluate_agent("agent-001", 3, 0.0, "/admin/settings");
+        assert!(matches!(decision, MiddlewareDecision::Allow { .. }));
+    }
+
+    #[cfg(feature = "wasm-policies")]
+    #[test]
+    fn test_plugin_registration_rejects_invalid_module() {
+        let middleware = CfGovernanceMiddleware::new(CfConfig::default());
+        let result = middleware.register_plugin_module("bad-plugin", b"not a real wasm module");
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "wasm-policies")]
+    #[test]
+    fn test_wasm_policies_noop_when_none_configured() {
+        // No plugins configured; evaluation proceeds exactly as the built-in
+        // gates decide.
+        let mut middleware = CfGovernanceMiddleware::new(CfConfig::default());
+        let decision = middleware.evaluate_agent("agent-001", 3, 1.0, "read_data");
+        assert!(matches!(decision, MiddlewareDecision::Allow { .. }));
+    }
 }
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: BSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! Pluggable WASM governance policies, evaluated inside the Worker after the
+//! built-in trust/budget/consent gates pass.
+//!
+//! Only compiled when the `wasm-policies` feature is enabled. Operators
+//! register modules via [`CfConfig::wasm_policies`](crate::CfConfig::wasm_policies);
+//! each module exports a `check(ptr, len) -> u64` function sharing the Worker's
+//! linear memory: it receives the serialised [`Context`](aumos_governance_core::types::Context)
+//! JSON at `(ptr, len)` and returns a packed `(result_ptr << 32) | result_len`
+//! pointing at a JSON-encoded [`PluginDecision`] it wrote into its own memory
+//! (plus an `alloc(len) -> ptr` export the host uses to place the input).
+//!
+//! Compiled modules are cached process-wide, keyed by name, so repeat
+//! requests within the same Worker isolate skip recompilation. Each
+//! invocation still runs in a fresh, fuel-metered [`wasmtime::Store`] so a
+//! runaway policy can't stall the Worker.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+use wasmtime::{Engine, Linker, Module, Store};
+
+/// A policy plugin's verdict, returned by a module's exported `check`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum PluginDecision {
+    /// The plugin raises no objection; evaluation continues.
+    Allow,
+    /// The plugin vetoes the action outright.
+    Deny {
+        /// Human-readable reason, folded into the resulting `Deny`.
+        reason: String,
+    },
+    /// The plugin adjusts the effective trust level and/or cost already
+    /// established by the built-in gates -- e.g. "treat this as trust 5
+    /// because the payload looks like PII".
+    Mutate {
+        /// Overridden trust level, if the plugin wants to change it.
+        #[serde(default)]
+        trust_level: Option<u8>,
+        /// Overridden cost, if the plugin wants to change it. Recorded in the
+        /// audit trail; the budget charge already made is not changed.
+        #[serde(default)]
+        cost: Option<f64>,
+    },
+}
+
+/// Where to fetch a plugin module's bytecode from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum PluginSource {
+    /// A Cloudflare KV namespace binding plus key holding the `.wasm` bytes.
+    Kv {
+        /// The KV namespace binding name.
+        binding: String,
+        /// The key the module's bytes are stored under.
+        key: String,
+    },
+    /// A URL the Worker fetches the `.wasm` bytes from.
+    Url {
+        /// The module's URL.
+        url: String,
+    },
+}
+
+fn default_fuel_limit() -> u64 {
+    1_000_000
+}
+
+/// Declarative registration of one WASM policy module.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WasmPolicyConfig {
+    /// Name surfaced in audit records and error messages.
+    pub name: String,
+    /// Where to load the module's bytecode from.
+    pub source: PluginSource,
+    /// Fuel budget for a single `check` invocation. Defaults to `1_000_000`.
+    #[serde(default = "default_fuel_limit")]
+    pub fuel_limit: u64,
+}
+
+/// Compiled-module cache and evaluator for registered WASM policy plugins.
+pub struct PluginRuntime {
+    engine: Engine,
+    modules: HashMap<String, Module>,
+}
+
+impl PluginRuntime {
+    fn new() -> Self {
+        let mut config = wasmtime::Config::new();
+        config.consume_fuel(true);
+        Self {
+            engine: Engine::new(&config)
+                .expect("wasmtime engine construction is infallible for this config"),
+            modules: HashMap::new(),
+        }
+    }
+
+    /// Compile and cache `bytes` under `name`, replacing any previously
+    /// cached module of the same name.
+    pub fn register(&mut self, name: &str, bytes: &[u8]) -> Result<(), String> {
+        let module = Module::new(&self.engine, bytes).map_err(|error| error.to_string())?;
+        self.modules.insert(name.to_string(), module);
+        Ok(())
+    }
+
+    /// Invoke the cached module `name`'s `check` export against
+    /// `context_json`, metered by `fuel_limit`.
+    pub fn evaluate(
+        &self,
+        name: &str,
+        context_json: &str,
+        fuel_limit: u64,
+    ) -> Result<PluginDecision, String> {
+        let module = self
+            .modules
+            .get(name)
+            .ok_or_else(|| format!("plugin module '{name}' is not loaded"))?;
+
+        let mut store = Store::new(&self.engine, ());
+        store.set_fuel(fuel_limit).map_err(|error| error.to_string())?;
+
+        let linker = Linker::new(&self.engine);
+        let instance = linker
+            .instantiate(&mut store, module)
+            .map_err(|error| error.to_string())?;
+
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| format!("plugin module '{name}' does not export linear memory"))?;
+        let alloc = instance
+            .get_typed_func::<u32, u32>(&mut store, "alloc")
+            .map_err(|error| error.to_string())?;
+        let check = instance
+            .get_typed_func::<(u32, u32), u64>(&mut store, "check")
+            .map_err(|error| error.to_string())?;
+
+        let input = context_json.as_bytes();
+        let input_ptr = alloc
+            .call(&mut store, input.len() as u32)
+            .map_err(|error| error.to_string())?;
+        memory
+            .write(&mut store, input_ptr as usize, input)
+            .map_err(|error| error.to_string())?;
+
+        let packed = check
+            .call(&mut store, (input_ptr, input.len() as u32))
+            .map_err(|error| format!("plugin '{name}' trapped or ran out of fuel: {error}"))?;
+        let (result_ptr, result_len) = ((packed >> 32) as u32, packed as u32);
+
+        let mut result_bytes = vec![0u8; result_len as usize];
+        memory
+            .read(&store, result_ptr as usize, &mut result_bytes)
+            .map_err(|error| error.to_string())?;
+
+        let result_json = String::from_utf8(result_bytes).map_err(|error| error.to_string())?;
+        serde_json::from_str(&result_json)
+            .map_err(|error| format!("plugin '{name}' returned invalid decision JSON: {error}"))
+    }
+}
+
+static PLUGIN_RUNTIME: OnceLock<Mutex<PluginRuntime>> = OnceLock::new();
+
+/// The process-wide compiled-module cache, shared across requests within a
+/// Worker isolate.
+pub fn runtime() -> &'static Mutex<PluginRuntime> {
+    PLUGIN_RUNTIME.get_or_init(|| Mutex::new(PluginRuntime::new()))
+}
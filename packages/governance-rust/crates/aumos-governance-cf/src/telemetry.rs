@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: BSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! OpenTelemetry traces and metrics for governance evaluations.
+//!
+//! Only compiled when the `otel` feature is enabled. [`Telemetry::init`]
+//! installs an OTLP trace exporter pointed at [`CfConfig::otlp_endpoint`](crate::CfConfig::otlp_endpoint).
+//! The metric instruments are taken from the global meter provider, which
+//! this module does not install, so metrics are exported only if the host
+//! has installed a meter provider. No log records are emitted.
+//!
+//! Each call to [`CfGovernanceMiddleware::evaluate_agent`](crate::CfGovernanceMiddleware::evaluate_agent)
+//! records, once it has decided, a span carrying `agent_id`, the effective
+//! trust level, and the resulting [`MiddlewareDecision`](crate::MiddlewareDecision)
+//! variant, and emits:
+//!
+//! - `governance.decisions` -- counter of decisions, tagged by `outcome`/`code`/`action`
+//! - `governance.check.duration_ms` -- histogram of the evaluation's duration
+//!   (engine check and plugins; the KV trust lookup happens before and is not included)
+//! - `governance.budget.remaining` -- gauge of remaining budget per category
+//!
+//! Budget remaining uses a synchronous gauge recorded at the single point
+//! where we already hold the rolled-over envelope, rather than a
+//! callback-based observable gauge -- Workers isolates are short-lived and
+//! don't benefit from background collection callbacks.
+
+use opentelemetry::metrics::{Counter, Gauge, Histogram};
+use opentelemetry::trace::{Span, Tracer};
+use opentelemetry::{global, KeyValue};
+
+/// Holds the metric instruments used across the lifetime of a middleware
+/// instance. One [`Telemetry`] is created per [`CfGovernanceMiddleware`](crate::CfGovernanceMiddleware)
+/// when `config.otlp_endpoint` is set.
+pub struct Telemetry {
+    decisions: Counter<u64>,
+    check_duration_ms: Histogram<f64>,
+    budget_remaining: Gauge<f64>,
+}
+
+impl Telemetry {
+    /// Install the OTLP exporter against `endpoint` and return the metric
+    /// instruments used by [`record_decision`](Self::record_decision) and
+    /// [`record_budget_remaining`](Self::record_budget_remaining).
+    pub fn init(endpoint: &str) -> Self {
+        let _ = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter().tonic().with_endpoint(endpoint),
+            )
+            .install_simple();
+
+        let meter = global::meter("aumos-governance-cf");
+        Self {
+            decisions: meter
+                .u64_counter("governance.decisions")
+                .with_description("Count of governance middleware decisions")
+                .init(),
+            check_duration_ms: meter
+                .f64_histogram("governance.check.duration_ms")
+                .with_description("Duration of the governance evaluation, excluding the KV trust lookup, in milliseconds")
+                .init(),
+            budget_remaining: meter
+                .f64_gauge("governance.budget.remaining")
+                .with_description("Remaining headroom in the default budget dimension per category")
+                .init(),
+        }
+    }
+
+    /// Record a completed evaluation: a span plus the decision counter and
+    /// duration histogram, all tagged with `outcome`/`code`/`action`.
+    pub fn record_decision(
+        &self,
+        agent_id: &str,
+        trust_level: u8,
+        action: &str,
+        outcome: &str,
+        code: &str,
+        elapsed_ms: f64,
+    ) {
+        let tracer = global::tracer("aumos-governance-cf");
+        let mut span = tracer.start(format!("governance.evaluate_agent:{action}"));
+        span.set_attribute(KeyValue::new("agent_id", agent_id.to_string()));
+        span.set_attribute(KeyValue::new("trust_level", trust_level as i64));
+        span.set_attribute(KeyValue::new("outcome", outcome.to_string()));
+        span.set_attribute(KeyValue::new("code", code.to_string()));
+        span.end();
+
+        let attributes = [
+            KeyValue::new("outcome", outcome.to_string()),
+            KeyValue::new("code", code.to_string()),
+            KeyValue::new("action", action.to_string()),
+        ];
+        self.decisions.add(1, &attributes);
+        self.check_duration_ms.record(elapsed_ms, &attributes);
+    }
+
+    /// Record the current remaining headroom for `category`'s default
+    /// dimension.
+    pub fn record_budget_remaining(&self, category: &str, remaining: f64) {
+        self.budget_remaining.record(remaining, &[KeyValue::new("category", category.to_string())]);
+    }
+}
@@ -119,6 +119,7 @@ fn budget_enforcement_benchmark(criterion: &mut Criterion) {
             let result = manager.check(
                 black_box("category-025"),
                 black_box(10.0),
+                black_box(0),
             );
             black_box(result);
         });
@@ -126,12 +127,13 @@ fn budget_enforcement_benchmark(criterion: &mut Criterion) {
 
     group.bench_function("check_exceeds_budget", |bencher| {
         // Pre-spend most of the budget.
-        manager.record("category-049", 9_999.0);
+        manager.record("category-049", 9_999.0, 0).unwrap();
 
         bencher.iter(|| {
             let result = manager.check(
                 black_box("category-049"),
                 black_box(50.0),
+                black_box(0),
             );
             black_box(result);
         });
@@ -142,6 +144,7 @@ fn budget_enforcement_benchmark(criterion: &mut Criterion) {
             let result = manager.check(
                 black_box("nonexistent-category"),
                 black_box(1.0),
+                black_box(0),
             );
             black_box(result);
         });
@@ -165,6 +168,7 @@ fn full_evaluation_benchmark(criterion: &mut Criterion) {
         require_consent: false,
         default_observer_on_missing: false,
         pass_on_missing_envelope: true,
+        ..Config::default()
     };
 
     let mut engine = GovernanceEngine::new(config, InMemoryStorage::new());
@@ -234,8 +238,12 @@ fn audit_log_benchmark(criterion: &mut Criterion) {
     let sample_decision = Decision {
         permitted: true,
         action: "benchmark_action".to_string(),
+        agent_id: "agent-001".to_string(),
+        scope: "default".to_string(),
         timestamp_ms: 1_700_000_000_000,
         reason: "All governance gates passed.".to_string(),
+        policy_epoch: 0,
+        policy_hash: String::new(),
         trust: TrustResult {
             permitted: true,
             current_level: TrustLevel::ActAndReport,
@@ -248,6 +256,7 @@ fn audit_log_benchmark(criterion: &mut Criterion) {
             requested: 1.0,
             category: "benchmark".to_string(),
             reason: "Within budget".to_string(),
+            dimension: None,
         },
         consent: ConsentResult {
             permitted: true,
@@ -307,6 +316,7 @@ fn conformance_vector_benchmark(criterion: &mut Criterion) {
                 require_consent: false,
                 default_observer_on_missing: true,
                 pass_on_missing_envelope: true,
+                ..Config::default()
             };
             let mut engine = GovernanceEngine::new(config, InMemoryStorage::new());
 
@@ -315,7 +325,7 @@ fn conformance_vector_benchmark(criterion: &mut Criterion) {
             engine.trust.set_level("agent-mon", "scope", TrustLevel::Monitor, "owner");
             engine.trust.set_level("agent-act", "scope", TrustLevel::ActAndReport, "owner");
             engine.budget.create_envelope("tokens", 1000.0, 86_400_000, 0);
-            engine.consent.record("agent-act", "read_pii");
+            engine.consent.record("agent-act", "read_pii", None, None);
 
             // Vector 1: Observer requesting Observer (permit)
             let v1 = Context {
@@ -19,6 +19,22 @@
 //! Read operations (check_*) acquire a shared read lock.
 //! Write operations (set_*, record_*, log) acquire an exclusive write lock.
 //!
+//! # Budget lock discipline
+//!
+//! [`BudgetManager`](crate::budget::BudgetManager)'s checkpoint stack
+//! (`checkpoint`/`revert_to`/`discard`) is positional, not per-caller: it
+//! assumes whoever opened a checkpoint is the only one touching the stack
+//! until that checkpoint is closed. If two concurrent calls interleaved
+//! their own separate lock acquisitions — one opening a checkpoint, the
+//! other committing an unrelated debit on top of it via `discard`, then the
+//! first reverting past that debit — the revert would silently erase the
+//! second call's spend. [`check`](Self::check), [`schedule`](Self::schedule),
+//! and [`check_batch`](Self::check_batch) each avoid this by acquiring the
+//! budget write lock exactly once per call and holding that single guard for
+//! the checkpoint's entire open-to-close lifetime, including across the
+//! consent gate's own `.await` — so no other call can interleave a
+//! checkpoint of its own until this one has fully closed.
+//!
 //! The evaluation pipeline remains sequential and non-configurable —
 //! exactly as in the sync [`GovernanceEngine`]:
 //!
@@ -27,6 +43,78 @@
 //! 3. Consent gate (skipped when data_type is None)
 //! 4. Audit log (always written)
 //!
+//! # Emergency pause
+//!
+//! [`AsyncGovernanceEngine::pause`] flips a process-wide kill-switch: every
+//! subsequent [`check`](AsyncGovernanceEngine::check) call short-circuits
+//! *before* the trust gate with a denied [`Decision`] (`reason: "Engine
+//! paused."`), without touching trust, budget, or consent state. Read-only
+//! methods (`check_trust`, `check_budget`, `query_audit`, etc.) are
+//! unaffected, so an operator can still inspect state mid-incident.
+//! [`AsyncGovernanceEngine::resume`] clears the flag. Both transitions are
+//! themselves written to the audit log, attributed to the caller-supplied
+//! `by`.
+//!
+//! # Deferred actions
+//!
+//! [`AsyncGovernanceEngine::schedule`] runs the full gate pipeline
+//! immediately — an action that would be denied is never scheduled, and its
+//! budget (if any) is reserved up front via [`BudgetManager::record`] — but
+//! holds the resulting permit decision in a pending queue instead of
+//! auditing it right away. The caller gets back a [`ScheduledId`] naming a
+//! cancellation window:
+//!
+//! * [`cancel_scheduled`](AsyncGovernanceEngine::cancel_scheduled) aborts the
+//!   pending action before it executes, refunding the reserved budget and
+//!   recording who vetoed it.
+//! * [`poll_due`](AsyncGovernanceEngine::poll_due) finalizes and audit-logs
+//!   every pending action whose window has elapsed as of the supplied
+//!   `now_ms` — callers are expected to poll this periodically (e.g. from a
+//!   Tokio interval task).
+//!
+//! There is no background timer inside the engine; `poll_due` must be driven
+//! by the host.
+//!
+//! # Batch evaluation
+//!
+//! [`AsyncGovernanceEngine::check_batch`] evaluates many actions while
+//! acquiring each manager's lock only once for the whole batch, rather than
+//! once per action as a loop of [`check`](AsyncGovernanceEngine::check)
+//! calls would. Per-action results are identical either way, including the
+//! running budget depletion across the batch.
+//!
+//! # Authorization
+//!
+//! [`set_trust_level`](AsyncGovernanceEngine::set_trust_level),
+//! [`record_consent`](AsyncGovernanceEngine::record_consent),
+//! [`revoke_consent`](AsyncGovernanceEngine::revoke_consent),
+//! [`delegate_consent`](AsyncGovernanceEngine::delegate_consent),
+//! [`revoke_delegation`](AsyncGovernanceEngine::revoke_delegation), and
+//! [`record_spend`](AsyncGovernanceEngine::record_spend) each take a
+//! `principal` naming who is asking, and first consult an
+//! [`AuthorizationManager`](crate::authorization::AuthorizationManager) for
+//! the matching [`GovernanceOperation`](crate::authorization::GovernanceOperation)
+//! before applying the mutation. An unauthorized caller's attempt is denied
+//! and audited rather than silently taking effect — this is what makes
+//! "trust changes are always initiated by an authorised owner" an enforced
+//! rule instead of a convention. Authority is granted and withdrawn via
+//! [`grant_authority`](AsyncGovernanceEngine::grant_authority) and
+//! [`revoke_authority`](AsyncGovernanceEngine::revoke_authority); granting
+//! itself is unchecked, since someone must hold the engine to make the
+//! first grant.
+//!
+//! # Fail-closed clock handling
+//!
+//! `check`, `pause`, and `resume` read the system clock via
+//! `unwrap_or_default`, so a clock before the Unix epoch would silently
+//! stamp a decision with `timestamp_ms = 0` rather than surfacing the
+//! failure. [`try_check`](AsyncGovernanceEngine::try_check),
+//! [`try_pause`](AsyncGovernanceEngine::try_pause), and
+//! [`try_resume`](AsyncGovernanceEngine::try_resume) are fail-closed
+//! siblings that report that failure as a typed [`GovernanceError`]
+//! instead — the denial is audited either way, this only changes how the
+//! caller learns whether it was policy or malfunction.
+//!
 //! # Example
 //!
 //! ```rust,no_run
@@ -34,6 +122,7 @@
 //! # {
 //! use aumos_governance_core::{
 //!     async_engine::AsyncGovernanceEngine,
+//!     authorization::GovernanceOperation,
 //!     storage::InMemoryStorage,
 //!     types::{Context, TrustLevel},
 //!     config::Config,
@@ -43,6 +132,7 @@
 //! async fn main() {
 //!     let engine = AsyncGovernanceEngine::new(Config::default(), InMemoryStorage::new());
 //!
+//!     engine.grant_authority("owner", GovernanceOperation::SetTrustLevel, "ops").await;
 //!     engine.set_trust_level("agent-001", "ops", TrustLevel::ActAndReport, "owner").await;
 //!
 //!     let ctx = Context {
@@ -62,11 +152,14 @@
 
 #![cfg(feature = "async")]
 
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 
 use tokio::sync::RwLock;
 
 use crate::audit::AuditLogger;
+use crate::authorization::{AuthorizationManager, GovernanceOperation};
 use crate::budget::BudgetManager;
 use crate::config::Config;
 use crate::consent::ConsentManager;
@@ -77,6 +170,26 @@ use crate::types::{
     TrustLevel, TrustResult,
 };
 
+/// Identifies a deferred action queued by [`AsyncGovernanceEngine::schedule`].
+///
+/// Opaque and only meaningful to the engine that issued it — pass it to
+/// [`cancel_scheduled`](AsyncGovernanceEngine::cancel_scheduled) to veto the
+/// action before its window elapses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScheduledId(u64);
+
+/// A deferred action sitting in its cancellation window.
+///
+/// Holds the already-evaluated permit [`Decision`] (gates ran, budget was
+/// reserved) plus enough of the original request to refund that budget if
+/// [`cancel_scheduled`](AsyncGovernanceEngine::cancel_scheduled) vetoes it.
+struct PendingAction {
+    decision:       Decision,
+    category:       String,
+    reserved_cost:  Option<f64>,
+    executes_at_ms: u64,
+}
+
 // ---------------------------------------------------------------------------
 // AsyncGovernanceEngine
 // ---------------------------------------------------------------------------
@@ -91,6 +204,16 @@ pub struct AsyncGovernanceEngine<S: Storage> {
     budget:  Arc<RwLock<BudgetManager<S>>>,
     consent: Arc<RwLock<ConsentManager<S>>>,
     audit:   Arc<RwLock<AuditLogger<S>>>,
+    /// Gates [`set_trust_level`](Self::set_trust_level), [`record_consent`](Self::record_consent),
+    /// [`revoke_consent`](Self::revoke_consent), and [`record_spend`](Self::record_spend).
+    authorization: Arc<RwLock<AuthorizationManager<S>>>,
+    /// Emergency kill-switch. When `true`, [`check`](Self::check) denies
+    /// every action before the trust gate runs.
+    paused:  Arc<AtomicBool>,
+    /// Deferred actions awaiting either cancellation or [`poll_due`](Self::poll_due).
+    pending: Arc<RwLock<BTreeMap<u64, PendingAction>>>,
+    /// Monotonic counter backing [`ScheduledId`] generation.
+    next_scheduled_id: Arc<AtomicU64>,
 }
 
 impl<S: Storage + Clone> AsyncGovernanceEngine<S> {
@@ -103,44 +226,468 @@ impl<S: Storage + Clone> AsyncGovernanceEngine<S> {
             trust:   Arc::new(RwLock::new(TrustManager::new(config.clone(), storage.clone()))),
             budget:  Arc::new(RwLock::new(BudgetManager::new(config.clone(), storage.clone()))),
             consent: Arc::new(RwLock::new(ConsentManager::new(config.clone(), storage.clone()))),
-            audit:   Arc::new(RwLock::new(AuditLogger::new(storage))),
+            audit:   Arc::new(RwLock::new(AuditLogger::new(storage.clone()))),
+            authorization: Arc::new(RwLock::new(AuthorizationManager::new(storage))),
+            paused:  Arc::new(AtomicBool::new(false)),
+            pending: Arc::new(RwLock::new(BTreeMap::new())),
+            next_scheduled_id: Arc::new(AtomicU64::new(0)),
         }
     }
 }
 
 impl<S: Storage> AsyncGovernanceEngine<S> {
-    /// Construct an [`AsyncGovernanceEngine`] from four pre-built managers.
+    /// Construct an [`AsyncGovernanceEngine`] from five pre-built managers.
     pub fn from_parts(
-        trust:   TrustManager<S>,
-        budget:  BudgetManager<S>,
-        consent: ConsentManager<S>,
-        audit:   AuditLogger<S>,
+        trust:         TrustManager<S>,
+        budget:        BudgetManager<S>,
+        consent:       ConsentManager<S>,
+        audit:         AuditLogger<S>,
+        authorization: AuthorizationManager<S>,
     ) -> Self {
         Self {
             trust:   Arc::new(RwLock::new(trust)),
             budget:  Arc::new(RwLock::new(budget)),
             consent: Arc::new(RwLock::new(consent)),
             audit:   Arc::new(RwLock::new(audit)),
+            authorization: Arc::new(RwLock::new(authorization)),
+            paused:  Arc::new(AtomicBool::new(false)),
+            pending: Arc::new(RwLock::new(BTreeMap::new())),
+            next_scheduled_id: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Emergency pause
+    // -----------------------------------------------------------------------
+
+    /// Immediately suspend all governance evaluations: every `check()` call
+    /// from this point on is denied with `reason: "Engine paused."`, without
+    /// consulting trust, budget, or consent state.
+    ///
+    /// Read-only methods are unaffected, so operators can keep inspecting
+    /// state while paused. The transition itself is written to the audit
+    /// log, attributed to `by`.
+    pub async fn pause(&self, by: &str) {
+        self.paused.store(true, Ordering::SeqCst);
+        self.append_audit(pause_transition_decision(false, "engine_pause", by)).await;
+    }
+
+    /// Clear the pause flag set by [`pause`](Self::pause), restoring normal
+    /// evaluation. The transition itself is written to the audit log,
+    /// attributed to `by`.
+    pub async fn resume(&self, by: &str) {
+        self.paused.store(false, Ordering::SeqCst);
+        self.append_audit(pause_transition_decision(true, "engine_resume", by)).await;
+    }
+
+    /// Fail-closed sibling of [`pause`](Self::pause): the flag is only
+    /// flipped once a reliable timestamp is available. A clock failure
+    /// leaves the engine unpaused, logs a denied [`Decision`], and is
+    /// reported as [`GovernanceError::ClockError`] instead of silently
+    /// recording the transition under `timestamp_ms = 0`.
+    pub async fn try_pause(&self, by: &str) -> Result<Decision, GovernanceError> {
+        match try_pause_transition_decision(false, "engine_pause", by) {
+            Ok(decision) => {
+                self.paused.store(true, Ordering::SeqCst);
+                self.append_audit(decision.clone()).await;
+                Ok(decision)
+            }
+            Err(decision) => {
+                self.append_audit(decision.clone()).await;
+                Err(GovernanceError::ClockError(decision))
+            }
         }
     }
 
+    /// Fail-closed sibling of [`resume`](Self::resume). A clock failure
+    /// leaves the engine paused (the safe default) and is reported as
+    /// [`GovernanceError::ClockError`].
+    pub async fn try_resume(&self, by: &str) -> Result<Decision, GovernanceError> {
+        match try_pause_transition_decision(true, "engine_resume", by) {
+            Ok(decision) => {
+                self.paused.store(false, Ordering::SeqCst);
+                self.append_audit(decision.clone()).await;
+                Ok(decision)
+            }
+            Err(decision) => {
+                self.append_audit(decision.clone()).await;
+                Err(GovernanceError::ClockError(decision))
+            }
+        }
+    }
+
+    /// Whether the engine is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    // -----------------------------------------------------------------------
+    // Deferred actions
+    // -----------------------------------------------------------------------
+
+    /// Evaluate `action` immediately — trust, budget, and consent gates, in
+    /// the same order and with the same short-circuiting as [`check`](Self::check)
+    /// — but hold a permitted decision in a cancellation window instead of
+    /// finalizing it right away.
+    ///
+    /// Budget (when `ctx.cost` is set) is reserved now, via
+    /// [`BudgetManager::record`], not at execution time, so concurrent
+    /// `schedule` calls see accurate headroom. The reservation runs under a
+    /// checkpoint — same discipline as [`check`](Self::check) — so a later
+    /// consent denial can revert it instead of leaking the reservation. A
+    /// denied action is never scheduled: its denial is audit-logged
+    /// immediately and returned as `Err`, with any budget it reserved along
+    /// the way already given back.
+    ///
+    /// `delay_ms` is relative to now. The action becomes eligible for
+    /// [`poll_due`](Self::poll_due) once `executes_at_ms` (the schedule time
+    /// plus `delay_ms`) has elapsed, and can be vetoed any time before that
+    /// via [`cancel_scheduled`](Self::cancel_scheduled).
+    pub async fn schedule(
+        &self,
+        action: &str,
+        ctx: &Context,
+        delay_ms: u64,
+    ) -> Result<ScheduledId, Decision> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+
+        if self.is_paused() {
+            let decision = Decision {
+                permitted: false,
+                trust: skipped_trust_result(),
+                budget: skipped_budget_result(&ctx.category),
+                consent: skipped_consent_result(),
+                action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
+                timestamp_ms,
+                reason: "Engine paused.".into(),
+                policy_epoch: 0,
+                policy_hash: String::new(),
+            };
+            self.append_audit(decision.clone()).await;
+            return Err(decision);
+        }
+
+        // Step 1: Trust gate.
+        let trust_result: TrustResult = {
+            let manager = self.trust.read().await;
+            manager.check_level(&ctx.agent_id, &ctx.scope, ctx.required_trust)
+        };
+
+        if !trust_result.permitted {
+            let decision = Decision {
+                permitted: false,
+                trust: trust_result,
+                budget: skipped_budget_result(&ctx.category),
+                consent: skipped_consent_result(),
+                action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
+                timestamp_ms,
+                reason: "Trust gate denied.".into(),
+                policy_epoch: 0,
+                policy_hash: String::new(),
+            };
+            self.append_audit(decision.clone()).await;
+            return Err(decision);
+        }
+
+        // Steps 2-3: Budget gate, then consent gate — reserved now, refunded
+        // by `cancel_scheduled` if the action never executes. A single
+        // write-lock acquisition spans the checkpoint's entire open-to-close
+        // lifetime, including across the step 3 consent check's `.await`,
+        // for the same reason `evaluate_at` holds it: so no other concurrent
+        // `check`/`schedule`/`check_batch` call can open its own checkpoint
+        // on top of this one before this one closes. See the module's
+        // "Budget lock discipline" note.
+        let mut budget_manager = self.budget.write().await;
+        let budget_checkpoint = budget_manager.checkpoint();
+        let mut budget_record_error = None;
+        let budget_result: BudgetResult = match ctx.cost {
+            Some(amount) if amount > 0.0 => {
+                let result = budget_manager.check(&ctx.category, amount, timestamp_ms);
+                if result.permitted {
+                    if let Err(error) = budget_manager.record(&ctx.category, amount, timestamp_ms) {
+                        budget_record_error = Some(error);
+                    }
+                }
+                result
+            }
+            _ => skipped_budget_result(&ctx.category),
+        };
+
+        if let Some(error) = budget_record_error {
+            // Nothing was debited, so there's nothing to revert.
+            budget_manager.discard(budget_checkpoint);
+            drop(budget_manager);
+            let decision = Decision {
+                permitted: false,
+                trust: trust_result,
+                budget: BudgetResult {
+                    permitted: false,
+                    available: budget_result.available,
+                    requested: ctx.cost.unwrap_or(0.0),
+                    category: ctx.category.clone(),
+                    reason: format!("{}", error),
+                    dimension: None,
+                },
+                consent: skipped_consent_result(),
+                action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
+                timestamp_ms,
+                reason: "Budget gate denied.".into(),
+                policy_epoch: 0,
+                policy_hash: String::new(),
+            };
+            self.append_audit(decision.clone()).await;
+            return Err(decision);
+        }
+
+        if !budget_result.permitted {
+            budget_manager.discard(budget_checkpoint);
+            drop(budget_manager);
+            let decision = Decision {
+                permitted: false,
+                trust: trust_result,
+                budget: budget_result,
+                consent: skipped_consent_result(),
+                action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
+                timestamp_ms,
+                reason: "Budget gate denied.".into(),
+                policy_epoch: 0,
+                policy_hash: String::new(),
+            };
+            self.append_audit(decision.clone()).await;
+            return Err(decision);
+        }
+
+        // Step 3: Consent gate. `budget_manager` (and its write lock) is
+        // still held here, on purpose — see the steps 2-3 comment above.
+        let consent_result: ConsentResult = match &ctx.data_type {
+            Some(data_type) => {
+                let manager = self.consent.read().await;
+                manager.check(&ctx.agent_id, data_type, ctx, timestamp_ms)
+            }
+            None => skipped_consent_result(),
+        };
+
+        if !consent_result.permitted {
+            // Consent denied after step 2's debit — revert it so the agent
+            // isn't charged for a refused, never-scheduled action.
+            budget_manager.revert_to(budget_checkpoint);
+            drop(budget_manager);
+            let decision = Decision {
+                permitted: false,
+                trust: trust_result,
+                budget: budget_result,
+                consent: consent_result,
+                action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
+                timestamp_ms,
+                reason: "Consent gate denied.".into(),
+                policy_epoch: 0,
+                policy_hash: String::new(),
+            };
+            self.append_audit(decision.clone()).await;
+            return Err(decision);
+        }
+
+        // All gates passed — commit the budget debit (it's held as a real
+        // reservation from here, refunded only by `cancel_scheduled`).
+        budget_manager.discard(budget_checkpoint);
+        drop(budget_manager);
+
+        // Hold the permit instead of auditing it yet — `poll_due` or
+        // `cancel_scheduled` decides what happens to it next.
+        let decision = Decision {
+            permitted: true,
+            trust: trust_result,
+            budget: budget_result,
+            consent: consent_result,
+            action: action.into(),
+            agent_id: ctx.agent_id.clone(),
+            scope: ctx.scope.clone(),
+            timestamp_ms,
+            reason: "All governance gates passed; awaiting execution window.".into(),
+            policy_epoch: 0,
+            policy_hash: String::new(),
+        };
+
+        let id = self.next_scheduled_id.fetch_add(1, Ordering::SeqCst);
+        let executes_at_ms = timestamp_ms.saturating_add(delay_ms);
+        let reserved_cost = ctx.cost.filter(|&amount| amount > 0.0);
+        {
+            let mut pending = self.pending.write().await;
+            pending.insert(
+                id,
+                PendingAction {
+                    decision,
+                    category: ctx.category.clone(),
+                    reserved_cost,
+                    executes_at_ms,
+                },
+            );
+        }
+        Ok(ScheduledId(id))
+    }
+
+    /// Veto a pending [`schedule`](Self::schedule)d action before it executes.
+    ///
+    /// A no-op if `id` is unknown — already canceled, already finalized by
+    /// [`poll_due`](Self::poll_due), or never issued by this engine. Any
+    /// budget reserved at schedule time is given back via
+    /// [`BudgetManager::refund`]. The veto itself is written to the audit
+    /// log, attributed to `canceled_by`, so the cancellation is as
+    /// attributable as the original approval.
+    pub async fn cancel_scheduled(&self, id: ScheduledId, canceled_by: &str) {
+        let pending_action = {
+            let mut pending = self.pending.write().await;
+            pending.remove(&id.0)
+        };
+
+        let pending_action = match pending_action {
+            Some(pending_action) => pending_action,
+            None => return,
+        };
+
+        if let Some(amount) = pending_action.reserved_cost {
+            use std::time::{SystemTime, UNIX_EPOCH};
+            let now_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64;
+            let mut manager = self.budget.write().await;
+            let _ = manager.refund(&pending_action.category, amount, now_ms);
+        }
+
+        let cancel_decision = scheduled_cancel_decision(&pending_action.decision, canceled_by);
+        self.append_audit(cancel_decision).await;
+    }
+
+    /// Finalize and audit-log every pending [`schedule`](Self::schedule)d
+    /// action whose `executes_at_ms` is at or before `now_ms`, returning the
+    /// finalized decisions. Actions still inside their window are left
+    /// pending.
+    ///
+    /// Each returned [`Decision`] is stamped with `now_ms` as its
+    /// `timestamp_ms` — the audit log records when the action actually took
+    /// effect, not when it was approved. There is no background timer
+    /// inside the engine; callers must invoke this periodically (e.g. from
+    /// a Tokio interval task).
+    pub async fn poll_due(&self, now_ms: u64) -> Vec<Decision> {
+        let due_ids: Vec<u64> = {
+            let pending = self.pending.read().await;
+            pending
+                .iter()
+                .filter(|(_, pending_action)| pending_action.executes_at_ms <= now_ms)
+                .map(|(&id, _)| id)
+                .collect()
+        };
+
+        let mut finalized = Vec::with_capacity(due_ids.len());
+        for id in due_ids {
+            let pending_action = {
+                let mut pending = self.pending.write().await;
+                pending.remove(&id)
+            };
+            let pending_action = match pending_action {
+                Some(pending_action) => pending_action,
+                None => continue,
+            };
+            let mut decision = pending_action.decision;
+            decision.timestamp_ms = now_ms;
+            self.append_audit(decision.clone()).await;
+            finalized.push(decision);
+        }
+        finalized
+    }
+
+    // -----------------------------------------------------------------------
+    // Authorization
+    // -----------------------------------------------------------------------
+
+    /// Empower `principal` to perform `op` within `scope`, so that a later
+    /// call to [`set_trust_level`](Self::set_trust_level),
+    /// [`record_consent`](Self::record_consent),
+    /// [`revoke_consent`](Self::revoke_consent), or
+    /// [`record_spend`](Self::record_spend) made as that principal is
+    /// authorized. Granting is itself unchecked.
+    pub async fn grant_authority(&self, principal: &str, op: GovernanceOperation, scope: &str) {
+        let mut manager = self.authorization.write().await;
+        manager.grant_authority(principal, op, scope);
+    }
+
+    /// Withdraw a previously granted authority. A no-op if `principal` never
+    /// held it for `(op, scope)`.
+    pub async fn revoke_authority(&self, principal: &str, op: GovernanceOperation, scope: &str) {
+        let mut manager = self.authorization.write().await;
+        manager.revoke_authority(principal, op, scope);
+    }
+
+    /// Consult the [`AuthorizationManager`] for `(principal, op, scope)`.
+    /// `Ok(())` if authorized; otherwise builds, audits, and returns the
+    /// denial [`Decision`] so the caller can return it without applying the
+    /// mutation.
+    async fn authorize(
+        &self,
+        principal: &str,
+        op: GovernanceOperation,
+        scope: &str,
+    ) -> Result<(), Decision> {
+        let authorized = {
+            let manager = self.authorization.read().await;
+            manager.is_authorized(principal, op, scope)
+        };
+        if authorized {
+            return Ok(());
+        }
+        let decision = authorization_decision(false, principal, op, scope);
+        self.append_audit(decision.clone()).await;
+        Err(decision)
+    }
+
     // -----------------------------------------------------------------------
     // Trust
     // -----------------------------------------------------------------------
 
     /// Assign a trust level to an agent asynchronously.
     ///
-    /// Trust changes are always initiated by an authorised owner —
-    /// they are never generated automatically by the system.
+    /// Trust changes are always initiated by an authorised owner — they are
+    /// never generated automatically by the system, and now that is
+    /// enforced rather than conventional: `assigned_by` must hold
+    /// [`GovernanceOperation::SetTrustLevel`] authority over `scope` (see
+    /// [`grant_authority`](Self::grant_authority)). An unauthorized caller's
+    /// attempt is denied and audited, and the agent's trust is left
+    /// unchanged.
     pub async fn set_trust_level(
         &self,
         agent_id: &str,
         scope: &str,
         level: TrustLevel,
         assigned_by: &str,
-    ) {
-        let mut manager = self.trust.write().await;
-        manager.set_level(agent_id, scope, level, assigned_by);
+    ) -> Decision {
+        if let Err(decision) = self
+            .authorize(assigned_by, GovernanceOperation::SetTrustLevel, scope)
+            .await
+        {
+            return decision;
+        }
+        {
+            let mut manager = self.trust.write().await;
+            manager.set_level(agent_id, scope, level, assigned_by);
+        }
+        let decision = authorization_decision(true, assigned_by, GovernanceOperation::SetTrustLevel, scope);
+        self.append_audit(decision.clone()).await;
+        decision
     }
 
     /// Check whether an agent holds the required trust level.
@@ -158,43 +705,160 @@ impl<S: Storage> AsyncGovernanceEngine<S> {
     // Budget
     // -----------------------------------------------------------------------
 
-    /// Check whether a spending envelope has sufficient headroom.
-    pub async fn check_budget(&self, category: &str, amount: f64) -> BudgetResult {
+    /// Check whether a spending envelope has sufficient headroom as of `now_ms`.
+    pub async fn check_budget(&self, category: &str, amount: f64, now_ms: u64) -> BudgetResult {
         let manager = self.budget.read().await;
-        manager.check(category, amount)
+        manager.check(category, amount, now_ms)
     }
 
-    /// Record an actual spend against a budget envelope.
-    pub async fn record_spend(&self, category: &str, amount: f64) {
-        let mut manager = self.budget.write().await;
-        manager.record(category, amount);
+    /// Record an actual spend against a budget envelope as of `now_ms`, on
+    /// behalf of `principal`.
+    ///
+    /// `principal` must hold [`GovernanceOperation::RecordSpend`] authority
+    /// over `category` (see [`grant_authority`](Self::grant_authority)); an
+    /// unauthorized caller gets back a denied [`Decision`] and no spend is
+    /// recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::budget::BudgetError`] if no envelope exists for
+    /// `category`, or if the debit would exceed its (post-rollover) limit.
+    /// This is distinct from an authorization denial, which is carried in
+    /// the `Ok` [`Decision`] instead.
+    pub async fn record_spend(
+        &self,
+        category: &str,
+        amount: f64,
+        now_ms: u64,
+        principal: &str,
+    ) -> Result<Decision, crate::budget::BudgetError> {
+        if let Err(decision) = self
+            .authorize(principal, GovernanceOperation::RecordSpend, category)
+            .await
+        {
+            return Ok(decision);
+        }
+        {
+            let mut manager = self.budget.write().await;
+            manager.record(category, amount, now_ms)?;
+        }
+        let decision = authorization_decision(true, principal, GovernanceOperation::RecordSpend, category);
+        self.append_audit(decision.clone()).await;
+        Ok(decision)
     }
 
     // -----------------------------------------------------------------------
     // Consent
     // -----------------------------------------------------------------------
 
-    /// Check whether active consent exists for an agent to perform an action.
-    pub async fn check_consent(&self, agent_id: &str, action: &str) -> ConsentResult {
+    /// Check whether `ctx.agent_id` may perform `action` as of `now_ms`,
+    /// running any installed consent policies before falling back to the
+    /// stored grant/delegation lookup — see [`ConsentManager::check`].
+    pub async fn check_consent(&self, action: &str, ctx: &Context, now_ms: u64) -> ConsentResult {
         let manager = self.consent.read().await;
-        manager.check(agent_id, action)
+        manager.check(&ctx.agent_id, action, ctx, now_ms)
     }
 
-    /// Record explicit consent for an agent to perform a class of action.
+    /// Record explicit consent for an agent to perform a class of action, on
+    /// behalf of `principal`, optionally bounded by an expiry and/or scoped
+    /// to a purpose.
+    ///
+    /// `principal` must hold [`GovernanceOperation::RecordConsent`]
+    /// authority over `action` (see [`grant_authority`](Self::grant_authority));
+    /// an unauthorized caller gets back a denied [`Decision`] and no consent
+    /// is recorded.
     pub async fn record_consent(
         &self,
         agent_id: &str,
         action: &str,
-        expires_at_ms: Option<u64>,
-    ) {
-        let mut manager = self.consent.write().await;
-        manager.record(agent_id, action, expires_at_ms);
+        expiry_ms: Option<u64>,
+        purpose: Option<&str>,
+        principal: &str,
+    ) -> Decision {
+        if let Err(decision) = self
+            .authorize(principal, GovernanceOperation::RecordConsent, action)
+            .await
+        {
+            return decision;
+        }
+        {
+            let mut manager = self.consent.write().await;
+            manager.record(agent_id, action, expiry_ms, purpose);
+        }
+        let decision = authorization_decision(true, principal, GovernanceOperation::RecordConsent, action);
+        self.append_audit(decision.clone()).await;
+        decision
+    }
+
+    /// Revoke consent for an agent / action pair, on behalf of `principal`.
+    ///
+    /// `principal` must hold [`GovernanceOperation::RevokeConsent`]
+    /// authority over `action`; an unauthorized caller gets back a denied
+    /// [`Decision`] and the consent grant is left untouched.
+    pub async fn revoke_consent(&self, agent_id: &str, action: &str, principal: &str) -> Decision {
+        if let Err(decision) = self
+            .authorize(principal, GovernanceOperation::RevokeConsent, action)
+            .await
+        {
+            return decision;
+        }
+        {
+            let mut manager = self.consent.write().await;
+            manager.revoke(agent_id, action);
+        }
+        let decision = authorization_decision(true, principal, GovernanceOperation::RevokeConsent, action);
+        self.append_audit(decision.clone()).await;
+        decision
+    }
+
+    /// Let `grantor` authorise `delegate` to act on its behalf for `action`,
+    /// on behalf of `principal`.
+    ///
+    /// `principal` must hold [`GovernanceOperation::DelegateConsent`]
+    /// authority over `action`; an unauthorized caller gets back a denied
+    /// [`Decision`] and no delegation is recorded.
+    pub async fn delegate_consent(
+        &self,
+        grantor: &str,
+        delegate: &str,
+        action: &str,
+        principal: &str,
+    ) -> Decision {
+        if let Err(decision) = self
+            .authorize(principal, GovernanceOperation::DelegateConsent, action)
+            .await
+        {
+            return decision;
+        }
+        {
+            let mut manager = self.consent.write().await;
+            manager.delegate(grantor, delegate, action);
+        }
+        let decision = authorization_decision(true, principal, GovernanceOperation::DelegateConsent, action);
+        self.append_audit(decision.clone()).await;
+        decision
     }
 
-    /// Revoke consent for an agent / action pair.
-    pub async fn revoke_consent(&self, agent_id: &str, action: &str) {
-        let mut manager = self.consent.write().await;
-        manager.revoke(agent_id, action);
+    /// Withdraw a previously recorded delegation for `(delegate, action)`,
+    /// on behalf of `principal`.
+    ///
+    /// `principal` must hold [`GovernanceOperation::RevokeDelegation`]
+    /// authority over `action`; an unauthorized caller gets back a denied
+    /// [`Decision`] and the delegation is left untouched.
+    pub async fn revoke_delegation(&self, delegate: &str, action: &str, principal: &str) -> Decision {
+        if let Err(decision) = self
+            .authorize(principal, GovernanceOperation::RevokeDelegation, action)
+            .await
+        {
+            return decision;
+        }
+        {
+            let mut manager = self.consent.write().await;
+            manager.revoke_delegation(delegate, action);
+        }
+        let decision = authorization_decision(true, principal, GovernanceOperation::RevokeDelegation, action);
+        self.append_audit(decision.clone()).await;
+        decision
     }
 
     // -----------------------------------------------------------------------
@@ -205,9 +869,18 @@ impl<S: Storage> AsyncGovernanceEngine<S> {
     ///
     /// The evaluation pipeline is sequential:
     /// 1. Trust gate
-    /// 2. Budget gate (skipped when `ctx.cost` is `None`)
-    /// 3. Consent gate (skipped when `ctx.data_type` is `None`)
+    /// 2. Budget gate (skipped when `ctx.cost` is `None`); the debit runs
+    ///    under a checkpoint that step 3 can revert.
+    /// 3. Consent gate (skipped when `ctx.data_type` is `None`); denying here
+    ///    reverts step 2's debit.
     /// 4. Audit log (always written)
+    ///
+    /// A clock read that fails (the system clock reports a time before the
+    /// Unix epoch) is silently treated as `timestamp_ms = 0` rather than
+    /// denying the action. Callers in adversarial environments who need to
+    /// distinguish "denied by policy" from "the engine's clock is broken"
+    /// should use [`try_check`](Self::try_check) instead, which reports that
+    /// failure as a typed [`GovernanceError`] rather than a default value.
     pub async fn check(&self, action: &str, ctx: &Context) -> Decision {
         use std::time::{SystemTime, UNIX_EPOCH};
         let timestamp_ms = SystemTime::now()
@@ -215,6 +888,55 @@ impl<S: Storage> AsyncGovernanceEngine<S> {
             .unwrap_or_default()
             .as_millis() as u64;
 
+        self.evaluate_at(action, ctx, timestamp_ms).await
+    }
+
+    /// Fail-closed sibling of [`check`](Self::check): the same evaluation
+    /// pipeline, but a clock failure is reported as
+    /// [`GovernanceError::ClockError`] instead of silently defaulting
+    /// `timestamp_ms` to `0`.
+    ///
+    /// The policy is fail-closed either way: a clock failure still produces
+    /// a denied [`Decision`] (carried inside the error, with reason
+    /// `"Clock failure; denying fail-closed."`) and that denial is still
+    /// written to the audit log — this method only changes how the caller
+    /// learns about it, from a silently-wrong timestamp to an explicit
+    /// `Err`.
+    pub async fn try_check(&self, action: &str, ctx: &Context) -> Result<Decision, GovernanceError> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(duration) => Ok(self.evaluate_at(action, ctx, duration.as_millis() as u64).await),
+            Err(_) => {
+                let decision = clock_error_decision(action, ctx);
+                self.append_audit(decision.clone()).await;
+                Err(GovernanceError::ClockError(decision))
+            }
+        }
+    }
+
+    /// Shared gate pipeline for [`check`](Self::check) and
+    /// [`try_check`](Self::try_check), evaluated as of a caller-supplied
+    /// `timestamp_ms`.
+    async fn evaluate_at(&self, action: &str, ctx: &Context, timestamp_ms: u64) -> Decision {
+        // Step 0: Pause gate. Short-circuits before any other gate runs.
+        if self.is_paused() {
+            let decision = Decision {
+                permitted: false,
+                trust: skipped_trust_result(),
+                budget: skipped_budget_result(&ctx.category),
+                consent: skipped_consent_result(),
+                action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
+                timestamp_ms,
+                reason: "Engine paused.".into(),
+                policy_epoch: 0,
+                policy_hash: String::new(),
+            };
+            self.append_audit(decision.clone()).await;
+            return decision;
+        }
+
         // Step 1: Trust gate.
         let trust_result: TrustResult = {
             let manager = self.trust.read().await;
@@ -228,81 +950,370 @@ impl<S: Storage> AsyncGovernanceEngine<S> {
                 budget: skipped_budget_result(&ctx.category),
                 consent: skipped_consent_result(),
                 action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
                 timestamp_ms,
                 reason: "Trust gate denied.".into(),
+                policy_epoch: 0,
+                policy_hash: String::new(),
             };
             self.append_audit(decision.clone()).await;
             return decision;
         }
 
-        // Step 2: Budget gate.
+        // Steps 2-3: Budget gate, then consent gate. A single write-lock
+        // acquisition spans the checkpoint's entire open-to-close lifetime —
+        // including across the step 3 consent check's `.await` — so no other
+        // concurrent `check`/`schedule`/`check_batch` call can open its own
+        // checkpoint on top of this one and have this call's eventual
+        // `revert_to` unwind past it. See the module's "Budget lock
+        // discipline" note.
+        let mut budget_manager = self.budget.write().await;
+        let budget_checkpoint = budget_manager.checkpoint();
+        let mut budget_record_error = None;
         let budget_result: BudgetResult = match ctx.cost {
             Some(amount) if amount > 0.0 => {
-                let result = {
-                    let manager = self.budget.read().await;
-                    manager.check(&ctx.category, amount)
-                };
+                let result = budget_manager.check(&ctx.category, amount, timestamp_ms);
                 if result.permitted {
-                    let mut manager = self.budget.write().await;
-                    manager.record(&ctx.category, amount);
+                    if let Err(error) = budget_manager.record(&ctx.category, amount, timestamp_ms) {
+                        budget_record_error = Some(error);
+                    }
                 }
                 result
             }
             _ => skipped_budget_result(&ctx.category),
         };
 
+        if let Some(error) = budget_record_error {
+            // Nothing was debited, so there's nothing to revert.
+            budget_manager.discard(budget_checkpoint);
+            drop(budget_manager);
+            let decision = Decision {
+                permitted: false,
+                trust: trust_result,
+                budget: BudgetResult {
+                    permitted: false,
+                    available: budget_result.available,
+                    requested: ctx.cost.unwrap_or(0.0),
+                    category: ctx.category.clone(),
+                    reason: format!("{}", error),
+                    dimension: None,
+                },
+                consent: skipped_consent_result(),
+                action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
+                timestamp_ms,
+                reason: "Budget gate denied.".into(),
+                policy_epoch: 0,
+                policy_hash: String::new(),
+            };
+            self.append_audit(decision.clone()).await;
+            return decision;
+        }
+
         if !budget_result.permitted {
+            budget_manager.discard(budget_checkpoint);
+            drop(budget_manager);
             let decision = Decision {
                 permitted: false,
                 trust: trust_result,
                 budget: budget_result,
                 consent: skipped_consent_result(),
                 action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
                 timestamp_ms,
                 reason: "Budget gate denied.".into(),
+                policy_epoch: 0,
+                policy_hash: String::new(),
             };
             self.append_audit(decision.clone()).await;
             return decision;
         }
 
-        // Step 3: Consent gate.
+        // Step 3: Consent gate. `budget_manager` (and its write lock) is
+        // still held here, on purpose — see the steps 2-3 comment above.
         let consent_result: ConsentResult = match &ctx.data_type {
             Some(data_type) => {
                 let manager = self.consent.read().await;
-                manager.check(&ctx.agent_id, data_type)
+                manager.check(&ctx.agent_id, data_type, ctx, timestamp_ms)
             }
             None => skipped_consent_result(),
         };
 
         if !consent_result.permitted {
+            // Consent denied after step 2's debit — revert it so the agent
+            // isn't charged for a refused action.
+            budget_manager.revert_to(budget_checkpoint);
+            drop(budget_manager);
             let decision = Decision {
                 permitted: false,
                 trust: trust_result,
                 budget: budget_result,
                 consent: consent_result,
                 action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
                 timestamp_ms,
                 reason: "Consent gate denied.".into(),
+                policy_epoch: 0,
+                policy_hash: String::new(),
             };
             self.append_audit(decision.clone()).await;
             return decision;
         }
 
-        // Step 4: All gates passed.
+        // Step 4: All gates passed — commit the budget debit.
+        budget_manager.discard(budget_checkpoint);
+        drop(budget_manager);
         let decision = Decision {
             permitted: true,
             trust: trust_result,
             budget: budget_result,
             consent: consent_result,
             action: action.into(),
+            agent_id: ctx.agent_id.clone(),
+            scope: ctx.scope.clone(),
             timestamp_ms,
             reason: "All governance gates passed.".into(),
+            policy_epoch: 0,
+            policy_hash: String::new(),
         };
 
         self.append_audit(decision.clone()).await;
         decision
     }
 
+    /// Evaluate many `(action, Context)` pairs with bounded lock
+    /// acquisition: one trust read lock, one budget write lock (held for the
+    /// whole batch, per the module's "Budget lock discipline" note), one
+    /// consent read lock, and one audit write lock for the whole batch —
+    /// instead of acquiring each manager's lock once per action as a loop of
+    /// [`check`](Self::check) calls would.
+    ///
+    /// Per-action results are identical to calling `check` on each pair in
+    /// sequence: the same gate-skip semantics, the same running budget
+    /// depletion (costed actions are checked and recorded against their
+    /// category's headroom in order), and the same checkpoint discipline —
+    /// each costed action's debit runs under its own
+    /// [`checkpoint`](crate::budget::BudgetManager::checkpoint), opened
+    /// before the debit and reverted if that action's own consent check
+    /// later denies it, exactly as `check`'s step 2/step 3 do. The
+    /// checkpoints are reverted (or discarded) in reverse action order once
+    /// every consent result is known, so an earlier action's checkpoint
+    /// never observes a later action's debit as part of its own revert.
+    pub async fn check_batch(&self, actions: &[(String, Context)]) -> Vec<Decision> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+
+        if self.is_paused() {
+            let decisions: Vec<Decision> = actions
+                .iter()
+                .map(|(action, ctx)| Decision {
+                    permitted: false,
+                    trust: skipped_trust_result(),
+                    budget: skipped_budget_result(&ctx.category),
+                    consent: skipped_consent_result(),
+                    action: action.clone(),
+                    agent_id: ctx.agent_id.clone(),
+                    scope: ctx.scope.clone(),
+                    timestamp_ms,
+                    reason: "Engine paused.".into(),
+                    policy_epoch: 0,
+                    policy_hash: String::new(),
+                })
+                .collect();
+            self.append_audit_batch(&decisions).await;
+            return decisions;
+        }
+
+        // Step 1: Trust gate, one read lock for the whole batch.
+        let trust_results: Vec<TrustResult> = {
+            let manager = self.trust.read().await;
+            actions
+                .iter()
+                .map(|(_, ctx)| manager.check_level(&ctx.agent_id, &ctx.scope, ctx.required_trust))
+                .collect()
+        };
+
+        // Steps 2-3: Budget gate, then consent gate. A *single* budget
+        // write-lock acquisition spans from the first checkpoint opened
+        // below to the last one closed after the consent pass — including
+        // across the step 3 consent check's `.await` — so no other
+        // concurrent `check`/`schedule`/`check_batch` call can interleave a
+        // checkpoint of its own in between this batch's debits and their
+        // eventual revert/discard. See the module's "Budget lock
+        // discipline" note. An action whose trust gate already denied never
+        // reaches the budget manager — exactly as it wouldn't reach it in
+        // `check`. Every costed action's debit runs under its own
+        // checkpoint (opened immediately before the debit, same as
+        // `check`'s step 2), so the consent pass below can revert exactly
+        // that action's debit without touching any other action's.
+        let mut budget_results: Vec<BudgetResult> = Vec::with_capacity(actions.len());
+        let mut budget_record_errors: Vec<Option<crate::budget::BudgetError>> = Vec::with_capacity(actions.len());
+        let mut budget_checkpoints: Vec<Option<crate::budget::CheckpointId>> = Vec::with_capacity(actions.len());
+        let mut budget_manager = self.budget.write().await;
+        for ((_, ctx), trust_result) in actions.iter().zip(&trust_results) {
+            if !trust_result.permitted {
+                budget_results.push(skipped_budget_result(&ctx.category));
+                budget_record_errors.push(None);
+                budget_checkpoints.push(None);
+                continue;
+            }
+            match ctx.cost {
+                Some(amount) if amount > 0.0 => {
+                    let checkpoint = budget_manager.checkpoint();
+                    let result = budget_manager.check(&ctx.category, amount, timestamp_ms);
+                    let mut record_error = None;
+                    if result.permitted {
+                        if let Err(error) = budget_manager.record(&ctx.category, amount, timestamp_ms) {
+                            record_error = Some(error);
+                        }
+                    }
+                    budget_results.push(result);
+                    budget_record_errors.push(record_error);
+                    budget_checkpoints.push(Some(checkpoint));
+                }
+                _ => {
+                    budget_results.push(skipped_budget_result(&ctx.category));
+                    budget_record_errors.push(None);
+                    budget_checkpoints.push(None);
+                }
+            }
+        }
+
+        // Step 3: Consent gate, one read lock for the whole batch. Computed
+        // for every action regardless of earlier gates (the check is a pure
+        // read with no side effects) and discarded below where `check`
+        // would never have reached it. `budget_manager` (and its write
+        // lock) is still held here, on purpose — see the comment above.
+        let consent_results: Vec<ConsentResult> = {
+            let manager = self.consent.read().await;
+            actions
+                .iter()
+                .map(|(_, ctx)| match &ctx.data_type {
+                    Some(data_type) => manager.check(&ctx.agent_id, data_type, ctx, timestamp_ms),
+                    None => skipped_consent_result(),
+                })
+                .collect()
+        };
+
+        // Close out every open checkpoint in reverse action order: a denied
+        // consent result reverts its action's debit (matching `check`'s step
+        // 3), anything else discards the checkpoint and keeps the debit.
+        // Reverse order matters because the checkpoints were opened as a
+        // LIFO stack — closing the most-recently-opened one first keeps
+        // each `revert_to`/`discard` scoped to exactly its own action.
+        for i in (0..actions.len()).rev() {
+            let Some(checkpoint) = budget_checkpoints[i] else {
+                continue;
+            };
+            let debited = budget_record_errors[i].is_none() && budget_results[i].permitted;
+            if debited && !consent_results[i].permitted {
+                budget_manager.revert_to(checkpoint);
+            } else {
+                budget_manager.discard(checkpoint);
+            }
+        }
+        drop(budget_manager);
+
+        // Assemble the per-action decisions, mirroring `check`'s exact
+        // short-circuit precedence.
+        let mut decisions = Vec::with_capacity(actions.len());
+        for (i, (action, ctx)) in actions.iter().enumerate() {
+            let trust_result = trust_results[i].clone();
+            let budget_result = budget_results[i].clone();
+
+            let decision = if !trust_result.permitted {
+                Decision {
+                    permitted: false,
+                    trust: trust_result,
+                    budget: budget_result,
+                    consent: skipped_consent_result(),
+                    action: action.clone(),
+                    agent_id: ctx.agent_id.clone(),
+                    scope: ctx.scope.clone(),
+                    timestamp_ms,
+                    reason: "Trust gate denied.".into(),
+                    policy_epoch: 0,
+                    policy_hash: String::new(),
+                }
+            } else if let Some(error) = &budget_record_errors[i] {
+                Decision {
+                    permitted: false,
+                    trust: trust_result,
+                    budget: BudgetResult {
+                        permitted: false,
+                        available: budget_result.available,
+                        requested: ctx.cost.unwrap_or(0.0),
+                        category: ctx.category.clone(),
+                        reason: format!("{}", error),
+                        dimension: None,
+                    },
+                    consent: skipped_consent_result(),
+                    action: action.clone(),
+                    agent_id: ctx.agent_id.clone(),
+                    scope: ctx.scope.clone(),
+                    timestamp_ms,
+                    reason: "Budget gate denied.".into(),
+                    policy_epoch: 0,
+                    policy_hash: String::new(),
+                }
+            } else if !budget_result.permitted {
+                Decision {
+                    permitted: false,
+                    trust: trust_result,
+                    budget: budget_result,
+                    consent: skipped_consent_result(),
+                    action: action.clone(),
+                    agent_id: ctx.agent_id.clone(),
+                    scope: ctx.scope.clone(),
+                    timestamp_ms,
+                    reason: "Budget gate denied.".into(),
+                    policy_epoch: 0,
+                    policy_hash: String::new(),
+                }
+            } else if !consent_results[i].permitted {
+                Decision {
+                    permitted: false,
+                    trust: trust_result,
+                    budget: budget_result,
+                    consent: consent_results[i].clone(),
+                    action: action.clone(),
+                    agent_id: ctx.agent_id.clone(),
+                    scope: ctx.scope.clone(),
+                    timestamp_ms,
+                    reason: "Consent gate denied.".into(),
+                    policy_epoch: 0,
+                    policy_hash: String::new(),
+                }
+            } else {
+                Decision {
+                    permitted: true,
+                    trust: trust_result,
+                    budget: budget_result,
+                    consent: consent_results[i].clone(),
+                    action: action.clone(),
+                    agent_id: ctx.agent_id.clone(),
+                    scope: ctx.scope.clone(),
+                    timestamp_ms,
+                    reason: "All governance gates passed.".into(),
+                    policy_epoch: 0,
+                    policy_hash: String::new(),
+                }
+            };
+
+            decisions.push(decision);
+        }
+
+        self.append_audit_batch(&decisions).await;
+        decisions
+    }
+
     // -----------------------------------------------------------------------
     // Audit
     // -----------------------------------------------------------------------
@@ -313,6 +1324,15 @@ impl<S: Storage> AsyncGovernanceEngine<S> {
         logger.log(decision);
     }
 
+    /// Append every decision in `decisions` under a single audit write
+    /// lock, in order — the batch counterpart to [`append_audit`](Self::append_audit).
+    async fn append_audit_batch(&self, decisions: &[Decision]) {
+        let mut logger = self.audit.write().await;
+        for decision in decisions {
+            logger.log(decision.clone());
+        }
+    }
+
     /// Query the audit log asynchronously.
     pub async fn query_audit(&self, filter: &AuditFilter) -> Vec<AuditRecord> {
         let logger = self.audit.read().await;
@@ -320,6 +1340,69 @@ impl<S: Storage> AsyncGovernanceEngine<S> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// GovernanceError
+// ---------------------------------------------------------------------------
+
+/// Errors surfaced by the fail-closed `try_*` API surface (
+/// [`try_check`](AsyncGovernanceEngine::try_check),
+/// [`try_pause`](AsyncGovernanceEngine::try_pause),
+/// [`try_resume`](AsyncGovernanceEngine::try_resume)).
+///
+/// Every variant here is an *engine malfunction*, not a policy denial —
+/// `check`/`pause`/`resume` already express "denied by policy" via a
+/// [`Decision`] with `permitted: false`. `GovernanceError` is for the case
+/// where the engine itself couldn't produce a trustworthy decision at all,
+/// so a caller in an adversarial environment can tell the two apart instead
+/// of both looking like an ordinary denial. The accompanying `Decision` in
+/// each variant has already been written to the audit log with a
+/// distinguishable reason before the error is returned — the policy is
+/// fail-closed either way.
+///
+/// Only clock failure is modelled today, since `tokio::sync::RwLock` never
+/// returns an error and this crate's [`Storage`] trait is infallible. New
+/// variants belong here if a future storage backend or lock primitive gains
+/// a real failure path.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GovernanceError {
+    /// The system clock reported a time before the Unix epoch, so no
+    /// reliable timestamp could be produced.
+    ClockError(Decision),
+}
+
+impl core::fmt::Display for GovernanceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GovernanceError::ClockError(decision) => write!(
+                f,
+                "system clock is before the Unix epoch; denied '{}' fail-closed",
+                decision.action
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GovernanceError {}
+
+/// Build the denied [`Decision`] recorded when a fallible clock read fails
+/// before any gate could run. Unlike a policy denial, no gate actually ran —
+/// every sub-result is the neutral "skipped" placeholder.
+fn clock_error_decision(action: &str, ctx: &Context) -> Decision {
+    Decision {
+        permitted: false,
+        trust: skipped_trust_result(),
+        budget: skipped_budget_result(&ctx.category),
+        consent: skipped_consent_result(),
+        action: action.into(),
+        agent_id: ctx.agent_id.clone(),
+        scope: ctx.scope.clone(),
+        timestamp_ms: 0,
+        reason: "Clock failure; denying fail-closed.".into(),
+        policy_epoch: 0,
+        policy_hash: String::new(),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Helpers (mirror the sync engine)
 // ---------------------------------------------------------------------------
@@ -331,6 +1414,7 @@ fn skipped_budget_result(category: &str) -> BudgetResult {
         requested: 0.0,
         category: category.into(),
         reason: "Budget gate skipped (no cost specified).".into(),
+        dimension: None,
     }
 }
 
@@ -340,3 +1424,279 @@ fn skipped_consent_result() -> ConsentResult {
         reason: "Consent gate skipped (no data type specified).".into(),
     }
 }
+
+/// A neutral [`TrustResult`] for decisions where the trust gate never ran,
+/// e.g. the pause short-circuit in [`AsyncGovernanceEngine::check`].
+fn skipped_trust_result() -> TrustResult {
+    TrustResult {
+        permitted: true,
+        current_level: TrustLevel::Observer,
+        required_level: TrustLevel::Observer,
+        reason: "Trust gate skipped.".into(),
+    }
+}
+
+/// Build the audit-log [`Decision`] for a pause/resume transition, so the
+/// halt (and its lift) is attributable to the operator who triggered it.
+fn pause_transition_decision(permitted: bool, action: &str, by: &str) -> Decision {
+    // A clock failure here is swallowed into `timestamp_ms = 0` for
+    // backward compatibility with the infallible `pause`/`resume` API —
+    // use `try_pause`/`try_resume` to surface it as a `GovernanceError`
+    // instead.
+    match try_pause_transition_decision(permitted, action, by) {
+        Ok(decision) => decision,
+        Err(decision) => decision,
+    }
+}
+
+/// Build the audit-log [`Decision`] for a pause/resume transition, failing
+/// closed (`Err`, with a clock-failure [`Decision`]) if the system clock
+/// reports a time before the Unix epoch rather than stamping the transition
+/// with an unreliable timestamp.
+fn try_pause_transition_decision(permitted: bool, action: &str, by: &str) -> Result<Decision, Decision> {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => Ok(Decision {
+            permitted,
+            trust: skipped_trust_result(),
+            budget: skipped_budget_result("system"),
+            consent: skipped_consent_result(),
+            action: action.into(),
+            agent_id: by.to_string(),
+            scope: "system".into(),
+            timestamp_ms: duration.as_millis() as u64,
+            reason: format!("Triggered by {}.", by),
+            policy_epoch: 0,
+            policy_hash: String::new(),
+        }),
+        Err(_) => Err(Decision {
+            permitted: false,
+            trust: skipped_trust_result(),
+            budget: skipped_budget_result("system"),
+            consent: skipped_consent_result(),
+            action: action.into(),
+            agent_id: by.to_string(),
+            scope: "system".into(),
+            timestamp_ms: 0,
+            reason: "Clock failure; denying fail-closed.".into(),
+            policy_epoch: 0,
+            policy_hash: String::new(),
+        }),
+    }
+}
+
+/// Build the audit-log [`Decision`] recording an authorization check for a
+/// governance-state mutation. Unlike a gate [`Decision`], no trust, budget,
+/// or consent gate ran — every sub-result is the neutral "skipped"
+/// placeholder, and `action`/`scope` name the operation and target rather
+/// than an agent's action and scope.
+fn authorization_decision(
+    permitted: bool,
+    principal: &str,
+    op: GovernanceOperation,
+    scope: &str,
+) -> Decision {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let timestamp_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    let reason = if permitted {
+        format!(
+            "'{}' is authorized to perform '{}' on scope '{}'.",
+            principal,
+            op.as_str(),
+            scope
+        )
+    } else {
+        format!(
+            "'{}' is not authorized to perform '{}' on scope '{}'.",
+            principal,
+            op.as_str(),
+            scope
+        )
+    };
+
+    Decision {
+        permitted,
+        trust: skipped_trust_result(),
+        budget: skipped_budget_result(scope),
+        consent: skipped_consent_result(),
+        action: op.as_str().into(),
+        agent_id: principal.into(),
+        scope: scope.into(),
+        timestamp_ms,
+        reason,
+        policy_epoch: 0,
+        policy_hash: String::new(),
+    }
+}
+
+/// Build the audit-log [`Decision`] for vetoing a pending
+/// [`AsyncGovernanceEngine::schedule`]d action, so the veto is attributable
+/// to the caller who issued it rather than silently erasing the original
+/// approval.
+fn scheduled_cancel_decision(original: &Decision, canceled_by: &str) -> Decision {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let timestamp_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+
+    Decision {
+        permitted: false,
+        trust: skipped_trust_result(),
+        budget: skipped_budget_result(&original.budget.category),
+        consent: skipped_consent_result(),
+        action: "scheduled_action_canceled".into(),
+        agent_id: canceled_by.to_string(),
+        scope: original.scope.clone(),
+        timestamp_ms,
+        reason: format!(
+            "Canceled scheduled action '{}' for agent '{}' before its execution window.",
+            original.action, original.agent_id
+        ),
+        policy_epoch: 0,
+        policy_hash: String::new(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+
+    const AGENT: &str = "agent-001";
+    const SCOPE: &str = "ops";
+    const CATEGORY: &str = "financial";
+
+    fn engine(trust_level: TrustLevel, budget_limit: f64) -> AsyncGovernanceEngine<InMemoryStorage> {
+        let storage = InMemoryStorage::new();
+        let mut trust = TrustManager::new(Config::default(), storage.clone());
+        trust.set_level(AGENT, SCOPE, trust_level, "owner");
+        let mut budget = BudgetManager::new(Config::default(), storage.clone());
+        budget.create_envelope(CATEGORY, budget_limit, 0, 0);
+        let consent = ConsentManager::new(Config::default(), storage.clone());
+        let audit = AuditLogger::new(storage.clone());
+        let authorization = AuthorizationManager::new(storage);
+        AsyncGovernanceEngine::from_parts(trust, budget, consent, audit, authorization)
+    }
+
+    fn ctx(cost: Option<f64>, data_type: Option<&str>) -> Context {
+        Context {
+            agent_id: AGENT.into(),
+            scope: SCOPE.into(),
+            required_trust: TrustLevel::Suggest,
+            cost,
+            category: CATEGORY.into(),
+            data_type: data_type.map(String::from),
+            purpose: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn schedule_denies_on_trust_gate_without_touching_budget() {
+        let engine = engine(TrustLevel::Observer, 500.0);
+
+        let result = engine.schedule("act", &ctx(Some(100.0), None), 0).await;
+
+        assert!(result.is_err());
+        assert_eq!(engine.check_budget(CATEGORY, 0.0, 0).await.available, 500.0);
+    }
+
+    #[tokio::test]
+    async fn schedule_denies_on_budget_gate_and_leaves_the_checkpoint_discarded() {
+        let engine = engine(TrustLevel::Suggest, 50.0);
+
+        let result = engine.schedule("act", &ctx(Some(100.0), None), 0).await;
+
+        assert!(result.is_err());
+        assert_eq!(engine.check_budget(CATEGORY, 0.0, 0).await.available, 50.0);
+    }
+
+    /// Regression test: a consent denial must revert the budget debit made
+    /// by the budget gate just before it, not leak it — `schedule` holds no
+    /// `PendingAction` for a denied action, so `cancel_scheduled` can never
+    /// run to refund it later.
+    #[tokio::test]
+    async fn schedule_refunds_the_budget_reservation_when_consent_gate_denies() {
+        let engine = engine(TrustLevel::Suggest, 500.0);
+
+        // No consent has been recorded for "read_logs", so the consent gate
+        // denies by default.
+        let result = engine.schedule("act", &ctx(Some(100.0), Some("read_logs")), 0).await;
+
+        assert!(result.is_err());
+        assert_eq!(engine.check_budget(CATEGORY, 0.0, 0).await.available, 500.0);
+    }
+
+    /// Regression test: in `check_batch`, an action denied by its own
+    /// consent check must have its budget debit reverted — identical to
+    /// what a sequential `check` loop would do — and that revert must not
+    /// disturb another action's debit in the same batch.
+    #[tokio::test]
+    async fn check_batch_refunds_only_the_action_that_consent_denies() {
+        let engine = engine(TrustLevel::Suggest, 500.0);
+
+        let actions = vec![
+            ("act-a".to_string(), ctx(Some(100.0), None)),
+            // No consent has been recorded for "read_logs", so this one is
+            // denied by the consent gate after its debit already ran.
+            ("act-b".to_string(), ctx(Some(100.0), Some("read_logs"))),
+            ("act-c".to_string(), ctx(Some(100.0), None)),
+        ];
+
+        let decisions = engine.check_batch(&actions).await;
+
+        assert!(decisions[0].permitted);
+        assert!(!decisions[1].permitted);
+        assert!(decisions[2].permitted);
+
+        // Only act-a and act-c's 100.0 debits should stick; act-b's must
+        // have been refunded, leaving 500.0 - 100.0 - 100.0 = 300.0.
+        assert_eq!(engine.check_budget(CATEGORY, 0.0, 0).await.available, 300.0);
+    }
+
+    #[tokio::test]
+    async fn concurrent_check_calls_do_not_corrupt_each_others_budget_debit() {
+        let engine = engine(TrustLevel::Suggest, 500.0);
+
+        // act-b is denied by consent and reverts its own debit; act-a and
+        // act-c have no consent requirement, so their debits stick. Each
+        // `check` call holds the budget write lock for its entire
+        // checkpoint-open-to-close span (see the module's "Budget lock
+        // discipline" note), so however `tokio::join!` interleaves these
+        // three futures at their await points, act-b's revert can only ever
+        // unwind its own checkpoint — never a concurrently-committed debit
+        // belonging to act-a or act-c.
+        let (a, b, c) = tokio::join!(
+            engine.check("act-a", &ctx(Some(100.0), None)),
+            engine.check("act-b", &ctx(Some(100.0), Some("read_logs"))),
+            engine.check("act-c", &ctx(Some(100.0), None)),
+        );
+
+        assert!(a.permitted);
+        assert!(!b.permitted);
+        assert!(c.permitted);
+        assert_eq!(engine.check_budget(CATEGORY, 0.0, 0).await.available, 300.0);
+    }
+
+    #[tokio::test]
+    async fn schedule_reserves_budget_and_cancel_scheduled_refunds_it() {
+        let engine = engine(TrustLevel::Suggest, 500.0);
+
+        let id = engine
+            .schedule("act", &ctx(Some(100.0), None), 0)
+            .await
+            .expect("all gates should pass");
+        assert_eq!(engine.check_budget(CATEGORY, 0.0, 0).await.available, 400.0);
+
+        engine.cancel_scheduled(id, "owner").await;
+        assert_eq!(engine.check_budget(CATEGORY, 0.0, 0).await.available, 500.0);
+    }
+}
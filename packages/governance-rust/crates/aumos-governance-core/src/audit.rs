@@ -3,20 +3,39 @@
 
 //! Audit log management.
 //!
-//! [`AuditLogger`] exposes two operations only:
+//! [`AuditLogger`] exposes these operations:
 //!
-//! * [`log`](AuditLogger::log)     — record a governance decision
-//! * [`query`](AuditLogger::query) — search / filter the audit chain
+//! * [`log`](AuditLogger::log)             — record a governance decision
+//! * [`query`](AuditLogger::query)         — search / filter the audit chain
+//! * [`verify_chain`](AuditLogger::verify_chain) — check the chain for tampering
+//! * [`root`](AuditLogger::root)           — Merkle root over every record's hash
+//! * [`prove`](AuditLogger::prove)         — compact inclusion proof for one record
 //!
 //! Records are chained via SHA-256 hashes to form a tamper-evident log.
 //! The log is **recording only** — there is no anomaly detection, no
 //! counterfactual generation, and no real-time alerting.
+//!
+//! ## Inclusion proofs
+//!
+//! `verify_chain` proves the *whole* log is untampered, but doing so means
+//! walking every record. `root`/`prove` instead maintain a Merkle tree over
+//! the same per-record hashes used by the chain, so an auditor holding only
+//! the current root and one record can confirm that record is genuinely part
+//! of the log using `prove`'s ~log₂(n)-sized [`InclusionProof`] and the free
+//! [`verify`] function — without access to, or trust in, the rest of the log.
 
+use alloc::sync::Arc;
 use alloc::string::String;
 use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
 
+use crate::clock::Clock;
+use crate::clock_policy::{ClockPolicy, ClockPolicyError};
+use crate::policy::PolicyChange;
 use crate::storage::Storage;
-use crate::types::{AuditFilter, AuditRecord, Decision};
+use crate::types::{
+    AuditFilter, AuditRecord, BudgetResult, ConsentResult, Decision, TrustLevel, TrustResult,
+};
 
 /// Records governance decisions in a chained, tamper-evident audit log.
 ///
@@ -34,8 +53,12 @@ use crate::types::{AuditFilter, AuditRecord, Decision};
 /// let decision = Decision {
 ///     permitted: true,
 ///     action: "send_report".into(),
+///     agent_id: "agent-001".into(),
+///     scope: "default".into(),
 ///     timestamp_ms: 0,
 ///     reason: "PERMIT".into(),
+///     policy_epoch: 0,
+///     policy_hash: String::new(),
 ///     trust: TrustResult {
 ///         permitted: true,
 ///         current_level: TrustLevel::ActAndReport,
@@ -48,6 +71,7 @@ use crate::types::{AuditFilter, AuditRecord, Decision};
 ///         requested: 0.0,
 ///         category: "default".into(),
 ///         reason: "ok".into(),
+///         dimension: None,
 ///     },
 ///     consent: ConsentResult {
 ///         permitted: true,
@@ -59,11 +83,43 @@ use crate::types::{AuditFilter, AuditRecord, Decision};
 ///
 /// let records = logger.query(&AuditFilter::default());
 /// assert_eq!(records.len(), 1);
+/// assert!(logger.verify_chain().is_ok());
+///
+/// // Agent-scoped queries hit a secondary index, not a full scan.
+/// let by_agent = logger.query(&AuditFilter {
+///     agent_id: Some("agent-001".into()),
+///     ..AuditFilter::default()
+/// });
+/// assert_eq!(by_agent.len(), 1);
+/// assert!(logger.query(&AuditFilter {
+///     agent_id: Some("agent-002".into()),
+///     ..AuditFilter::default()
+/// }).is_empty());
 /// ```
 pub struct AuditLogger<S: Storage> {
     storage: S,
     /// Hash of the most recently appended record (genesis = 64 zeros).
     last_hash: String,
+    /// Leaf hashes of the Merkle accumulator, in append order — one per
+    /// logged record, mirroring `record.hash`. Updated incrementally (one
+    /// push) by [`log`](Self::log); [`root`](Self::root) and
+    /// [`prove`](Self::prove) rebuild the tree above this vector on demand.
+    leaves: Vec<String>,
+    /// Time source for [`log_now`](Self::log_now) and for
+    /// [`GatePipeline`](crate::gate::GatePipeline)'s own entry timestamp.
+    /// [`log`](Self::log) itself stays on `decision.timestamp_ms` — this
+    /// clock only backs callers that want the logger to stamp it instead.
+    clock: Arc<dyn Clock + Send + Sync>,
+    /// History of [`checkpoint_root`](Self::checkpoint_root) snapshots, oldest first.
+    root_checkpoints: Vec<RootCheckpoint>,
+    /// Clock-skew and time-ordering bound consulted only by
+    /// [`log_checked`](Self::log_checked); [`log`](Self::log) itself never
+    /// rejects a record on its account.
+    clock_policy: ClockPolicy,
+    /// `timestamp_ms` of the most recently appended record (`0` for an
+    /// empty log), checked by [`log_checked`](Self::log_checked) against the
+    /// next record's timestamp to keep the chain time-ordered.
+    last_timestamp_ms: u64,
 }
 
 impl<S: Storage> AuditLogger<S> {
@@ -72,9 +128,89 @@ impl<S: Storage> AuditLogger<S> {
         Self {
             storage,
             last_hash: "0".repeat(64),
+            leaves: Vec::new(),
+            clock: crate::clock::default_clock(),
+            root_checkpoints: Vec::new(),
+            clock_policy: ClockPolicy::default(),
+            last_timestamp_ms: 0,
         }
     }
 
+    /// Install `clock` as this logger's time source, replacing the default.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Install `clock_policy` as this logger's clock-skew policy, replacing
+    /// the default.
+    pub fn with_clock_policy(mut self, clock_policy: ClockPolicy) -> Self {
+        self.clock_policy = clock_policy;
+        self
+    }
+
+    /// This logger's injected [`Clock`], read directly.
+    pub(crate) fn now_ms(&self) -> u64 {
+        self.clock.now_ms()
+    }
+
+    /// Like [`log`](Self::log), but overwrites `decision.timestamp_ms` with
+    /// this logger's injected [`Clock`] before appending it.
+    pub fn log_now(&mut self, mut decision: Decision) {
+        decision.timestamp_ms = self.clock.now_ms();
+        self.log(decision);
+    }
+
+    /// Append `change` (a [`PolicySet::apply`](crate::policy::PolicySet::apply)
+    /// result) to the audit chain as its own record, stamped with `change`'s
+    /// resulting epoch/hash, so a policy upgrade is as auditable as any
+    /// governed action rather than a silent in-place mutation.
+    pub fn log_policy_change(&mut self, change: &PolicyChange, timestamp_ms: u64) {
+        let decision = Decision {
+            permitted: true,
+            trust: TrustResult {
+                permitted: true,
+                current_level: TrustLevel::Autonomous,
+                required_level: TrustLevel::Autonomous,
+                reason: "Policy change applied by the governing operator.".into(),
+            },
+            budget: BudgetResult {
+                permitted: true,
+                available: 0.0,
+                requested: 0.0,
+                category: "policy".into(),
+                reason: "Not a spend; no budget gate applies to a policy change.".into(),
+                dimension: None,
+            },
+            consent: ConsentResult {
+                permitted: true,
+                reason: "Not a consent-gated action.".into(),
+            },
+            action: "governance:apply_policy_instruction".into(),
+            agent_id: "governance-operator".into(),
+            scope: "policy".into(),
+            timestamp_ms,
+            reason: format!("{:?}", change.instruction),
+            policy_epoch: change.after.epoch,
+            policy_hash: change.after.hash.clone(),
+        };
+        self.log(decision);
+    }
+
+    /// Like [`log`](Self::log), but rejects `decision.timestamp_ms` unless
+    /// it passes this logger's [`ClockPolicy`]: not more than
+    /// `max_forward_drift_ms` ahead of this logger's own [`Clock`], and not
+    /// earlier than the previously appended record's timestamp. Nothing is
+    /// written on rejection.
+    pub fn log_checked(&mut self, decision: Decision) -> Result<(), ClockPolicyError> {
+        self.clock_policy
+            .check_forward_drift("timestamp_ms", decision.timestamp_ms, self.clock.now_ms())?;
+        self.clock_policy
+            .check_monotonic(self.last_timestamp_ms, decision.timestamp_ms)?;
+        self.log(decision);
+        Ok(())
+    }
+
     /// Append a governance decision to the audit chain.
     ///
     /// The record's `prev_hash` is set to the hash of the previous record
@@ -86,12 +222,14 @@ impl<S: Storage> AuditLogger<S> {
     pub fn log(&mut self, decision: Decision) {
         let timestamp_ms = decision.timestamp_ms;
         let action = decision.action.clone();
+        let agent_id = decision.agent_id.clone();
+        let scope = decision.scope.clone();
+        let policy_epoch = decision.policy_epoch;
+        let policy_hash = decision.policy_hash.clone();
 
-        let hash = compute_hash(&decision, &self.last_hash);
+        let hash = recompute_hash(&decision, &self.last_hash);
         let prev_hash = self.last_hash.clone();
 
-        // Build a record id that embeds the agent context so queries can
-        // filter by agent without deserialising every record.
         let record_id = format!("{}-{}", action, &hash[..8]);
 
         let record = AuditRecord {
@@ -100,9 +238,22 @@ impl<S: Storage> AuditLogger<S> {
             hash: hash.clone(),
             prev_hash,
             timestamp_ms,
+            // Detached signing, when configured, is applied by the storage
+            // backend (e.g. `FileStorage`) at append time — the core crate
+            // stays `no_std` and does not depend on a signature scheme.
+            signature: None,
+            // Duplicated out of `decision` so `Storage::query_audit`
+            // implementations can index agent/scope lookups without
+            // deserialising the nested decision.
+            agent_id,
+            scope,
+            policy_epoch,
+            policy_hash,
         };
 
         self.last_hash = hash;
+        self.last_timestamp_ms = timestamp_ms;
+        self.leaves.push(record.hash.clone());
         self.storage.append_audit(record);
     }
 
@@ -133,53 +284,296 @@ impl<S: Storage> AuditLogger<S> {
         &self.last_hash
     }
 
+    /// Walk the full audit log, recomputing every record's hash-chain link.
+    ///
+    /// Returns `Ok(())` if the chain is intact, or `Err(index)` naming the
+    /// first record whose link is broken. Delegates to
+    /// [`Storage::verify_chain`]; see there for what "broken" covers.
+    pub fn verify_chain(&self) -> Result<(), usize> {
+        self.storage.verify_chain()
+    }
+
     /// Borrow the underlying storage.
     pub fn storage(&self) -> &S {
         &self.storage
     }
+
+    /// The Merkle root over every logged record's hash, in append order.
+    ///
+    /// `"0".repeat(64)` (the same sentinel as the genesis `prev_hash`) if the
+    /// log is empty. Recomputed from [`leaves`](Self) on every call — cheap
+    /// relative to the rest of the log's upkeep, and keeps the accumulator
+    /// free of any state beyond the append-only leaf vector.
+    pub fn root(&self) -> String {
+        match merkle_layers(&self.leaves).last() {
+            Some(top) if !top.is_empty() => top[0].clone(),
+            _ => "0".repeat(64),
+        }
+    }
+
+    /// Snapshot the current [`root`](Self::root) as a [`RootCheckpoint`],
+    /// append it to this logger's checkpoint history, and return it.
+    ///
+    /// [`root`](Self::root) recomputes from [`leaves`](Self) on every call,
+    /// which is fine for an occasional [`prove`](Self::prove) but gives a
+    /// third party nothing to hold onto between calls — a record proven
+    /// against today's root can't be re-checked once more records are
+    /// logged, since the root has since moved on. `checkpoint_root` is for
+    /// callers who periodically (per batch, per audit period) want a durable
+    /// `(root, record_count)` pair with a monotonic `root_seq`, so a proof
+    /// captured alongside a specific checkpoint stays verifiable against
+    /// that checkpoint's root even after the log has grown past it.
+    pub fn checkpoint_root(&mut self) -> RootCheckpoint {
+        let checkpoint = RootCheckpoint {
+            root_seq: self.root_checkpoints.len() as u64,
+            record_count: self.leaves.len() as u64,
+            root: self.root(),
+        };
+        self.root_checkpoints.push(checkpoint.clone());
+        checkpoint
+    }
+
+    /// Every [`RootCheckpoint`] snapshotted so far via
+    /// [`checkpoint_root`](Self::checkpoint_root), oldest first.
+    pub fn root_checkpoints(&self) -> &[RootCheckpoint] {
+        &self.root_checkpoints
+    }
+
+    /// Build a compact [`InclusionProof`] that `record_id` is part of the
+    /// log, or `None` if no record with that id has been logged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aumos_governance_core::{
+    ///     audit::{self, AuditLogger},
+    ///     storage::InMemoryStorage,
+    ///     types::{AuditFilter, Decision, TrustResult, BudgetResult, ConsentResult, TrustLevel},
+    /// };
+    ///
+    /// let mut logger = AuditLogger::new(InMemoryStorage::new());
+    /// let decision = Decision {
+    ///     permitted: true,
+    ///     action: "send_report".into(),
+    ///     agent_id: "agent-001".into(),
+    ///     scope: "default".into(),
+    ///     timestamp_ms: 0,
+    ///     reason: "PERMIT".into(),
+    ///     policy_epoch: 0,
+    ///     policy_hash: String::new(),
+    ///     trust: TrustResult {
+    ///         permitted: true,
+    ///         current_level: TrustLevel::ActAndReport,
+    ///         required_level: TrustLevel::Suggest,
+    ///         reason: "ok".into(),
+    ///     },
+    ///     budget: BudgetResult {
+    ///         permitted: true,
+    ///         available: 400.0,
+    ///         requested: 0.0,
+    ///         category: "default".into(),
+    ///         reason: "ok".into(),
+    ///         dimension: None,
+    ///     },
+    ///     consent: ConsentResult {
+    ///         permitted: true,
+    ///         reason: "ok".into(),
+    ///     },
+    /// };
+    /// logger.log(decision);
+    ///
+    /// let record = &logger.query(&AuditFilter::default())[0];
+    /// let proof = logger.prove(&record.id).unwrap();
+    /// assert!(audit::verify(&record.hash, &proof, &logger.root()));
+    /// ```
+    pub fn prove(&self, record_id: &str) -> Option<InclusionProof> {
+        let records = self.storage.query_audit(&AuditFilter::default());
+        let mut index = records.iter().position(|record| record.id == record_id)?;
+
+        if index >= self.leaves.len() {
+            return None;
+        }
+
+        let layers = merkle_layers(&self.leaves);
+        let mut siblings = Vec::new();
+        let mut sibling_is_right = Vec::new();
+
+        for layer in &layers[..layers.len().saturating_sub(1)] {
+            let is_left = index % 2 == 0;
+            let sibling_index = if is_left { index + 1 } else { index - 1 };
+            // An odd-sized level duplicates the last node as its own pair.
+            let sibling_index = if sibling_index < layer.len() { sibling_index } else { index };
+            siblings.push(layer[sibling_index].clone());
+            sibling_is_right.push(is_left);
+            index /= 2;
+        }
+
+        Some(InclusionProof { siblings, sibling_is_right })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Merkle accumulator
+// ---------------------------------------------------------------------------
+
+/// A durable `(root, record_count)` snapshot taken by
+/// [`AuditLogger::checkpoint_root`], numbered by a monotonically increasing
+/// `root_seq` (the checkpoint's index in
+/// [`root_checkpoints`](AuditLogger::root_checkpoints)).
+///
+/// Unlike [`AuditLogger::root`], which always reflects the *current* log,
+/// a `RootCheckpoint` stays valid for proofs captured against it even after
+/// later records extend the log past `record_count`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RootCheckpoint {
+    /// This checkpoint's position in the checkpoint history, starting at `0`.
+    pub root_seq: u64,
+    /// Number of records folded into `root` at the time of this checkpoint.
+    pub record_count: u64,
+    /// The Merkle root over the first `record_count` leaves.
+    pub root: String,
+}
+
+/// A compact proof that a leaf hash belongs to the tree behind some
+/// [`AuditLogger::root`], without needing the rest of the log.
+///
+/// `siblings[i]` is the sibling hash at level `i` of the path from leaf to
+/// root; `sibling_is_right[i]` says whether that sibling sits to the right
+/// (`true`) or left (`false`) of the node being proven at that level — both
+/// vectors are the same length, one entry per tree level below the root.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct InclusionProof {
+    /// Ordered sibling hashes, leaf level first.
+    pub siblings: Vec<String>,
+    /// Whether each corresponding sibling is the right-hand node.
+    pub sibling_is_right: Vec<bool>,
+}
+
+/// Recompute `leaf`'s path through `proof` and compare the result to `root`.
+///
+/// Returns `true` only if hashing `leaf` with each sibling, in the order and
+/// side `proof` specifies, reproduces `root` exactly.
+pub fn verify(leaf: &str, proof: &InclusionProof, root: &str) -> bool {
+    if proof.siblings.len() != proof.sibling_is_right.len() {
+        return false;
+    }
+
+    let mut current = String::from(leaf);
+    for (sibling, &is_right) in proof.siblings.iter().zip(&proof.sibling_is_right) {
+        current = if is_right {
+            hash_pair(&current, sibling)
+        } else {
+            hash_pair(sibling, &current)
+        };
+    }
+
+    current == root
+}
+
+/// Build every level of the Merkle tree over `leaves`, bottom-up, returning
+/// an empty `Vec` for an empty input. The last level is always a single
+/// (root) hash once `leaves` is non-empty. A level with an odd node count
+/// duplicates its last node to pair with itself.
+fn merkle_layers(leaves: &[String]) -> Vec<Vec<String>> {
+    if leaves.is_empty() {
+        return Vec::new();
+    }
+
+    let mut layers = alloc::vec![leaves.to_vec()];
+
+    while layers.last().map(Vec::len).unwrap_or(0) > 1 {
+        let current = layers.last().expect("just checked non-empty");
+        let mut next = Vec::with_capacity(current.len().div_ceil(2));
+
+        let mut i = 0;
+        while i < current.len() {
+            let left = &current[i];
+            let right = if i + 1 < current.len() { &current[i + 1] } else { left };
+            next.push(hash_pair(left, right));
+            i += 2;
+        }
+
+        layers.push(next);
+    }
+
+    layers
+}
+
+/// Hash two child nodes together to produce their parent in the Merkle tree.
+///
+/// Uses the same digest choice as [`recompute_hash`] (genuine SHA-256 under
+/// the `std` feature, FNV-1a otherwise) for the same no_std-compatibility
+/// reason.
+fn hash_pair(left: &str, right: &str) -> String {
+    #[cfg(feature = "std")]
+    {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        hasher.update(left.as_bytes());
+        hasher.update(right.as_bytes());
+        let digest = hasher.finalize();
+
+        let mut out = String::with_capacity(64);
+        for byte in digest {
+            out.push_str(&format!("{:02x}", byte));
+        }
+        out
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        let payload = format!("{}:{}", left, right);
+        let hash64 = fnv1a_64(payload.as_bytes());
+        let hex16 = u64_to_hex(hash64);
+        let mut out = alloc::string::String::with_capacity(64);
+        for _ in 0..4 {
+            out.push_str(&hex16);
+        }
+        out
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Hash chain implementation
 // ---------------------------------------------------------------------------
 
-/// Compute a deterministic hash string for an audit record.
+/// Compute the deterministic hash-chain link for an audit record.
 ///
-/// In `std` mode a proper SHA-256 digest is produced.  In `no_std` mode a
-/// lightweight FNV-1a 64-bit hash is used, rendered as a zero-padded 64-char
-/// hex string to keep the field width consistent.
+/// In `std` mode a genuine SHA-256 digest is produced over the canonical JSON
+/// serialisation of the decision, concatenated with `prev_hash`. In `no_std`
+/// mode a lightweight FNV-1a 64-bit hash is used instead (no collision
+/// resistance, but still chain-linked), rendered as a zero-padded 64-char hex
+/// string to keep the field width consistent across both paths.
 ///
 /// The hash covers the serialised decision **and** the previous record hash so
-/// that any modification to any field in the chain is detectable.
-fn compute_hash(decision: &Decision, prev_hash: &str) -> String {
+/// that any modification to any field in the chain is detectable (in `std`
+/// mode; the `no_std` payload covers only `action`, `permitted` and
+/// `timestamp_ms`). Exposed
+/// (not just used internally by [`log`](AuditLogger::log)) so storage
+/// backends can recompute and verify the chain independently, e.g.
+/// `aumos-governance-std`'s `FileStorage::open`.
+pub fn recompute_hash(decision: &Decision, prev_hash: &str) -> String {
     #[cfg(feature = "std")]
     {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
+        use sha2::{Digest, Sha256};
 
-        // Construct a deterministic byte string from the decision fields and
-        // the previous hash.  We use serde_json for a stable canonical form.
-        let payload = format!(
-            "{}:{}:{}:{}:{}:{}",
-            prev_hash,
-            decision.action,
-            decision.permitted,
-            decision.timestamp_ms,
-            decision.trust.current_level as u8,
-            decision.budget.requested
-        );
+        // Canonical JSON serialisation of the decision gives a stable byte
+        // string independent of in-memory field order.
+        let canonical = serde_json::to_vec(decision)
+            .unwrap_or_else(|_| alloc::vec::Vec::new());
 
-        // Apply a 64-bit hash seeded with the prev_hash to maintain chain
-        // dependency.  This is a structural hash for chain linking; downstream
-        // integrations that require cryptographic-strength audit trails should
-        // layer an external signing step on top.
-        let mut hasher = DefaultHasher::new();
-        payload.hash(&mut hasher);
-        let digest = hasher.finish();
+        let mut hasher = Sha256::new();
+        hasher.update(prev_hash.as_bytes());
+        hasher.update(&canonical);
+        let digest = hasher.finalize();
 
-        // Expand to 64 hex chars by doubling the 16-char u64 representation.
-        let hex16 = format!("{:016x}", digest);
-        hex16.repeat(4)
+        let mut out = String::with_capacity(64);
+        for byte in digest {
+            out.push_str(&format!("{:02x}", byte));
+        }
+        out
     }
     #[cfg(not(feature = "std"))]
     {
@@ -204,7 +598,7 @@ fn compute_hash(decision: &Decision, prev_hash: &str) -> String {
 }
 
 #[cfg(not(feature = "std"))]
-fn fnv1a_64(bytes: &[u8]) -> u64 {
+pub(crate) fn fnv1a_64(bytes: &[u8]) -> u64 {
     const FNV_OFFSET: u64 = 14_695_981_039_346_656_037;
     const FNV_PRIME: u64 = 1_099_511_628_211;
     let mut hash = FNV_OFFSET;
@@ -216,7 +610,7 @@ fn fnv1a_64(bytes: &[u8]) -> u64 {
 }
 
 #[cfg(not(feature = "std"))]
-fn u64_to_hex(value: u64) -> alloc::string::String {
+pub(crate) fn u64_to_hex(value: u64) -> alloc::string::String {
     const HEX: &[u8] = b"0123456789abcdef";
     let mut out = alloc::string::String::with_capacity(16);
     for shift in (0..8).rev() {
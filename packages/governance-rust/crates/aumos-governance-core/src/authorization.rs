@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! Authorization for governance-state mutations.
+//!
+//! [`AuthorizationManager`] exposes three operations only:
+//!
+//! * [`grant_authority`](AuthorizationManager::grant_authority)  — empower a principal
+//! * [`is_authorized`](AuthorizationManager::is_authorized)     — check whether a principal may act
+//! * [`revoke_authority`](AuthorizationManager::revoke_authority) — withdraw a principal's authority
+//!
+//! This is deliberately a separate subsystem from [`TrustManager`](crate::trust::TrustManager):
+//! trust governs what an *agent* may do, authorization governs who may
+//! change governance state itself (assign trust, record or revoke consent,
+//! record spend) on the agent's behalf. Without it, any caller holding a
+//! reference to the engine could escalate an agent's trust by convention
+//! alone — this manager makes that an explicit, checked grant.
+
+use alloc::string::String;
+
+use crate::storage::Storage;
+
+// ---------------------------------------------------------------------------
+// GovernanceOperation
+// ---------------------------------------------------------------------------
+
+/// A governance-state mutation that can be gated by [`AuthorizationManager`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GovernanceOperation {
+    /// Assign or change an agent's trust level.
+    SetTrustLevel,
+    /// Record a consent grant.
+    RecordConsent,
+    /// Revoke a consent grant.
+    RevokeConsent,
+    /// Delegate a consented action to another agent.
+    DelegateConsent,
+    /// Withdraw a previously recorded delegation.
+    RevokeDelegation,
+    /// Record a spend against a budget envelope.
+    RecordSpend,
+}
+
+impl GovernanceOperation {
+    /// Stable, lowercase identifier used as the audit-log `action` and the
+    /// authorization storage key.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GovernanceOperation::SetTrustLevel => "set_trust_level",
+            GovernanceOperation::RecordConsent => "record_consent",
+            GovernanceOperation::RevokeConsent => "revoke_consent",
+            GovernanceOperation::DelegateConsent => "delegate_consent",
+            GovernanceOperation::RevokeDelegation => "revoke_delegation",
+            GovernanceOperation::RecordSpend => "record_spend",
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// AuthorizationManager
+// ---------------------------------------------------------------------------
+
+/// Maps `(principal, operation, scope)` triples to a granted/not-granted
+/// flag, backing every governance write method's authorization check.
+///
+/// # Examples
+///
+/// ```rust
+/// use aumos_governance_core::{
+///     authorization::{AuthorizationManager, GovernanceOperation},
+///     storage::InMemoryStorage,
+/// };
+///
+/// let mut manager = AuthorizationManager::new(InMemoryStorage::new());
+///
+/// // No grant yet.
+/// assert!(!manager.is_authorized("owner", GovernanceOperation::SetTrustLevel, "finance"));
+///
+/// // Grant, then check again.
+/// manager.grant_authority("owner", GovernanceOperation::SetTrustLevel, "finance");
+/// assert!(manager.is_authorized("owner", GovernanceOperation::SetTrustLevel, "finance"));
+///
+/// // Revoke.
+/// manager.revoke_authority("owner", GovernanceOperation::SetTrustLevel, "finance");
+/// assert!(!manager.is_authorized("owner", GovernanceOperation::SetTrustLevel, "finance"));
+/// ```
+pub struct AuthorizationManager<S: Storage> {
+    storage: S,
+}
+
+impl<S: Storage> AuthorizationManager<S> {
+    /// Create a new [`AuthorizationManager`].
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+
+    /// Empower `principal` to perform `op` within `scope`.
+    ///
+    /// Granting authority is itself unchecked — whoever holds the engine at
+    /// bootstrap time is trusted to make the initial grants. There is no
+    /// recursive "who may grant authority" check, by design.
+    pub fn grant_authority(&mut self, principal: &str, op: GovernanceOperation, scope: &str) {
+        let key = Self::authority_key(principal, op, scope);
+        self.storage.set_authority(&key, true);
+    }
+
+    /// Withdraw a previously granted authority.
+    ///
+    /// A no-op if `principal` never held it for `(op, scope)`.
+    pub fn revoke_authority(&mut self, principal: &str, op: GovernanceOperation, scope: &str) {
+        let key = Self::authority_key(principal, op, scope);
+        self.storage.set_authority(&key, false);
+    }
+
+    /// Whether `principal` currently holds authority to perform `op` within
+    /// `scope`. Absent a grant, this is always `false` — authorization is
+    /// deny-by-default, unlike [`Config::pass_on_missing_envelope`](crate::config::Config).
+    pub fn is_authorized(&self, principal: &str, op: GovernanceOperation, scope: &str) -> bool {
+        let key = Self::authority_key(principal, op, scope);
+        self.storage.get_authority(&key)
+    }
+
+    /// Borrow the underlying storage.
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// The storage key for `(principal, op, scope)`.
+    ///
+    /// `principal` and `scope` are caller-supplied strings with no charset
+    /// restriction, so a plain `:`-joined key is ambiguous: e.g.
+    /// `principal = "alice"`, `scope = "bob:set_trust_level:finance"` and
+    /// `principal = "alice:set_trust_level:bob"`, `scope = "finance"` both
+    /// join to the identical string once `op` is spliced between them,
+    /// letting a grant for one `(principal, scope)` pair double as a grant
+    /// for another. Each is instead length-prefixed (`len:value`, TLV-style)
+    /// so no choice of field content can make two different grants collide
+    /// on the same key.
+    fn authority_key(principal: &str, op: GovernanceOperation, scope: &str) -> String {
+        let mut key = String::with_capacity(principal.len() + scope.len() + 16);
+        write_len_prefixed(&mut key, principal);
+        write_len_prefixed(&mut key, scope);
+        key.push_str(op.as_str());
+        key
+    }
+}
+
+/// Append `field` to `key` as `"{len}:{field}"`, so the reader never has to
+/// guess where `field` ends — unlike a bare separator, a length prefix can't
+/// be spoofed by choosing `field`'s own content.
+fn write_len_prefixed(key: &mut String, field: &str) {
+    key.push_str(&alloc::format!("{}:", field.len()));
+    key.push_str(field);
+}
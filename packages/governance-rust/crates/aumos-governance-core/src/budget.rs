@@ -3,21 +3,154 @@
 
 //! Spending envelope management.
 //!
-//! [`BudgetManager`] exposes three operations only:
+//! [`BudgetManager`] exposes four operations:
 //!
 //! * [`create_envelope`](BudgetManager::create_envelope) — define a new spending limit
 //! * [`check`](BudgetManager::check)                    — verify headroom before spending
 //! * [`record`](BudgetManager::record)                  — record a completed spend
+//! * [`refund`](BudgetManager::refund)                  — give back a spend that never completed
 //!
 //! Budget allocations are **always static**.  There is no adaptive allocation,
 //! no ML-based prediction, and no automatic rebalancing.
+//!
+//! ## Checkpoints
+//!
+//! [`refund`](BudgetManager::refund) undoes a *known* amount after the fact.
+//! When a caller instead needs to speculatively debit a category, run
+//! further gates, and only find out afterwards whether the debit should
+//! stick, use [`checkpoint`](BudgetManager::checkpoint) /
+//! [`revert_to`](BudgetManager::revert_to) /
+//! [`discard`](BudgetManager::discard) instead: `checkpoint` marks a point in
+//! time, any envelope touched by [`record_dimensions`](Self::record_dimensions)
+//! or [`refund_dimensions`](Self::refund_dimensions) after that point has its
+//! pre-touch state captured on first touch, and `revert_to` restores every
+//! touched category back to exactly that state. Checkpoints nest — `revert_to`
+//! correctly unwinds any checkpoints opened after the one being reverted to,
+//! and `discard` drops a checkpoint's captured state once its speculative
+//! work is confirmed good, without touching the envelope.
+//!
+//! ## Action sessions
+//!
+//! A checkpoint reverts an envelope back to a known-good point in time — it
+//! assumes every intervening debit was, in hindsight, either entirely right
+//! or entirely wrong. An action made up of several costed sub-operations
+//! (e.g. one multi-call tool invocation) often isn't that clean: some
+//! sub-operations may cost less than reserved and need a partial refund
+//! without undoing the whole action. [`begin_action`](BudgetManager::begin_action)
+//! opens an [`ActionSession`] for exactly this: [`charge`](ActionSession::charge)
+//! and [`refund`](ActionSession::refund) accumulate a net delta per category
+//! purely in memory, and [`settle`](ActionSession::settle) applies only that
+//! net figure to the envelope — nothing is written to storage until then.
+//!
+//! ## Resource dimensions
+//!
+//! An envelope can bound more than one resource at once — e.g. LLM tokens
+//! *and* USD cost under the same category — by tracking several named
+//! **dimensions**. [`create_envelope_with_dimensions`], [`check_dimensions`],
+//! and [`record_dimensions`] accept a `dimension -> amount` map and permit a
+//! spend only if *every* requested dimension fits its own headroom. The
+//! [`BudgetResult`] reports the **binding dimension** — the one closest to
+//! (or over) its limit — in its `reason`. [`create_envelope`]/[`check`]/
+//! [`record`] remain as single-value convenience wrappers around a one-entry
+//! [`Envelope::DEFAULT_DIMENSION`] map, so existing callers are unaffected.
+//!
+//! [`create_envelope_with_dimensions`]: BudgetManager::create_envelope_with_dimensions
+//! [`check_dimensions`]: BudgetManager::check_dimensions
+//! [`record_dimensions`]: BudgetManager::record_dimensions
+//!
+//! [`EnvelopeBuilder`] is a fluent alternative to building the `limits` map
+//! by hand before calling [`create_envelope_with_dimensions`] — useful when a
+//! resource schema (the set of dimension names and their limits) is declared
+//! once and reused. [`diagnose_dimensions`](BudgetManager::diagnose_dimensions)
+//! complements [`check_dimensions`]: where `check_dimensions` collapses the
+//! outcome to a single binding dimension for a human-readable [`BudgetResult`],
+//! `diagnose_dimensions` enumerates *every* dimension that would be exceeded
+//! and by how much, for callers that need the full picture rather than just
+//! the tightest one.
+//!
+//! ## Metered dimensions
+//!
+//! Named dimensions above are `f64` and caller-defined by convention — a
+//! separate, typed family covers integer-unit resources instead:
+//! [`create_metered_envelope`](BudgetManager::create_metered_envelope) defines
+//! a [`MeteredEnvelope`](crate::metered_budget::MeteredEnvelope) over a closed
+//! [`ResourceDimension`](crate::metered_budget::ResourceDimension) axis, and
+//! [`check_metered`](BudgetManager::check_metered)/
+//! [`record_metered`](BudgetManager::record_metered) evaluate a
+//! [`CostModel`](crate::metered_budget::CostModel)'s per-dimension cost
+//! vector for a named action against it, reporting the first exhausted
+//! dimension in [`BudgetResult::dimension`](crate::types::BudgetResult::dimension).
+//! Metered envelopes are held directly on [`BudgetManager`] rather than
+//! through [`Storage`] — see [`metered_budget`](crate::metered_budget)'s
+//! module docs for why.
+//!
+//! ## Period rollover
+//!
+//! An envelope with `period_ms > 0` resets automatically: both [`check`] and
+//! [`record`] take an explicit `now_ms` (the caller supplies the clock, so
+//! tests stay deterministic) and compute how many whole periods have elapsed
+//! since `starts_at_ms`. If at least one period has elapsed, `spent` is reset
+//! to `0.0` and `starts_at_ms` is advanced by that many periods before
+//! headroom is evaluated. `record` persists the rolled-over envelope;
+//! `check` evaluates against it without writing anything back.
+//!
+//! [`check`]: BudgetManager::check
+//! [`record`]: BudgetManager::record
 
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
 use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
 
+use crate::clock::Clock;
 use crate::config::Config;
+use crate::metered_budget::{CostModel, MeteredEnvelope, ResourceDimension};
 use crate::storage::Storage;
 use crate::types::{BudgetResult, Envelope};
 
+/// Errors returned by [`BudgetManager::record`].
+///
+/// Unlike [`BudgetResult`] (a descriptive, always-`Ok` evaluation used by
+/// [`check`](BudgetManager::check)), these are hard failures: `record` is the
+/// single point where a debit either lands or doesn't, so callers can't
+/// accidentally overspend by skipping `check`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BudgetError {
+    /// No envelope has been created for this category.
+    NoEnvelope {
+        /// The category that was debited.
+        category: String,
+    },
+    /// The spend would exceed the envelope's remaining (post-rollover) headroom
+    /// in its binding dimension (the one closest to, or over, its limit).
+    Exceeded {
+        /// The category that was debited.
+        category: String,
+        /// The dimension that could not absorb the spend.
+        dimension: String,
+        /// The amount that was requested in `dimension`.
+        requested: f64,
+        /// The amount actually available in `dimension` before this debit.
+        available: f64,
+    },
+}
+
+impl fmt::Display for BudgetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BudgetError::NoEnvelope { category } => {
+                write!(f, "no envelope configured for category '{}'", category)
+            }
+            BudgetError::Exceeded { category, dimension, requested, available } => write!(
+                f,
+                "spend of {:.4} exceeds envelope '{}' in dimension '{}' (available: {:.4})",
+                requested, category, dimension, available
+            ),
+        }
+    }
+}
+
 /// Manages static spending envelopes for named cost categories.
 ///
 /// # Examples
@@ -35,28 +168,87 @@ use crate::types::{BudgetResult, Envelope};
 /// manager.create_envelope("financial", 500.0, 86_400_000, 0);
 ///
 /// // Check whether a $100 spend fits.
-/// let result = manager.check("financial", 100.0);
+/// let result = manager.check("financial", 100.0, 0);
 /// assert!(result.permitted);
 ///
 /// // Record the spend.
-/// manager.record("financial", 100.0);
+/// manager.record("financial", 100.0, 0).unwrap();
 ///
 /// // Check again — $400 remains.
-/// let result = manager.check("financial", 401.0);
+/// let result = manager.check("financial", 401.0, 0);
 /// assert!(!result.permitted);
 /// ```
 pub struct BudgetManager<S: Storage> {
     config: Config,
     storage: S,
+    /// Open checkpoints, oldest first. See [`checkpoint`](Self::checkpoint).
+    checkpoints: Vec<Checkpoint>,
+    /// Monotonically increasing counter handed out as the next [`CheckpointId`].
+    next_checkpoint_id: CheckpointId,
+    /// Time source for the `_now` convenience methods. [`check`](Self::check),
+    /// [`record`](Self::record), and [`refund`](Self::refund) themselves stay
+    /// on an explicit `now_ms` parameter — see "Period rollover" above — this
+    /// clock only backs the variants that don't take one.
+    clock: Arc<dyn Clock + Send + Sync>,
+    /// Typed [`MeteredEnvelope`]s, keyed by category. Held directly on the
+    /// manager rather than through [`Storage`] — see the "Metered
+    /// dimensions" section above for why this family is storage-free.
+    metered: BTreeMap<String, MeteredEnvelope>,
 }
 
 impl<S: Storage> BudgetManager<S> {
     /// Create a new [`BudgetManager`].
     pub fn new(config: Config, storage: S) -> Self {
-        Self { config, storage }
+        Self {
+            config,
+            storage,
+            checkpoints: Vec::new(),
+            next_checkpoint_id: 0,
+            clock: crate::clock::default_clock(),
+            metered: BTreeMap::new(),
+        }
+    }
+
+    /// Install `clock` as the time source for this manager's `_now`
+    /// convenience methods (e.g. [`check_now`](Self::check_now)), replacing
+    /// the default.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// This manager's injected [`Clock`], read directly. Used by
+    /// [`GatePipeline`](crate::gate::GatePipeline)'s `BudgetGate`, which has
+    /// no `now_ms` parameter of its own to thread through.
+    pub(crate) fn now_ms(&self) -> u64 {
+        self.clock.now_ms()
+    }
+
+    /// Like [`check`](Self::check), but reads `now_ms` from this manager's
+    /// injected [`Clock`] instead of taking it as a parameter.
+    pub fn check_now(&self, category: &str, amount: f64) -> BudgetResult {
+        self.check(category, amount, self.clock.now_ms())
+    }
+
+    /// Like [`record`](Self::record), but reads `now_ms` from this manager's
+    /// injected [`Clock`] instead of taking it as a parameter.
+    pub fn record_now(&mut self, category: &str, amount: f64) -> Result<(), BudgetError> {
+        let now_ms = self.clock.now_ms();
+        self.record(category, amount, now_ms)
+    }
+
+    /// Like [`refund`](Self::refund), but reads `now_ms` from this manager's
+    /// injected [`Clock`] instead of taking it as a parameter.
+    pub fn refund_now(&mut self, category: &str, amount: f64) -> Result<(), BudgetError> {
+        let now_ms = self.clock.now_ms();
+        self.refund(category, amount, now_ms)
     }
 
-    /// Define a new (or replace an existing) spending envelope for `category`.
+    /// Define a new (or replace an existing) single-dimension spending
+    /// envelope for `category`.
+    ///
+    /// Convenience wrapper around [`create_envelope_with_dimensions`](Self::create_envelope_with_dimensions)
+    /// that tracks one dimension named [`Envelope::DEFAULT_DIMENSION`].
     ///
     /// * `category`    — the logical cost category (e.g. `"llm-tokens"`)
     /// * `limit`       — maximum amount per period
@@ -81,21 +273,60 @@ impl<S: Storage> BudgetManager<S> {
         limit: f64,
         period_ms: u64,
         starts_at_ms: u64,
+    ) {
+        let mut limits = BTreeMap::new();
+        limits.insert(String::from(Envelope::DEFAULT_DIMENSION), limit);
+        self.create_envelope_with_dimensions(category, limits, period_ms, starts_at_ms);
+    }
+
+    /// Define a new (or replace an existing) multi-dimensional spending
+    /// envelope for `category`.
+    ///
+    /// `limits` maps a dimension name (e.g. `"tokens"`, `"usd"`) to its
+    /// per-period maximum. A spend against this envelope is only permitted
+    /// once every dimension it requests fits its own headroom.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aumos_governance_core::{
+    ///     budget::BudgetManager,
+    ///     storage::InMemoryStorage,
+    ///     config::Config,
+    /// };
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut manager = BudgetManager::new(Config::default(), InMemoryStorage::new());
+    ///
+    /// let mut limits = BTreeMap::new();
+    /// limits.insert("tokens".to_string(), 100_000.0);
+    /// limits.insert("usd".to_string(), 5.0);
+    /// manager.create_envelope_with_dimensions("llm-calls", limits, 86_400_000, 0);
+    /// ```
+    pub fn create_envelope_with_dimensions(
+        &mut self,
+        category: &str,
+        limits: BTreeMap<String, f64>,
+        period_ms: u64,
+        starts_at_ms: u64,
     ) {
         let envelope = Envelope {
             category: category.into(),
-            limit,
-            spent: 0.0,
+            limits,
+            spent: BTreeMap::new(),
             period_ms,
             starts_at_ms,
         };
         self.storage.set_envelope(category, envelope);
     }
 
-    /// Evaluate whether `amount` fits within the `category` envelope.
+    /// Evaluate whether `amount` fits within the `category` envelope as of `now_ms`.
     ///
-    /// Does **not** modify any state.  Call [`record`](Self::record) after the
-    /// action completes to debit the envelope.
+    /// Does **not** modify any state — if one or more periods have elapsed
+    /// since `starts_at_ms`, the rolled-over view is used to compute
+    /// `available`/`permitted`, but nothing is written back. Call
+    /// [`record`](Self::record) after the action completes to both apply the
+    /// rollover and debit the envelope.
     ///
     /// When no envelope exists for `category`:
     /// - If `Config::pass_on_missing_envelope` is `true` → permitted.
@@ -113,65 +344,137 @@ impl<S: Storage> BudgetManager<S> {
     /// let mut manager = BudgetManager::new(Config::default(), InMemoryStorage::new());
     /// manager.create_envelope("financial", 200.0, 0, 0);
     ///
-    /// assert!(manager.check("financial", 150.0).permitted);
-    /// assert!(!manager.check("financial", 250.0).permitted);
+    /// assert!(manager.check("financial", 150.0, 0).permitted);
+    /// assert!(!manager.check("financial", 250.0, 0).permitted);
+    /// ```
+    pub fn check(&self, category: &str, amount: f64, now_ms: u64) -> BudgetResult {
+        let mut amounts = BTreeMap::new();
+        amounts.insert(String::from(Envelope::DEFAULT_DIMENSION), amount);
+        self.check_dimensions(category, &amounts, now_ms)
+    }
+
+    /// Evaluate whether every dimension in `amounts` fits within the
+    /// `category` envelope's own headroom as of `now_ms`.
+    ///
+    /// A spend is permitted only if *all* requested dimensions fit — the
+    /// returned [`BudgetResult`] reports the **binding dimension** (the one
+    /// closest to, or over, its limit) via `available`/`requested`/`reason`.
+    /// A dimension the envelope doesn't track is treated as unbounded, so
+    /// requesting a dimension unrelated to the envelope's own never denies
+    /// the spend on that dimension's account.
+    ///
+    /// Does **not** modify any state — see [`check`](Self::check) for the
+    /// rollover semantics, which apply identically here.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aumos_governance_core::{
+    ///     budget::BudgetManager,
+    ///     storage::InMemoryStorage,
+    ///     config::Config,
+    /// };
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut manager = BudgetManager::new(Config::default(), InMemoryStorage::new());
+    /// let mut limits = BTreeMap::new();
+    /// limits.insert("tokens".to_string(), 100_000.0);
+    /// limits.insert("usd".to_string(), 5.0);
+    /// manager.create_envelope_with_dimensions("llm-calls", limits, 0, 0);
+    ///
+    /// let mut spend = BTreeMap::new();
+    /// spend.insert("tokens".to_string(), 1_000.0);
+    /// spend.insert("usd".to_string(), 10.0);
+    /// // Denied: the "usd" dimension is binding — it's the one that doesn't fit.
+    /// assert!(!manager.check_dimensions("llm-calls", &spend, 0).permitted);
     /// ```
-    pub fn check(&self, category: &str, amount: f64) -> BudgetResult {
+    pub fn check_dimensions(
+        &self,
+        category: &str,
+        amounts: &BTreeMap<String, f64>,
+        now_ms: u64,
+    ) -> BudgetResult {
         match self.storage.get_envelope(category) {
             Some(envelope) => {
-                let available = envelope.available();
-                let permitted = envelope.can_spend(amount);
-                let reason: String = if permitted {
+                let envelope = rolled_over(&envelope, now_ms);
+                let binding = binding_dimension(&envelope, amounts);
+                let reason: String = if amounts.len() == 1 && binding.dimension == Envelope::DEFAULT_DIMENSION {
+                    if binding.permitted {
+                        format!(
+                            "Spend of {:.4} fits within envelope '{}' (available: {:.4}).",
+                            binding.requested, category, binding.available
+                        )
+                    } else {
+                        format!(
+                            "Spend of {:.4} exceeds envelope '{}' (available: {:.4}).",
+                            binding.requested, category, binding.available
+                        )
+                    }
+                } else if binding.permitted {
                     format!(
-                        "Spend of {:.4} fits within envelope '{}' (available: {:.4}).",
-                        amount, category, available
+                        "Spend fits within envelope '{}' (binding dimension '{}': available {:.4}, requested {:.4}).",
+                        category, binding.dimension, binding.available, binding.requested
                     )
                 } else {
                     format!(
-                        "Spend of {:.4} exceeds envelope '{}' (available: {:.4}, limit: {:.4}).",
-                        amount, category, available, envelope.limit
+                        "Spend exceeds envelope '{}' in dimension '{}' (available: {:.4}, requested: {:.4}).",
+                        category, binding.dimension, binding.available, binding.requested
                     )
                 };
                 BudgetResult {
-                    permitted,
-                    available,
-                    requested: amount,
+                    permitted: binding.permitted,
+                    available: binding.available,
+                    requested: binding.requested,
                     category: category.into(),
                     reason,
+                    dimension: None,
                 }
             }
             None => {
+                let requested: f64 = amounts.values().sum();
                 if self.config.pass_on_missing_envelope {
                     BudgetResult {
                         permitted: true,
                         available: f64::MAX,
-                        requested: amount,
+                        requested,
                         category: category.into(),
                         reason: format!(
                             "No envelope configured for '{}'; passing (open budget).",
                             category
                         ),
+                        dimension: None,
                     }
                 } else {
                     BudgetResult {
                         permitted: false,
                         available: 0.0,
-                        requested: amount,
+                        requested,
                         category: category.into(),
                         reason: format!(
                             "No envelope configured for '{}'; denying (strict mode).",
                             category
                         ),
+                        dimension: None,
                     }
                 }
             }
         }
     }
 
-    /// Debit `amount` from the `category` envelope.
+    /// Debit `amount` from the `category` envelope as of `now_ms`.
+    ///
+    /// Applies period rollover first (resetting `spent` and advancing
+    /// `starts_at_ms` if one or more periods have elapsed), persists that
+    /// rolled-over state regardless of outcome, then attempts the debit.
+    /// Callers should call [`check`](Self::check) first; `record` re-validates
+    /// headroom itself so overspending is impossible even if `check` is
+    /// skipped.
     ///
-    /// If no envelope exists this is a no-op.  Callers should call
-    /// [`check`](Self::check) first; `record` does not re-validate.
+    /// # Errors
+    ///
+    /// Returns [`BudgetError::NoEnvelope`] if no envelope exists for
+    /// `category`, or [`BudgetError::Exceeded`] if the debit would exceed the
+    /// (post-rollover) limit.
     ///
     /// # Examples
     ///
@@ -184,16 +487,113 @@ impl<S: Storage> BudgetManager<S> {
     ///
     /// let mut manager = BudgetManager::new(Config::default(), InMemoryStorage::new());
     /// manager.create_envelope("financial", 500.0, 0, 0);
-    /// manager.record("financial", 100.0);
+    /// manager.record("financial", 100.0, 0).unwrap();
     ///
-    /// let result = manager.check("financial", 1.0);
+    /// let result = manager.check("financial", 1.0, 0);
     /// assert_eq!(result.available, 400.0);
     /// ```
-    pub fn record(&mut self, category: &str, amount: f64) {
-        if let Some(mut envelope) = self.storage.get_envelope(category) {
-            envelope.spent += amount;
+    pub fn record(&mut self, category: &str, amount: f64, now_ms: u64) -> Result<(), BudgetError> {
+        let mut amounts = BTreeMap::new();
+        amounts.insert(String::from(Envelope::DEFAULT_DIMENSION), amount);
+        self.record_dimensions(category, &amounts, now_ms)
+    }
+
+    /// Debit every dimension in `amounts` from the `category` envelope as of
+    /// `now_ms`.
+    ///
+    /// Applies period rollover first — resetting every dimension's `spent`
+    /// together and advancing `starts_at_ms` if one or more periods have
+    /// elapsed — persists that rolled-over state regardless of outcome, then
+    /// attempts the debit. As with [`record`](Self::record), the debit is
+    /// all-or-nothing: if any dimension would be exceeded, none are debited.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BudgetError::NoEnvelope`] if no envelope exists for
+    /// `category`, or [`BudgetError::Exceeded`] (naming the binding
+    /// dimension) if the debit would exceed the (post-rollover) limit in any
+    /// requested dimension.
+    pub fn record_dimensions(
+        &mut self,
+        category: &str,
+        amounts: &BTreeMap<String, f64>,
+        now_ms: u64,
+    ) -> Result<(), BudgetError> {
+        let envelope = self.storage.get_envelope(category).ok_or_else(|| BudgetError::NoEnvelope {
+            category: category.into(),
+        })?;
+
+        self.capture_for_checkpoint(category, &envelope);
+
+        let mut envelope = rolled_over(&envelope, now_ms);
+        let binding = binding_dimension(&envelope, amounts);
+
+        if !binding.permitted {
             self.storage.set_envelope(category, envelope);
+            return Err(BudgetError::Exceeded {
+                category: category.into(),
+                dimension: binding.dimension,
+                requested: binding.requested,
+                available: binding.available,
+            });
         }
+
+        for (dimension, &amount) in amounts {
+            *envelope.spent.entry(dimension.clone()).or_insert(0.0) += amount;
+        }
+        self.storage.set_envelope(category, envelope);
+        Ok(())
+    }
+
+    /// Give back `amount` to the `category` envelope as of `now_ms`, undoing
+    /// a [`record`](Self::record) for a spend that was reserved but never
+    /// completed (e.g. a scheduled action cancelled before it took effect).
+    ///
+    /// Convenience wrapper around [`refund_dimensions`](Self::refund_dimensions)
+    /// that targets one dimension named [`Envelope::DEFAULT_DIMENSION`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BudgetError::NoEnvelope`] if no envelope exists for
+    /// `category`.
+    pub fn refund(&mut self, category: &str, amount: f64, now_ms: u64) -> Result<(), BudgetError> {
+        let mut amounts = BTreeMap::new();
+        amounts.insert(String::from(Envelope::DEFAULT_DIMENSION), amount);
+        self.refund_dimensions(category, &amounts, now_ms)
+    }
+
+    /// Give back every dimension in `amounts` to the `category` envelope as
+    /// of `now_ms`.
+    ///
+    /// Applies period rollover first, exactly as [`record_dimensions`]
+    /// (Self::record_dimensions) does, then subtracts `amounts` from
+    /// `spent`, floored at `0.0` per dimension — a refund can never push an
+    /// envelope negative, e.g. because the period already rolled over
+    /// between the original `record` and this `refund`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BudgetError::NoEnvelope`] if no envelope exists for
+    /// `category`.
+    pub fn refund_dimensions(
+        &mut self,
+        category: &str,
+        amounts: &BTreeMap<String, f64>,
+        now_ms: u64,
+    ) -> Result<(), BudgetError> {
+        let envelope = self.storage.get_envelope(category).ok_or_else(|| BudgetError::NoEnvelope {
+            category: category.into(),
+        })?;
+
+        self.capture_for_checkpoint(category, &envelope);
+
+        let mut envelope = rolled_over(&envelope, now_ms);
+        for (dimension, &amount) in amounts {
+            let spent = envelope.spent.entry(dimension.clone()).or_insert(0.0);
+            *spent = (*spent - amount).max(0.0);
+        }
+        self.storage.set_envelope(category, envelope);
+        Ok(())
     }
 
     /// Retrieve the current envelope snapshot for `category`.
@@ -205,4 +605,849 @@ impl<S: Storage> BudgetManager<S> {
     pub fn storage(&self) -> &S {
         &self.storage
     }
+
+    /// Open a new checkpoint and return its id.
+    ///
+    /// From this point on, the first [`record_dimensions`](Self::record_dimensions)
+    /// or [`refund_dimensions`](Self::refund_dimensions) call touching any given
+    /// category captures that category's pre-touch envelope into this
+    /// checkpoint. A category never touched after the checkpoint was opened
+    /// is simply absent from it, so [`revert_to`](Self::revert_to) leaves it
+    /// untouched too.
+    ///
+    /// Checkpoints nest: opening a second checkpoint before reverting or
+    /// discarding the first pushes it on top of a stack.
+    ///
+    /// The stack is positional, not per-caller: it assumes whoever opened a
+    /// checkpoint is the only one touching `self` until that checkpoint is
+    /// closed. Under a shared `&mut` this is automatically enforced by the
+    /// borrow checker; a caller sharing one `BudgetManager` across
+    /// concurrent tasks (e.g. behind a lock, as the `async` feature's async
+    /// engine does) must hold that lock for the checkpoint's entire
+    /// open-to-close span, or two interleaved checkpoints can corrupt each
+    /// other's state — see that module's "Budget lock discipline" note.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aumos_governance_core::{budget::BudgetManager, storage::InMemoryStorage, config::Config};
+    ///
+    /// let mut manager = BudgetManager::new(Config::default(), InMemoryStorage::new());
+    /// manager.create_envelope("financial", 500.0, 0, 0);
+    ///
+    /// let checkpoint = manager.checkpoint();
+    /// manager.record("financial", 100.0, 0).unwrap();
+    /// assert_eq!(manager.check("financial", 0.0, 0).available, 400.0);
+    ///
+    /// manager.revert_to(checkpoint);
+    /// assert_eq!(manager.check("financial", 0.0, 0).available, 500.0);
+    /// ```
+    pub fn checkpoint(&mut self) -> CheckpointId {
+        let id = self.next_checkpoint_id;
+        self.next_checkpoint_id += 1;
+        self.checkpoints.push(Checkpoint {
+            id,
+            snapshots: BTreeMap::new(),
+        });
+        id
+    }
+
+    /// Restore every category touched since `checkpoint` was opened back to
+    /// its state at that time, then close `checkpoint` and any checkpoints
+    /// opened after it.
+    ///
+    /// A no-op (beyond closing the checkpoint) if no category was touched.
+    /// Unknown or already-closed ids are ignored. When checkpoints nest,
+    /// reverting to an outer one correctly unwinds every inner one too —
+    /// each category is restored to its *earliest* captured state among the
+    /// checkpoints being unwound, which is its state at `checkpoint` time
+    /// regardless of how many inner checkpoints touched it afterwards.
+    pub fn revert_to(&mut self, checkpoint: CheckpointId) {
+        let Some(start) = self.checkpoints.iter().position(|c| c.id == checkpoint) else {
+            return;
+        };
+
+        let mut restore: BTreeMap<String, Envelope> = BTreeMap::new();
+        for frame in &self.checkpoints[start..] {
+            for (category, envelope) in &frame.snapshots {
+                restore.entry(category.clone()).or_insert_with(|| envelope.clone());
+            }
+        }
+
+        for (category, envelope) in restore {
+            self.storage.set_envelope(&category, envelope);
+        }
+
+        self.checkpoints.truncate(start);
+    }
+
+    /// Close `checkpoint` and any checkpoints opened after it without
+    /// touching the envelope — commits whatever was recorded or refunded
+    /// since it was opened. Unknown or already-closed ids are ignored.
+    pub fn discard(&mut self, checkpoint: CheckpointId) {
+        if let Some(start) = self.checkpoints.iter().position(|c| c.id == checkpoint) {
+            self.checkpoints.truncate(start);
+        }
+    }
+
+    /// Capture `category`'s current envelope into *every* open checkpoint
+    /// frame, not just the innermost one — but only on each frame's first
+    /// touch of `category`, so each frame's captured state is always the
+    /// state as of that frame's own checkpoint time, not as of some later
+    /// intervening touch.
+    ///
+    /// Writing to every frame (rather than only the innermost, as an earlier
+    /// version of this method did) matters once checkpoints nest: if an
+    /// outer checkpoint is open, an inner one is opened on top of it, and
+    /// `category` is touched for the first time while the inner checkpoint
+    /// is innermost, that touch's pre-state must still reach the outer
+    /// frame. Capturing into only the innermost frame would lose it the
+    /// moment the inner checkpoint is [`discard`](Self::discard)ed — the
+    /// outer frame would have no record of `category`'s pre-touch state,
+    /// and a later [`revert_to`](Self::revert_to) on the outer checkpoint
+    /// would silently fail to restore it.
+    fn capture_for_checkpoint(&mut self, category: &str, envelope: &Envelope) {
+        for frame in &mut self.checkpoints {
+            frame
+                .snapshots
+                .entry(category.into())
+                .or_insert_with(|| envelope.clone());
+        }
+    }
+
+    /// Evaluate `amounts` against `category`'s envelope and return every
+    /// dimension that would be exceeded, with how much was requested versus
+    /// available in each.
+    ///
+    /// Unlike [`check_dimensions`](Self::check_dimensions) — which reports
+    /// only the single binding dimension via a [`BudgetResult`] — this
+    /// enumerates the complete set of breaches, so a caller (or a governance
+    /// policy surfaced to an operator) can report "tokens AND usd both
+    /// exceeded" rather than just the tighter of the two. Returns an empty
+    /// `Vec` if every requested dimension fits, or if no envelope exists for
+    /// `category` (there is nothing to enumerate against).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aumos_governance_core::{
+    ///     budget::BudgetManager,
+    ///     storage::InMemoryStorage,
+    ///     config::Config,
+    /// };
+    /// use std::collections::BTreeMap;
+    ///
+    /// let mut manager = BudgetManager::new(Config::default(), InMemoryStorage::new());
+    /// let mut limits = BTreeMap::new();
+    /// limits.insert("tokens".to_string(), 100.0);
+    /// limits.insert("usd".to_string(), 1.0);
+    /// manager.create_envelope_with_dimensions("llm-calls", limits, 0, 0);
+    ///
+    /// let mut spend = BTreeMap::new();
+    /// spend.insert("tokens".to_string(), 500.0);
+    /// spend.insert("usd".to_string(), 5.0);
+    /// let breaches = manager.diagnose_dimensions("llm-calls", &spend, 0);
+    /// assert_eq!(breaches.len(), 2);
+    /// ```
+    pub fn diagnose_dimensions(
+        &self,
+        category: &str,
+        amounts: &BTreeMap<String, f64>,
+        now_ms: u64,
+    ) -> Vec<DimensionBreach> {
+        let Some(envelope) = self.storage.get_envelope(category) else {
+            return Vec::new();
+        };
+        let envelope = rolled_over(&envelope, now_ms);
+
+        let mut breaches = Vec::new();
+        for (dimension, &requested) in amounts {
+            let available = envelope.available(dimension);
+            if requested > available {
+                breaches.push(DimensionBreach {
+                    dimension: dimension.clone(),
+                    requested,
+                    available,
+                });
+            }
+        }
+        breaches
+    }
+
+    /// Define a new (or replace an existing) [`MeteredEnvelope`] for
+    /// `category`, tracking typed, integer-unit [`ResourceDimension`]s rather
+    /// than the `f64`/named-dimension family above.
+    pub fn create_metered_envelope(
+        &mut self,
+        category: impl Into<String>,
+        limits: BTreeMap<ResourceDimension, u64>,
+        period_ms: u64,
+        starts_at_ms: u64,
+    ) {
+        let category = category.into();
+        let envelope = MeteredEnvelope::new(category.clone(), limits, period_ms, starts_at_ms);
+        self.metered.insert(category, envelope);
+    }
+
+    /// Evaluate `action`'s per-[`ResourceDimension`] cost (via `cost_model`)
+    /// against the `category` [`MeteredEnvelope`] as of `now_ms`.
+    ///
+    /// Does **not** modify any state — see [`check`](Self::check) for the
+    /// same does-not-mutate contract. On denial, [`BudgetResult::dimension`]
+    /// names the first exhausted dimension (in [`ResourceDimension`]'s
+    /// `Ord` order); on success it is `None`. Behaves like [`check`] with
+    /// `Config::pass_on_missing_envelope` when no metered envelope has been
+    /// created for `category`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aumos_governance_core::{
+    ///     budget::BudgetManager,
+    ///     metered_budget::{CostModel, ResourceDimension},
+    ///     storage::InMemoryStorage,
+    ///     config::Config,
+    /// };
+    /// use std::collections::BTreeMap;
+    ///
+    /// struct FixedCost;
+    /// impl CostModel for FixedCost {
+    ///     fn cost_of(&self, _action: &str) -> BTreeMap<ResourceDimension, u64> {
+    ///         let mut cost = BTreeMap::new();
+    ///         cost.insert(ResourceDimension::LlmInputTokens, 2_000);
+    ///         cost
+    ///     }
+    /// }
+    ///
+    /// let mut manager = BudgetManager::new(Config::default(), InMemoryStorage::new());
+    /// let mut limits = BTreeMap::new();
+    /// limits.insert(ResourceDimension::LlmInputTokens, 1_000);
+    /// manager.create_metered_envelope("llm-calls", limits, 0, 0);
+    ///
+    /// let result = manager.check_metered("llm-calls", "summarize", &FixedCost, 0);
+    /// assert!(!result.permitted);
+    /// assert_eq!(result.dimension, Some(ResourceDimension::LlmInputTokens));
+    /// ```
+    pub fn check_metered(
+        &self,
+        category: &str,
+        action: &str,
+        cost_model: &dyn CostModel,
+        now_ms: u64,
+    ) -> BudgetResult {
+        let cost = cost_model.cost_of(action);
+        let requested: u64 = cost.values().sum();
+        match self.metered.get(category) {
+            Some(envelope) => match envelope.can_spend(&cost, now_ms) {
+                Ok(()) => {
+                    // Tightest headroom among the dimensions this action costs.
+                    let available = cost
+                        .iter()
+                        .filter(|(_, &amount)| amount > 0)
+                        .map(|(dimension, _)| envelope.available(dimension, now_ms))
+                        .min()
+                        .unwrap_or(u64::MAX);
+                    BudgetResult {
+                        permitted: true,
+                        available: available as f64,
+                        requested: requested as f64,
+                        category: category.into(),
+                        reason: format!("Spend fits within metered envelope '{}'.", category),
+                        dimension: None,
+                    }
+                }
+                Err(dimension) => {
+                    let available = envelope.available(&dimension, now_ms);
+                    BudgetResult {
+                        permitted: false,
+                        available: available as f64,
+                        requested: requested as f64,
+                        category: category.into(),
+                        reason: format!(
+                            "Spend exceeds metered envelope '{}' in dimension '{:?}' (available: {}).",
+                            category, dimension, available
+                        ),
+                        dimension: Some(dimension),
+                    }
+                }
+            },
+            None => {
+                if self.config.pass_on_missing_envelope {
+                    BudgetResult {
+                        permitted: true,
+                        available: u64::MAX as f64,
+                        requested: requested as f64,
+                        category: category.into(),
+                        reason: format!(
+                            "No metered envelope configured for '{}'; passing (open budget).",
+                            category
+                        ),
+                        dimension: None,
+                    }
+                } else {
+                    BudgetResult {
+                        permitted: false,
+                        available: 0.0,
+                        requested: requested as f64,
+                        category: category.into(),
+                        reason: format!(
+                            "No metered envelope configured for '{}'; denying (strict mode).",
+                            category
+                        ),
+                        dimension: None,
+                    }
+                }
+            }
+        }
+    }
+
+    /// Debit `action`'s per-[`ResourceDimension`] cost (via `cost_model`)
+    /// from the `category` [`MeteredEnvelope`] as of `now_ms`.
+    ///
+    /// Mirrors [`record`](Self::record): re-validates headroom itself (so
+    /// overspending is impossible even if [`check_metered`](Self::check_metered)
+    /// is skipped) and applies period rollover regardless of outcome.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BudgetError::NoEnvelope`] if no metered envelope exists for
+    /// `category`, or [`BudgetError::Exceeded`] (naming the binding
+    /// dimension via its `Debug` formatting) if the debit would exceed the
+    /// (post-rollover) limit.
+    pub fn record_metered(
+        &mut self,
+        category: &str,
+        action: &str,
+        cost_model: &dyn CostModel,
+        now_ms: u64,
+    ) -> Result<(), BudgetError> {
+        let cost = cost_model.cost_of(action);
+        let envelope = self.metered.get_mut(category).ok_or_else(|| BudgetError::NoEnvelope {
+            category: category.into(),
+        })?;
+        envelope.spend(&cost, now_ms).map_err(|dimension| {
+            let available = envelope.available(&dimension, now_ms);
+            let requested = cost.get(&dimension).copied().unwrap_or(0);
+            BudgetError::Exceeded {
+                category: category.into(),
+                dimension: format!("{:?}", dimension),
+                requested: requested as f64,
+                available: available as f64,
+            }
+        })
+    }
+
+    /// Begin a new [`ActionSession`] — an in-memory, net-metered accounting
+    /// unit for an action made up of several costed sub-operations. See the
+    /// module-level "Action sessions" section for when to reach for this
+    /// instead of [`checkpoint`](Self::checkpoint)/[`revert_to`](Self::revert_to).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aumos_governance_core::{budget::BudgetManager, storage::InMemoryStorage, config::Config};
+    ///
+    /// let mut manager = BudgetManager::new(Config::default(), InMemoryStorage::new());
+    /// manager.create_envelope("financial", 100.0, 0, 0);
+    ///
+    /// let mut session = manager.begin_action();
+    /// session.charge(&manager, "financial", 80.0, 0).unwrap();  // reserve a worst-case cost
+    /// session.refund("financial", 30.0);                       // actual sub-operation cost less
+    ///
+    /// let results = session.settle(&mut manager, 0);
+    /// assert_eq!(results[0].requested, 50.0);                   // only the net 50.0 was debited
+    /// assert_eq!(manager.check("financial", 0.0, 0).available, 50.0);
+    /// ```
+    pub fn begin_action(&self) -> ActionSession {
+        ActionSession::default()
+    }
+}
+
+/// One dimension that would be exceeded by a spend, as reported by
+/// [`BudgetManager::diagnose_dimensions`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DimensionBreach {
+    /// The dimension that does not have enough headroom.
+    pub dimension: String,
+    /// The amount requested in `dimension`.
+    pub requested: f64,
+    /// The amount actually available in `dimension` before this spend.
+    pub available: f64,
+}
+
+/// Fluent builder for a multi-dimensional envelope's resource schema —
+/// an alternative to assembling the `limits` map by hand before calling
+/// [`BudgetManager::create_envelope_with_dimensions`].
+///
+/// # Examples
+///
+/// ```rust
+/// use aumos_governance_core::{
+///     budget::{BudgetManager, EnvelopeBuilder},
+///     storage::InMemoryStorage,
+///     config::Config,
+/// };
+///
+/// let mut manager = BudgetManager::new(Config::default(), InMemoryStorage::new());
+///
+/// EnvelopeBuilder::new("llm-calls")
+///     .dimension("tokens", 100_000.0)
+///     .dimension("usd", 5.0)
+///     .period(86_400_000, 0)
+///     .build(&mut manager);
+///
+/// assert!(manager.get_envelope("llm-calls").is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct EnvelopeBuilder {
+    category: String,
+    limits: BTreeMap<String, f64>,
+    period_ms: u64,
+    starts_at_ms: u64,
+}
+
+impl EnvelopeBuilder {
+    /// Start building an envelope's schema for `category`. No dimensions are
+    /// declared yet, and the envelope never resets (`period_ms: 0`) unless
+    /// [`period`](Self::period) is called.
+    pub fn new(category: &str) -> Self {
+        Self {
+            category: category.into(),
+            limits: BTreeMap::new(),
+            period_ms: 0,
+            starts_at_ms: 0,
+        }
+    }
+
+    /// Declare a resource dimension named `name` with a per-period `limit`.
+    /// Calling this again for the same `name` replaces its limit.
+    pub fn dimension(mut self, name: &str, limit: f64) -> Self {
+        self.limits.insert(name.into(), limit);
+        self
+    }
+
+    /// Set the envelope's rollover period. See [`BudgetManager::create_envelope`]
+    /// for `period_ms`/`starts_at_ms` semantics.
+    pub fn period(mut self, period_ms: u64, starts_at_ms: u64) -> Self {
+        self.period_ms = period_ms;
+        self.starts_at_ms = starts_at_ms;
+        self
+    }
+
+    /// Register the declared schema as `category`'s envelope on `manager`.
+    pub fn build<S: Storage>(self, manager: &mut BudgetManager<S>) {
+        manager.create_envelope_with_dimensions(&self.category, self.limits, self.period_ms, self.starts_at_ms);
+    }
+}
+
+/// Identifies a checkpoint opened by [`BudgetManager::checkpoint`].
+pub type CheckpointId = u64;
+
+/// One entry in [`BudgetManager`]'s checkpoint stack: the categories touched
+/// since it was opened, mapped to their envelope as it was just before that
+/// first touch.
+struct Checkpoint {
+    id: CheckpointId,
+    snapshots: BTreeMap<String, Envelope>,
+}
+
+/// An in-progress action's net budget delta across one or more categories,
+/// opened with [`BudgetManager::begin_action`].
+///
+/// Unlike [`checkpoint`](BudgetManager::checkpoint)/[`revert_to`]
+/// (BudgetManager::revert_to), which debit the envelope immediately and
+/// later undo it if the action is denied, an `ActionSession` never touches
+/// the stored envelope at all until [`settle`](Self::settle) — every
+/// [`charge`](Self::charge)/[`refund`](Self::refund) call only updates an
+/// in-memory net delta per category, and `settle` applies just that net
+/// figure. This is the right tool for an action with several costed
+/// sub-steps that can over- and under-shoot each other (e.g. a multi-call
+/// tool invocation that reserves a worst-case cost up front and refunds the
+/// unused remainder) — net metering settles the final balance once, rather
+/// than debiting and (maybe) reverting on every sub-step.
+#[derive(Debug, Default)]
+pub struct ActionSession {
+    originals: BTreeMap<String, Envelope>,
+    net: BTreeMap<String, f64>,
+}
+
+impl ActionSession {
+    /// Charge `amount` against `category`'s net delta, checked against the
+    /// envelope as it stood when this session first touched `category` (not
+    /// against any other session or write concurrently in flight) — so a
+    /// sequence of charges and refunds within one session sees a stable
+    /// snapshot throughout, exactly as one `check`/`record` pair would.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BudgetError::NoEnvelope`] if no envelope exists for
+    /// `category`, or [`BudgetError::Exceeded`] if the category's *net*
+    /// delta so far, including this charge, would exceed the snapshotted
+    /// headroom in any dimension. A denied charge leaves the session's net
+    /// delta unchanged.
+    pub fn charge<S: Storage>(
+        &mut self,
+        manager: &BudgetManager<S>,
+        category: &str,
+        amount: f64,
+        now_ms: u64,
+    ) -> Result<(), BudgetError> {
+        let envelope = self.snapshot(manager, category, now_ms)?;
+        let candidate_net = self.net.get(category).copied().unwrap_or(0.0) + amount;
+
+        let mut amounts = BTreeMap::new();
+        amounts.insert(String::from(Envelope::DEFAULT_DIMENSION), candidate_net);
+        let binding = binding_dimension(&envelope, &amounts);
+        if !binding.permitted {
+            return Err(BudgetError::Exceeded {
+                category: category.into(),
+                dimension: binding.dimension,
+                requested: binding.requested,
+                available: binding.available,
+            });
+        }
+
+        self.net.insert(category.into(), candidate_net);
+        Ok(())
+    }
+
+    /// Give back `amount` against `category`'s net delta within this
+    /// session — undoing a previous [`charge`](Self::charge) that turned out
+    /// to cost less than reserved.
+    ///
+    /// The net delta is floored at `0.0`: a refund can only ever undo a
+    /// charge already accumulated in this same session, so it can never
+    /// push `category`'s net below zero, and therefore can never push its
+    /// settled headroom above the snapshot taken when this session began.
+    /// `category` need not have been charged yet — refunding an untouched
+    /// category is a no-op.
+    pub fn refund(&mut self, category: &str, amount: f64) {
+        let net = self.net.entry(category.into()).or_insert(0.0);
+        *net = (*net - amount).max(0.0);
+    }
+
+    /// Apply every category's net delta to its stored envelope and return
+    /// one [`BudgetResult`] per category this session touched.
+    ///
+    /// Each category is settled independently: a category with a positive
+    /// net delta is [`record`](BudgetManager::record)ed, a negative net delta
+    /// is [`refund`](BudgetManager::refund)ed, and a net delta of exactly
+    /// `0.0` is skipped entirely — its envelope is never touched, so a
+    /// session that charges and then fully refunds the same category leaves
+    /// no trace. A category's settlement failing (e.g. another write
+    /// narrowed its headroom since this session's snapshot) does not affect
+    /// any other category's settlement. Callers that need the settlement
+    /// logged atomically with an audit record should write that record
+    /// immediately after this call returns, before any other mutation of
+    /// the same categories can interleave.
+    pub fn settle<S: Storage>(self, manager: &mut BudgetManager<S>, now_ms: u64) -> Vec<BudgetResult> {
+        let mut results = Vec::with_capacity(self.net.len());
+
+        for (category, net) in self.net {
+            if net > 0.0 {
+                let check = manager.check(&category, net, now_ms);
+                if !check.permitted {
+                    results.push(check);
+                    continue;
+                }
+                match manager.record(&category, net, now_ms) {
+                    Ok(()) => results.push(check),
+                    Err(error) => results.push(BudgetResult {
+                        permitted: false,
+                        available: check.available,
+                        requested: net,
+                        category: category.clone(),
+                        reason: format!("{}", error),
+                        dimension: None,
+                    }),
+                }
+            } else if net < 0.0 {
+                let available_before = manager
+                    .get_envelope(&category)
+                    .map(|envelope| envelope.available(Envelope::DEFAULT_DIMENSION))
+                    .unwrap_or(0.0);
+                let reason = match manager.refund(&category, -net, now_ms) {
+                    Ok(()) => "Net refund settled.".into(),
+                    Err(error) => format!("{}", error),
+                };
+                results.push(BudgetResult {
+                    permitted: true,
+                    available: available_before - net,
+                    requested: net,
+                    category: category.clone(),
+                    reason,
+                    dimension: None,
+                });
+            }
+            // net == 0.0: charges and refunds cancelled out exactly —
+            // nothing to settle, and the category is left unreported.
+        }
+
+        results
+    }
+
+    /// Return `category`'s envelope as it stood the first time this session
+    /// touched it, fetching and rollover-normalizing it from storage on
+    /// first touch and reusing the cached snapshot afterwards.
+    fn snapshot<S: Storage>(
+        &mut self,
+        manager: &BudgetManager<S>,
+        category: &str,
+        now_ms: u64,
+    ) -> Result<Envelope, BudgetError> {
+        if let Some(envelope) = self.originals.get(category) {
+            return Ok(envelope.clone());
+        }
+
+        let envelope = manager.storage.get_envelope(category).ok_or_else(|| BudgetError::NoEnvelope {
+            category: category.into(),
+        })?;
+        let envelope = rolled_over(&envelope, now_ms);
+        self.originals.insert(category.into(), envelope.clone());
+        Ok(envelope)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Return the view of `envelope` as of `now_ms`, resetting every dimension's
+/// `spent` together and advancing `starts_at_ms` by however many whole
+/// periods have elapsed.
+///
+/// A `period_ms` of `0` disables rollover entirely (the envelope never
+/// resets). `now_ms` before `starts_at_ms` (clock skew, or a test using a
+/// fixed `now_ms`) elapses zero periods and is returned unchanged. Rollover
+/// is all-dimensions-at-once — there's one period clock per envelope, not
+/// one per dimension.
+fn rolled_over(envelope: &Envelope, now_ms: u64) -> Envelope {
+    if envelope.period_ms == 0 {
+        return envelope.clone();
+    }
+
+    let elapsed_ms = now_ms.saturating_sub(envelope.starts_at_ms);
+    let periods_elapsed = elapsed_ms / envelope.period_ms;
+
+    if periods_elapsed == 0 {
+        return envelope.clone();
+    }
+
+    let mut rolled = envelope.clone();
+    rolled.spent.clear();
+    rolled.starts_at_ms += periods_elapsed * envelope.period_ms;
+    rolled
+}
+
+/// Outcome of evaluating `amounts` against `envelope`'s per-dimension
+/// headroom: the **binding dimension** is the one with the least headroom
+/// remaining after its requested amount — i.e. the one closest to, or over,
+/// its limit. If that dimension's post-spend headroom is non-negative, every
+/// other requested dimension's is too, so `permitted` for the binding
+/// dimension is `permitted` for the whole spend.
+struct BindingDimension {
+    dimension: String,
+    requested: f64,
+    available: f64,
+    permitted: bool,
+}
+
+fn binding_dimension(envelope: &Envelope, amounts: &BTreeMap<String, f64>) -> BindingDimension {
+    let mut binding: Option<(String, f64, f64, f64)> = None; // (dimension, requested, available, headroom_after)
+
+    for (dimension, &amount) in amounts {
+        let available = envelope.available(dimension);
+        let headroom_after = available - amount;
+        let is_tighter = match &binding {
+            Some((_, _, _, current_headroom_after)) => headroom_after < *current_headroom_after,
+            None => true,
+        };
+        if is_tighter {
+            binding = Some((dimension.clone(), amount, available, headroom_after));
+        }
+    }
+
+    match binding {
+        Some((dimension, requested, available, headroom_after)) => BindingDimension {
+            dimension,
+            requested,
+            available,
+            permitted: headroom_after >= 0.0,
+        },
+        // `amounts` was empty — nothing was requested, so nothing can be denied.
+        None => BindingDimension {
+            dimension: Envelope::DEFAULT_DIMENSION.into(),
+            requested: 0.0,
+            available: f64::MAX,
+            permitted: true,
+        },
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+
+    fn manager() -> BudgetManager<InMemoryStorage> {
+        BudgetManager::new(Config::default(), InMemoryStorage::new())
+    }
+
+    #[test]
+    fn revert_to_undoes_a_single_checkpoint() {
+        let mut m = manager();
+        m.create_envelope("financial", 500.0, 0, 0);
+
+        let checkpoint = m.checkpoint();
+        m.record("financial", 100.0, 0).unwrap();
+        assert_eq!(m.check("financial", 0.0, 0).available, 400.0);
+
+        m.revert_to(checkpoint);
+        assert_eq!(m.check("financial", 0.0, 0).available, 500.0);
+    }
+
+    #[test]
+    fn discard_commits_the_debit() {
+        let mut m = manager();
+        m.create_envelope("financial", 500.0, 0, 0);
+
+        let checkpoint = m.checkpoint();
+        m.record("financial", 100.0, 0).unwrap();
+        m.discard(checkpoint);
+
+        assert_eq!(m.check("financial", 0.0, 0).available, 400.0);
+    }
+
+    #[test]
+    fn revert_to_outer_unwinds_a_nested_inner_checkpoint_touching_the_same_category() {
+        let mut m = manager();
+        m.create_envelope("financial", 500.0, 0, 0);
+
+        let outer = m.checkpoint();
+        m.record("financial", 100.0, 0).unwrap();
+        let inner = m.checkpoint();
+        m.record("financial", 50.0, 0).unwrap();
+        m.discard(inner);
+
+        m.revert_to(outer);
+        assert_eq!(m.check("financial", 0.0, 0).available, 500.0);
+    }
+
+    /// Regression test for the nesting bug where `capture_for_checkpoint`
+    /// wrote a category's pre-touch snapshot into only the innermost open
+    /// checkpoint frame. Scenario: an outer checkpoint is open, an inner one
+    /// is opened on top of it, a category is touched for the *first time*
+    /// while the inner checkpoint is innermost (so only it captured the
+    /// category's pre-touch state), and the inner checkpoint is discarded
+    /// before the outer one is reverted. A correct implementation still
+    /// restores the category to its state from before either checkpoint was
+    /// opened; the buggy version left it at its post-touch value because the
+    /// outer frame never learned the category had been touched at all.
+    #[test]
+    fn revert_to_outer_restores_a_category_only_ever_touched_under_a_discarded_inner_checkpoint() {
+        let mut m = manager();
+        m.create_envelope("financial", 500.0, 0, 0);
+        m.create_envelope("compute", 200.0, 0, 0);
+
+        // "financial" is touched before the inner checkpoint opens, so both
+        // frames will have seen it; "compute" is untouched at that point.
+        let outer = m.checkpoint();
+        m.record("financial", 100.0, 0).unwrap();
+
+        let inner = m.checkpoint();
+        // "compute" is touched for the first time here — only `inner`
+        // captures its pre-touch state under the buggy implementation.
+        m.record("compute", 50.0, 0).unwrap();
+        m.discard(inner);
+
+        m.revert_to(outer);
+        assert_eq!(m.check("financial", 0.0, 0).available, 500.0);
+        assert_eq!(m.check("compute", 0.0, 0).available, 200.0);
+    }
+
+    #[test]
+    fn revert_to_unknown_checkpoint_is_a_no_op() {
+        let mut m = manager();
+        m.create_envelope("financial", 500.0, 0, 0);
+        m.record("financial", 100.0, 0).unwrap();
+
+        m.revert_to(9999);
+        assert_eq!(m.check("financial", 0.0, 0).available, 400.0);
+    }
+
+    struct TestCostModel;
+
+    impl CostModel for TestCostModel {
+        fn cost_of(&self, action: &str) -> BTreeMap<ResourceDimension, u64> {
+            let mut cost = BTreeMap::new();
+            if action == "summarize" {
+                cost.insert(ResourceDimension::LlmInputTokens, 2_000);
+                cost.insert(ResourceDimension::LlmOutputTokens, 200);
+            }
+            cost
+        }
+    }
+
+    #[test]
+    fn check_metered_permits_a_spend_within_every_dimension() {
+        let mut m = manager();
+        let mut limits = BTreeMap::new();
+        limits.insert(ResourceDimension::LlmInputTokens, 10_000);
+        limits.insert(ResourceDimension::LlmOutputTokens, 1_000);
+        m.create_metered_envelope("llm-calls", limits, 0, 0);
+
+        let result = m.check_metered("llm-calls", "summarize", &TestCostModel, 0);
+        assert!(result.permitted);
+        assert_eq!(result.dimension, None);
+    }
+
+    #[test]
+    fn check_metered_denies_and_reports_the_first_exhausted_dimension() {
+        let mut m = manager();
+        let mut limits = BTreeMap::new();
+        limits.insert(ResourceDimension::LlmInputTokens, 10_000);
+        limits.insert(ResourceDimension::LlmOutputTokens, 100);
+        m.create_metered_envelope("llm-calls", limits, 0, 0);
+
+        let result = m.check_metered("llm-calls", "summarize", &TestCostModel, 0);
+        assert!(!result.permitted);
+        assert_eq!(result.dimension, Some(ResourceDimension::LlmOutputTokens));
+    }
+
+    #[test]
+    fn record_metered_debits_every_dimension_and_is_reflected_by_check_metered() {
+        let mut m = manager();
+        let mut limits = BTreeMap::new();
+        limits.insert(ResourceDimension::LlmInputTokens, 10_000);
+        limits.insert(ResourceDimension::LlmOutputTokens, 1_000);
+        m.create_metered_envelope("llm-calls", limits, 0, 0);
+
+        m.record_metered("llm-calls", "summarize", &TestCostModel, 0).unwrap();
+
+        let result = m.check_metered("llm-calls", "summarize", &TestCostModel, 0);
+        assert!(result.permitted);
+
+        // On a tighter 4,000/210 envelope, one call leaves only 2,000/10
+        // input/output tokens of headroom, so another 2,000/200 fits input
+        // but not output.
+        let mut tight_limits = BTreeMap::new();
+        tight_limits.insert(ResourceDimension::LlmInputTokens, 4_000);
+        tight_limits.insert(ResourceDimension::LlmOutputTokens, 210);
+        m.create_metered_envelope("tight", tight_limits, 0, 0);
+        m.record_metered("tight", "summarize", &TestCostModel, 0).unwrap();
+        let result = m.check_metered("tight", "summarize", &TestCostModel, 0);
+        assert!(!result.permitted);
+        assert_eq!(result.dimension, Some(ResourceDimension::LlmOutputTokens));
+    }
+
+    #[test]
+    fn record_metered_with_no_envelope_returns_no_envelope_error() {
+        let mut m = manager();
+        let error = m.record_metered("missing", "summarize", &TestCostModel, 0).unwrap_err();
+        assert_eq!(error, BudgetError::NoEnvelope { category: "missing".into() });
+    }
 }
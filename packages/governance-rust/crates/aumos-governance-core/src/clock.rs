@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! Injectable time source for trust expiry, budget rollover, and audit
+//! timestamps.
+//!
+//! [`Clock::now_ms`] is the one place [`TrustManager`](crate::trust::TrustManager),
+//! [`BudgetManager`](crate::budget::BudgetManager), and
+//! [`AuditLogger`](crate::audit::AuditLogger) ask "what time is it" — each
+//! defaults to [`SystemClock`] under the `std` feature (a bare `0` under
+//! pure `no_std`, same as the wall-clock fallback this replaces), and each
+//! accepts a different [`Clock`] via its own `with_clock` builder. Swap in a
+//! [`ManualClock`] to pin time in tests — pinning trust-expiry and
+//! budget-window boundaries exactly — or to drive time from a host-supplied
+//! source in a `no_std`/WASM embedding that has no OS clock to read.
+//!
+//! [`GovernanceEngine::with_clock`](crate::engine::GovernanceEngine::with_clock)
+//! installs one [`Clock`] across the engine and all three managers at once,
+//! so a single [`ManualClock`] can be advanced from outside and every gate
+//! sees the same time.
+
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// A source of the current time, in milliseconds since the Unix epoch.
+pub trait Clock {
+    /// The current time, in milliseconds since the Unix epoch.
+    fn now_ms(&self) -> u64;
+}
+
+/// The default [`Clock`]: reads the OS wall clock via [`std::time::SystemTime`].
+///
+/// Only available under the `std` feature — a pure `no_std` build has no OS
+/// clock to read and falls back to a [`ManualClock`] pinned at `0` until a
+/// host supplies a real one.
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64
+    }
+}
+
+/// A [`Clock`] whose time is set explicitly rather than read from the OS.
+///
+/// Used in tests to pin exact expiry/rollover boundaries, and by `no_std`
+/// hosts (an embedded RTC, a WASM runtime's imported clock) that drive time
+/// through their own source instead of `std::time::SystemTime`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aumos_governance_core::clock::{Clock, ManualClock};
+///
+/// let clock = ManualClock::new(1_000);
+/// assert_eq!(clock.now_ms(), 1_000);
+///
+/// clock.advance(500);
+/// assert_eq!(clock.now_ms(), 1_500);
+///
+/// clock.set(0);
+/// assert_eq!(clock.now_ms(), 0);
+/// ```
+#[derive(Debug, Default)]
+pub struct ManualClock(AtomicU64);
+
+impl ManualClock {
+    /// Create a [`ManualClock`] starting at `now_ms`.
+    pub fn new(now_ms: u64) -> Self {
+        Self(AtomicU64::new(now_ms))
+    }
+
+    /// Set the clock to an explicit `now_ms`.
+    pub fn set(&self, now_ms: u64) {
+        self.0.store(now_ms, Ordering::Relaxed);
+    }
+
+    /// Advance the clock by `delta_ms`.
+    pub fn advance(&self, delta_ms: u64) {
+        self.0.fetch_add(delta_ms, Ordering::Relaxed);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_ms(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The crate's default [`Clock`]: [`SystemClock`] under `std`, a
+/// [`ManualClock`] pinned at `0` under pure `no_std` — the same fallback
+/// `current_time_ms()` used before this module existed, now overridable
+/// per-manager instead of hardcoded.
+///
+/// Shared via `Arc` rather than `Rc` (and bounded `Send + Sync`) so a
+/// [`Clock`] can be handed to [`GatePipeline`](crate::gate::GatePipeline)'s
+/// boxed gates, which — like the rest of that pipeline — require their
+/// trait objects to be `Send + Sync`.
+pub(crate) fn default_clock() -> Arc<dyn Clock + Send + Sync> {
+    #[cfg(feature = "std")]
+    {
+        Arc::new(SystemClock)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        Arc::new(ManualClock::new(0))
+    }
+}
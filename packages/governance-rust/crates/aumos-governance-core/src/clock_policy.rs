@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! Clock-skew guardrails for caller-supplied timestamps.
+//!
+//! [`TrustAssignment`](crate::types::TrustAssignment), [`ConsentGrant`](crate::types::ConsentGrant),
+//! and [`AuditRecord`](crate::types::AuditRecord) all carry `*_at_ms` /
+//! `expires_at_ms` values that, left unchecked, are trusted at face value —
+//! a record stamped far in the future (by mistake or deliberately) silently
+//! corrupts [`AuditFilter`](crate::types::AuditFilter) ordering and defeats
+//! expiry checks that assume a timestamp can only be in the past. [`ClockPolicy`]
+//! bounds that: how far ahead of a manager's own [`Clock`](crate::clock::Clock)
+//! a timestamp may sit, and that an expiry sits strictly after the record it
+//! expires.
+//!
+//! This is opt-in. It is consulted only by the `_checked` entry points —
+//! [`TrustManager::set_level_with_expiry_checked`](crate::trust::TrustManager::set_level_with_expiry_checked),
+//! [`TrustManager::set_level_signed`](crate::trust::TrustManager::set_level_signed),
+//! [`ConsentManager::record_checked`](crate::consent::ConsentManager::record_checked), and
+//! [`AuditLogger::log_checked`](crate::audit::AuditLogger::log_checked) — so
+//! existing callers that already trust their own inputs keep working exactly
+//! as before.
+
+use core::fmt;
+
+/// How far a caller-supplied timestamp may drift ahead of a manager's clock,
+/// and the requirement that an expiry outlive the record it expires.
+///
+/// `Config::max_clock_drift_ms` seeds the default a manager builds for
+/// itself in `new()`; install a different bound with a manager's own
+/// `with_clock_policy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockPolicy {
+    /// Maximum number of milliseconds a `*_at_ms` value may sit ahead of
+    /// `now` before it is rejected as future-dated.
+    pub max_forward_drift_ms: u64,
+}
+
+impl Default for ClockPolicy {
+    /// A 2-second allowance — enough to absorb ordinary clock skew between
+    /// the caller and the manager's own [`Clock`](crate::clock::Clock)
+    /// without letting a meaningfully future-dated record through.
+    fn default() -> Self {
+        Self {
+            max_forward_drift_ms: 2_000,
+        }
+    }
+}
+
+impl ClockPolicy {
+    /// Build a policy with the given forward-drift allowance.
+    pub fn new(max_forward_drift_ms: u64) -> Self {
+        Self { max_forward_drift_ms }
+    }
+
+    /// Reject `value_ms` if it sits more than `max_forward_drift_ms` ahead of
+    /// `now_ms`. `field` names the value being checked, for the error.
+    pub fn check_forward_drift(
+        &self,
+        field: &'static str,
+        value_ms: u64,
+        now_ms: u64,
+    ) -> Result<(), ClockPolicyError> {
+        if value_ms > now_ms.saturating_add(self.max_forward_drift_ms) {
+            return Err(ClockPolicyError::FutureDated {
+                field,
+                value_ms,
+                now_ms,
+                max_drift_ms: self.max_forward_drift_ms,
+            });
+        }
+        Ok(())
+    }
+
+    /// Reject `expires_at_ms`, when present, unless it is strictly greater
+    /// than `record_ms` (the assignment/record time it would expire).
+    pub fn check_expiry_after(
+        &self,
+        record_ms: u64,
+        expires_at_ms: Option<u64>,
+    ) -> Result<(), ClockPolicyError> {
+        if let Some(expiry) = expires_at_ms {
+            if expiry <= record_ms {
+                return Err(ClockPolicyError::ExpiryNotAfterRecordTime {
+                    record_ms,
+                    expires_at_ms: expiry,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Reject `new_ms` if it falls before `previous_ms` — the audit append
+    /// path's time-ordering check.
+    pub fn check_monotonic(&self, previous_ms: u64, new_ms: u64) -> Result<(), ClockPolicyError> {
+        if new_ms < previous_ms {
+            return Err(ClockPolicyError::OutOfOrderTimestamp {
+                previous_ms,
+                new_ms,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Why a timestamp failed [`ClockPolicy`] validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockPolicyError {
+    /// `field` sits more than `max_drift_ms` ahead of `now_ms`.
+    FutureDated {
+        /// Name of the offending field (e.g. `"expires_at_ms"`).
+        field: &'static str,
+        /// The rejected value.
+        value_ms: u64,
+        /// The clock reading it was checked against.
+        now_ms: u64,
+        /// The [`ClockPolicy::max_forward_drift_ms`] that was exceeded.
+        max_drift_ms: u64,
+    },
+    /// An `expires_at_ms` did not strictly exceed the record time it applies to.
+    ExpiryNotAfterRecordTime {
+        /// The assignment/record time the expiry was checked against.
+        record_ms: u64,
+        /// The rejected expiry.
+        expires_at_ms: u64,
+    },
+    /// An appended timestamp fell before the previous record's.
+    OutOfOrderTimestamp {
+        /// The previous record's timestamp.
+        previous_ms: u64,
+        /// The rejected, out-of-order timestamp.
+        new_ms: u64,
+    },
+}
+
+impl fmt::Display for ClockPolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClockPolicyError::FutureDated {
+                field,
+                value_ms,
+                now_ms,
+                max_drift_ms,
+            } => write!(
+                f,
+                "{field} = {value_ms} is more than {max_drift_ms}ms ahead of now ({now_ms})"
+            ),
+            ClockPolicyError::ExpiryNotAfterRecordTime {
+                record_ms,
+                expires_at_ms,
+            } => write!(
+                f,
+                "expires_at_ms ({expires_at_ms}) is not strictly after the record time ({record_ms})"
+            ),
+            ClockPolicyError::OutOfOrderTimestamp { previous_ms, new_ms } => write!(
+                f,
+                "timestamp_ms ({new_ms}) is before the previous record's ({previous_ms})"
+            ),
+        }
+    }
+}
@@ -22,6 +22,7 @@ use serde::{Deserialize, Serialize};
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Config {
     /// When `true`, the consent gate is enforced even when `Context.data_type`
     /// is `None`.  Defaults to `false` (consent gate is opt-in per action).
@@ -36,6 +37,22 @@ pub struct Config {
     /// pass (open budget). When `false`, a missing envelope denies the action.
     /// Defaults to `true` (no envelope = no limit configured = pass).
     pub pass_on_missing_envelope: bool,
+
+    /// Maximum number of delegated-authority hops
+    /// [`TrustManager::check_level`](crate::trust::TrustManager::check_level)
+    /// will recurse through when resolving a weighted approver that is
+    /// itself an agent with its own authority. A chain longer than this is
+    /// treated as unsatisfied rather than resolved, bounding the cost of
+    /// (and closing off cycles in) delegated trust. Defaults to `4`.
+    pub max_authority_depth: u8,
+
+    /// Default [`ClockPolicy::max_forward_drift_ms`](crate::clock_policy::ClockPolicy)
+    /// new managers build for themselves — the number of milliseconds a
+    /// caller-supplied timestamp may sit ahead of a manager's own
+    /// [`Clock`](crate::clock::Clock) before the `_checked` entry points
+    /// (e.g. [`TrustManager::set_level_with_expiry_checked`](crate::trust::TrustManager::set_level_with_expiry_checked))
+    /// reject it. Defaults to `2_000` (2 seconds).
+    pub max_clock_drift_ms: u64,
 }
 
 impl Default for Config {
@@ -44,6 +61,8 @@ impl Default for Config {
             require_consent: false,
             default_observer_on_missing: false,
             pass_on_missing_envelope: true,
+            max_authority_depth: 4,
+            max_clock_drift_ms: 2_000,
         }
     }
 }
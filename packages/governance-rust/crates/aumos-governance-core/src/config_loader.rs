@@ -13,11 +13,17 @@
 //! Both loaders are only available when the `std` feature is active
 //! (the default).
 //!
+//! For long-running engines, [`ConfigWatcher`] publishes a [`GovernanceConfig`]
+//! behind an atomic swap and can poll a TOML file for changes (or be driven
+//! by a SIGHUP handler via [`ConfigWatcher::reload_now`]) without dropping
+//! in-flight reads of the previous config.
+//!
 //! # File format
 //!
 //! ```toml
 //! trust_threshold  = 2      # integer 0–5 matching TrustLevel discriminants
-//! budget_limit     = 1000.0
+//! budget_limit     = "1.5k" # raw number or size-suffixed string (k/m/g, kb/mb/gb)
+//! period           = "1d"   # raw millisecond count or duration-suffixed string (ms/s/m/h/d)
 //! audit_level      = "standard"   # "minimal" | "standard" | "detailed"
 //! consent_required = false
 //! ```
@@ -27,7 +33,8 @@
 //! | Variable                     | Type    | Default   |
 //! |------------------------------|---------|-----------|
 //! | `AUMOS_TRUST_THRESHOLD`      | integer | 2         |
-//! | `AUMOS_BUDGET_LIMIT`         | float   | 1000.0    |
+//! | `AUMOS_BUDGET_LIMIT`         | float or size-suffixed string | 1000.0    |
+//! | `AUMOS_PERIOD`                | integer or duration-suffixed string | 0 |
 //! | `AUMOS_AUDIT_LEVEL`          | string  | "standard"|
 //! | `AUMOS_CONSENT_REQUIRED`     | boolean | false     |
 
@@ -35,11 +42,13 @@
 // "config-loader" implies "std", so std facilities are always available here.
 #![cfg(feature = "config-loader")]
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fs;
-use std::num::ParseFloatError;
 use std::num::ParseIntError;
+use std::sync::Arc;
 
+use arc_swap::ArcSwap;
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
@@ -100,9 +109,20 @@ pub struct GovernanceConfig {
     pub trust_threshold: u8,
 
     /// Default per-agent budget limit in the engine's configured cost unit.
-    #[serde(default = "default_budget_limit")]
+    ///
+    /// Accepts a raw number or a size-suffixed string (`"1.5k"`, `"2m"`,
+    /// `"1gb"`) via [`parse_scaled`] — see module docs for the unit table.
+    #[serde(default = "default_budget_limit", deserialize_with = "deserialize_scaled_size")]
     pub budget_limit: f64,
 
+    /// Length of one budget period in milliseconds.
+    ///
+    /// Accepts a raw millisecond count or a duration-suffixed string
+    /// (`"1d"`, `"12h"`, `"30m"`) via [`parse_scaled`]. Defaults to `0`
+    /// (no automatic period reset).
+    #[serde(default = "default_period_ms", deserialize_with = "deserialize_scaled_duration")]
+    pub period: u64,
+
     /// Verbosity of audit records produced by the engine.
     #[serde(default)]
     pub audit_level: AuditLevel,
@@ -115,18 +135,109 @@ pub struct GovernanceConfig {
 
 fn default_trust_threshold() -> u8 { 2 }
 fn default_budget_limit() -> f64 { 1000.0 }
+fn default_period_ms() -> u64 { 0 }
 
 impl Default for GovernanceConfig {
     fn default() -> Self {
         Self {
             trust_threshold:  default_trust_threshold(),
             budget_limit:     default_budget_limit(),
+            period:           default_period_ms(),
             audit_level:      AuditLevel::Standard,
             consent_required: false,
         }
     }
 }
 
+// ---------------------------------------------------------------------------
+// Suffix-aware scaled value parsing
+// ---------------------------------------------------------------------------
+
+/// Size-unit table for [`parse_scaled`]: multi-char suffixes first so `"gb"`
+/// is matched before the bare-prefix `"g"`, and so on down to the plain
+/// number case.
+const SIZE_UNITS: &[(&str, u64)] = &[
+    ("gb", 1_000_000_000),
+    ("g", 1_000_000_000),
+    ("mb", 1_000_000),
+    ("m", 1_000_000),
+    ("kb", 1_000),
+    ("k", 1_000),
+];
+
+/// Duration-unit table for [`parse_scaled`]. `"ms"` is listed before `"m"`/`"s"`
+/// so a value like `"500ms"` is never mis-parsed as `"500m" + "s"`.
+const DURATION_UNITS: &[(&str, u64)] = &[
+    ("ms", 1),
+    ("d", 86_400_000),
+    ("h", 3_600_000),
+    ("m", 60_000),
+    ("s", 1_000),
+];
+
+/// Parse a human-readable scaled value such as `"1.5k"` or `"2d"`.
+///
+/// `units` is tested in the order given — list multi-character suffixes
+/// (`"gb"`) ahead of the single-character prefixes they contain (`"g"`) so the
+/// longer match wins. The string is lowercased and trimmed first; the first
+/// matching suffix is stripped, the remaining numeric part is parsed as an
+/// `f64`, and the result is multiplied by the unit's factor. A plain number
+/// with no matching suffix is parsed as-is (factor `1`).
+///
+/// Returns `None` if no suffix matches and the bare string also fails to
+/// parse as a number.
+pub fn parse_scaled(value: &str, units: &[(&str, u64)]) -> Option<f64> {
+    let trimmed = value.trim().to_ascii_lowercase();
+
+    for &(suffix, factor) in units {
+        if let Some(numeric) = trimmed.strip_suffix(suffix) {
+            let numeric = numeric.trim();
+            if numeric.is_empty() {
+                continue;
+            }
+            return numeric.parse::<f64>().ok().map(|n| n * factor as f64);
+        }
+    }
+
+    trimmed.parse::<f64>().ok()
+}
+
+fn deserialize_scaled_size<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserialize_scaled(deserializer, SIZE_UNITS)
+}
+
+fn deserialize_scaled_duration<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserialize_scaled(deserializer, DURATION_UNITS).map(|value| value as u64)
+}
+
+/// Shared implementation backing [`deserialize_scaled_size`] and
+/// [`deserialize_scaled_duration`]: accept either a bare number or a
+/// suffixed string and resolve it through [`parse_scaled`].
+fn deserialize_scaled<'de, D>(deserializer: D, units: &[(&str, u64)]) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        Number(f64),
+        String(String),
+    }
+
+    match NumberOrString::deserialize(deserializer)? {
+        NumberOrString::Number(value) => Ok(value),
+        NumberOrString::String(raw) => parse_scaled(&raw, units).ok_or_else(|| {
+            serde::de::Error::custom(format!("cannot parse scaled value \"{}\"", raw))
+        }),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // ConfigError
 // ---------------------------------------------------------------------------
@@ -214,7 +325,8 @@ pub fn load_config(path: &str) -> Result<GovernanceConfig, ConfigError> {
 /// | Variable                 | Type    | Default   |
 /// |--------------------------|---------|-----------|
 /// | `AUMOS_TRUST_THRESHOLD`  | u8 0–5  | 2         |
-/// | `AUMOS_BUDGET_LIMIT`     | f64 ≥ 0 | 1000.0    |
+/// | `AUMOS_BUDGET_LIMIT`     | f64 ≥ 0, or size-suffixed string | 1000.0    |
+/// | `AUMOS_PERIOD`            | u64, or duration-suffixed string | 0 |
 /// | `AUMOS_AUDIT_LEVEL`      | string  | "standard"|
 /// | `AUMOS_CONSENT_REQUIRED` | bool    | false     |
 ///
@@ -225,22 +337,8 @@ pub fn load_config(path: &str) -> Result<GovernanceConfig, ConfigError> {
 /// integers.
 pub fn load_config_from_env() -> Result<GovernanceConfig, ConfigError> {
     let trust_threshold = read_env_u8("AUMOS_TRUST_THRESHOLD", default_trust_threshold())?;
-    if trust_threshold > 5 {
-        return Err(ConfigError::InvalidRange {
-            field: "AUMOS_TRUST_THRESHOLD".into(),
-            value: trust_threshold.to_string(),
-            reason: "must be in range 0–5 (matching TrustLevel discriminants)".into(),
-        });
-    }
-
-    let budget_limit = read_env_f64("AUMOS_BUDGET_LIMIT", default_budget_limit())?;
-    if budget_limit < 0.0 {
-        return Err(ConfigError::InvalidRange {
-            field: "AUMOS_BUDGET_LIMIT".into(),
-            value: budget_limit.to_string(),
-            reason: "must be >= 0.0".into(),
-        });
-    }
+    let budget_limit = read_env_scaled("AUMOS_BUDGET_LIMIT", default_budget_limit(), SIZE_UNITS)?;
+    let period = read_env_scaled("AUMOS_PERIOD", default_period_ms() as f64, DURATION_UNITS)? as u64;
 
     let audit_level = match std::env::var("AUMOS_AUDIT_LEVEL") {
         Ok(val) => AuditLevel::from_str_case_insensitive(&val)?,
@@ -249,12 +347,459 @@ pub fn load_config_from_env() -> Result<GovernanceConfig, ConfigError> {
 
     let consent_required = read_env_bool("AUMOS_CONSENT_REQUIRED", false)?;
 
-    Ok(GovernanceConfig {
+    let config = GovernanceConfig {
         trust_threshold,
         budget_limit,
+        period,
         audit_level,
         consent_required,
-    })
+    };
+    validate_ranges(&config)?;
+    Ok(config)
+}
+
+/// Range checks applied by [`load_config_from_env`] and by [`ConfigWatcher`]
+/// (its initial load and every reload). [`load_config`] and
+/// [`ConfigBuilder::build`] do not apply them. Kept separate from field-level
+/// parsing so a reload can validate a config it didn't itself parse.
+fn validate_ranges(config: &GovernanceConfig) -> Result<(), ConfigError> {
+    if config.trust_threshold > 5 {
+        return Err(ConfigError::InvalidRange {
+            field: "trust_threshold".into(),
+            value: config.trust_threshold.to_string(),
+            reason: "must be in range 0–5 (matching TrustLevel discriminants)".into(),
+        });
+    }
+
+    if config.budget_limit < 0.0 {
+        return Err(ConfigError::InvalidRange {
+            field: "budget_limit".into(),
+            value: config.budget_limit.to_string(),
+            reason: "must be >= 0.0".into(),
+        });
+    }
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Layered configuration merge
+// ---------------------------------------------------------------------------
+
+/// Identifies which configuration layer supplied a field's final value.
+///
+/// Produced alongside the merged [`GovernanceConfig`] by [`ConfigBuilder::build`]
+/// so operators can answer "where did `trust_threshold` come from?" when a
+/// decision is disputed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldOrigin {
+    /// The field was left unset by every layer and fell back to its built-in default.
+    Default,
+    /// The field was supplied by the TOML file at this path.
+    File(String),
+    /// The field was supplied by this environment variable.
+    Env(&'static str),
+}
+
+/// Per-field values collected from a single configuration layer. Every field
+/// is `Option` so a layer that does not mention a field leaves it for the
+/// next layer (or the default) to supply.
+#[derive(Debug, Clone, Default)]
+struct LayerValues {
+    trust_threshold:  Option<u8>,
+    budget_limit:     Option<f64>,
+    period:           Option<u64>,
+    audit_level:      Option<AuditLevel>,
+    consent_required: Option<bool>,
+}
+
+/// Composes configuration sources in ascending priority — built-in defaults,
+/// then TOML file(s), then `AUMOS_`-prefixed environment variables — into a
+/// single [`GovernanceConfig`] plus a provenance map recording which layer
+/// supplied each field's final value.
+///
+/// Layers are folded highest-priority-last: each layer's `Some` values
+/// overwrite whatever an earlier layer supplied, and any field still `None`
+/// after all layers falls back to its built-in default.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use aumos_governance_core::config_loader::ConfigBuilder;
+///
+/// let (config, origins) = ConfigBuilder::new()
+///     .with_defaults()
+///     .with_file("/etc/aumos/governance.toml")
+///     .with_env()
+///     .build()
+///     .expect("configuration should load");
+///
+/// println!("trust_threshold came from: {:?}", origins.get("trust_threshold"));
+/// println!("{}", config.trust_threshold);
+/// ```
+#[derive(Debug, Default)]
+pub struct ConfigBuilder {
+    layers: Vec<(LayerValues, FieldOriginLayer)>,
+    /// The first error raised by `with_file`/`with_env`, deferred until
+    /// `build` so the fluent chain never panics mid-construction.
+    pending_error: Option<ConfigError>,
+}
+
+/// The provenance tag applied to every field a given layer supplies.
+#[derive(Debug, Clone)]
+enum FieldOriginLayer {
+    Default,
+    File(String),
+    Env,
+}
+
+impl ConfigBuilder {
+    /// Create an empty builder with no layers.
+    pub fn new() -> Self {
+        Self { layers: Vec::new(), pending_error: None }
+    }
+
+    /// Add the built-in defaults as the lowest-priority layer.
+    pub fn with_defaults(mut self) -> Self {
+        let defaults = GovernanceConfig::default();
+        self.layers.push((
+            LayerValues {
+                trust_threshold:  Some(defaults.trust_threshold),
+                budget_limit:     Some(defaults.budget_limit),
+                period:           Some(defaults.period),
+                audit_level:      Some(defaults.audit_level),
+                consent_required: Some(defaults.consent_required),
+            },
+            FieldOriginLayer::Default,
+        ));
+        self
+    }
+
+    /// Layer in a TOML file's values, overriding any earlier layer.
+    ///
+    /// Every field present in the file is tracked with this path as its
+    /// origin; fields the file leaves out are left to other layers. The file
+    /// is read and parsed immediately; a bad path or malformed TOML is held
+    /// and returned by [`build`](Self::build).
+    pub fn with_file(mut self, path: &str) -> Self {
+        let loaded = load_config(path);
+        match loaded {
+            Ok(config) => {
+                let table = fs::read_to_string(path).ok().and_then(|content| toml::from_str::<toml::Value>(&content).ok());
+                let set = |key: &str| table.as_ref().and_then(|value| value.get(key)).is_some();
+                self.layers.push((
+                    LayerValues {
+                        trust_threshold:  set("trust_threshold").then_some(config.trust_threshold),
+                        budget_limit:     set("budget_limit").then_some(config.budget_limit),
+                        period:           set("period").then_some(config.period),
+                        audit_level:      set("audit_level").then_some(config.audit_level),
+                        consent_required: set("consent_required").then_some(config.consent_required),
+                    },
+                    FieldOriginLayer::File(path.to_owned()),
+                ));
+            }
+            Err(error) => {
+                // Defer the error to `build` so the fluent chain never panics.
+                self.layers.push((
+                    LayerValues::default(),
+                    FieldOriginLayer::File(path.to_owned()),
+                ));
+                if self.pending_error.is_none() {
+                    self.pending_error = Some(error);
+                }
+            }
+        }
+        self
+    }
+
+    /// Layer in `AUMOS_`-prefixed environment variables, overriding any
+    /// earlier layer. Unset variables leave their field as `None` for this
+    /// layer rather than falling back to a default here — the default layer
+    /// (if present) handles that.
+    pub fn with_env(mut self) -> Self {
+        match load_config_from_env() {
+            Ok(config) => {
+                let set = |var: &str| std::env::var(var).is_ok();
+                self.layers.push((
+                    LayerValues {
+                        trust_threshold:  set("AUMOS_TRUST_THRESHOLD").then_some(config.trust_threshold),
+                        budget_limit:     set("AUMOS_BUDGET_LIMIT").then_some(config.budget_limit),
+                        period:           set("AUMOS_PERIOD").then_some(config.period),
+                        audit_level:      set("AUMOS_AUDIT_LEVEL").then_some(config.audit_level),
+                        consent_required: set("AUMOS_CONSENT_REQUIRED").then_some(config.consent_required),
+                    },
+                    FieldOriginLayer::Env,
+                ));
+            }
+            Err(error) => {
+                if self.pending_error.is_none() {
+                    self.pending_error = Some(error);
+                }
+            }
+        }
+        self
+    }
+
+    /// Fold all layers (highest priority last) into a single
+    /// [`GovernanceConfig`] plus a provenance map naming the layer that
+    /// supplied each field's final value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`ConfigError`] raised by [`with_file`](Self::with_file)
+    /// or [`with_env`](Self::with_env).
+    pub fn build(self) -> Result<(GovernanceConfig, BTreeMap<&'static str, FieldOrigin>), ConfigError> {
+        if let Some(error) = self.pending_error {
+            return Err(error);
+        }
+
+        let mut trust_threshold:  Option<(u8, FieldOrigin)> = None;
+        let mut budget_limit:     Option<(f64, FieldOrigin)> = None;
+        let mut period:           Option<(u64, FieldOrigin)> = None;
+        let mut audit_level:      Option<(AuditLevel, FieldOrigin)> = None;
+        let mut consent_required: Option<(bool, FieldOrigin)> = None;
+
+        for (values, origin) in &self.layers {
+            if let Some(value) = values.trust_threshold {
+                trust_threshold = Some((value, field_origin(origin)));
+            }
+            if let Some(value) = values.budget_limit {
+                budget_limit = Some((value, field_origin(origin)));
+            }
+            if let Some(value) = values.period {
+                period = Some((value, field_origin(origin)));
+            }
+            if let Some(ref value) = values.audit_level {
+                audit_level = Some((value.clone(), field_origin(origin)));
+            }
+            if let Some(value) = values.consent_required {
+                consent_required = Some((value, field_origin(origin)));
+            }
+        }
+
+        let mut origins = BTreeMap::new();
+        // The env layer is tagged as a whole; name the variable behind each field.
+        let named = |origin: FieldOrigin, var: &'static str| match origin {
+            FieldOrigin::Env(_) => FieldOrigin::Env(var),
+            other => other,
+        };
+
+        let (trust_threshold, origin) =
+            trust_threshold.unwrap_or((default_trust_threshold(), FieldOrigin::Default));
+        origins.insert("trust_threshold", named(origin, "AUMOS_TRUST_THRESHOLD"));
+
+        let (budget_limit, origin) =
+            budget_limit.unwrap_or((default_budget_limit(), FieldOrigin::Default));
+        origins.insert("budget_limit", named(origin, "AUMOS_BUDGET_LIMIT"));
+
+        let (period, origin) = period.unwrap_or((default_period_ms(), FieldOrigin::Default));
+        origins.insert("period", named(origin, "AUMOS_PERIOD"));
+
+        let (audit_level, origin) = audit_level.unwrap_or((AuditLevel::default(), FieldOrigin::Default));
+        origins.insert("audit_level", named(origin, "AUMOS_AUDIT_LEVEL"));
+
+        let (consent_required, origin) = consent_required.unwrap_or((false, FieldOrigin::Default));
+        origins.insert("consent_required", named(origin, "AUMOS_CONSENT_REQUIRED"));
+
+        let config = GovernanceConfig {
+            trust_threshold,
+            budget_limit,
+            period,
+            audit_level,
+            consent_required,
+        };
+
+        Ok((config, origins))
+    }
+}
+
+fn field_origin(layer: &FieldOriginLayer) -> FieldOrigin {
+    match layer {
+        FieldOriginLayer::Default      => FieldOrigin::Default,
+        FieldOriginLayer::File(path)   => FieldOrigin::File(path.clone()),
+        FieldOriginLayer::Env          => FieldOrigin::Env("AUMOS_*"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Hot-reload with atomic swap
+// ---------------------------------------------------------------------------
+
+/// Watches a TOML config file and publishes reloaded [`GovernanceConfig`]
+/// values behind an [`ArcSwap`] so in-flight decisions always read a
+/// consistent snapshot.
+///
+/// A reload that fails to parse or fails [`validate_ranges`] is discarded —
+/// the previously published config keeps serving reads, and nothing is ever
+/// applied partially. A rejection by [`reload_now`](Self::reload_now) is
+/// reported via [`with_reload_error_handler`](Self::with_reload_error_handler)'s
+/// callback, if one is installed; otherwise it is silent beyond `reload_now`'s
+/// `false` return. Rejections in the background poll started by
+/// [`watch`](Self::watch) are always silent.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use aumos_governance_core::config_loader::ConfigWatcher;
+///
+/// let watcher = ConfigWatcher::new("/etc/aumos/governance.toml")
+///     .expect("initial config must load");
+/// let current = watcher.subscribe();
+///
+/// println!("trust_threshold: {}", current().trust_threshold);
+///
+/// // e.g. from a SIGHUP handler:
+/// watcher.reload_now();
+/// ```
+pub struct ConfigWatcher {
+    path: String,
+    current: Arc<ArcSwap<GovernanceConfig>>,
+    /// Set to `false` on drop to stop the background poll thread started by
+    /// [`ConfigWatcher::watch`].
+    running: Arc<std::sync::atomic::AtomicBool>,
+    /// Invoked with the watched path and the rejection reason whenever a
+    /// [`reload_now`](Self::reload_now) fails to parse or validate; the poll
+    /// thread started by [`watch`](Self::watch) does not use it. `None` (the default) means
+    /// rejected reloads are silent beyond their `false`/no-op return — this
+    /// type does no logging of its own; install a handler via
+    /// [`with_reload_error_handler`](Self::with_reload_error_handler) to
+    /// route failures through whatever structured logger or alerting path
+    /// the caller already has.
+    on_reload_error: Option<Arc<dyn Fn(&str, &ConfigError) + Send + Sync>>,
+}
+
+impl ConfigWatcher {
+    /// Load `path` for the first time. The returned watcher only reloads when
+    /// [`reload_now`](Self::reload_now) is called explicitly — use
+    /// [`watch`](Self::watch) for automatic polling.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConfigError`] if the initial load fails — there is no
+    /// "previous good config" to fall back on yet.
+    pub fn new(path: &str) -> Result<Self, ConfigError> {
+        let config = load_config(path)?;
+        validate_ranges(&config)?;
+        Ok(Self {
+            path: path.to_owned(),
+            current: Arc::new(ArcSwap::from_pointee(config)),
+            running: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            on_reload_error: None,
+        })
+    }
+
+    /// Install a callback invoked whenever a reload via
+    /// [`reload_now`](Self::reload_now) fails to parse or validate, receiving
+    /// the watched path and the [`ConfigError`] that rejected it. Replaces the
+    /// default no-op.
+    ///
+    /// The background poll started by [`watch`](Self::watch) does not call
+    /// this handler: its thread copies the handler slot inside `watch`,
+    /// before a handler can be installed on the returned watcher.
+    pub fn with_reload_error_handler(
+        mut self,
+        handler: impl Fn(&str, &ConfigError) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_reload_error = Some(Arc::new(handler));
+        self
+    }
+
+    /// Load `path` and spawn a background thread that polls its mtime every
+    /// `poll_interval` and calls [`reload_now`](Self::reload_now) whenever it
+    /// changes. The thread is stopped when the returned [`ConfigWatcher`] is
+    /// dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConfigError`] if the initial load fails.
+    pub fn watch(path: &str, poll_interval: std::time::Duration) -> Result<Self, ConfigError> {
+        let watcher = Self::new(path)?;
+        watcher.running.store(true, std::sync::atomic::Ordering::SeqCst);
+
+        let path = watcher.path.clone();
+        let current = Arc::clone(&watcher.current);
+        let running = Arc::clone(&watcher.running);
+        let on_reload_error = watcher.on_reload_error.clone();
+        let mut last_modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+
+        std::thread::spawn(move || {
+            while running.load(std::sync::atomic::Ordering::SeqCst) {
+                std::thread::sleep(poll_interval);
+                let modified = match fs::metadata(&path).and_then(|m| m.modified()) {
+                    Ok(modified) => modified,
+                    Err(_) => continue,
+                };
+                if last_modified == Some(modified) {
+                    continue;
+                }
+                last_modified = Some(modified);
+                reload_into(&path, &current, on_reload_error.as_deref());
+            }
+        });
+
+        Ok(watcher)
+    }
+
+    /// Return a cheap closure that reads the currently published config.
+    ///
+    /// Each call to the returned closure loads the latest [`Arc`] published
+    /// by [`reload_now`](Self::reload_now) — callers never observe a
+    /// partially-applied config.
+    pub fn subscribe(&self) -> impl Fn() -> Arc<GovernanceConfig> {
+        let current = Arc::clone(&self.current);
+        move || current.load_full()
+    }
+
+    /// Re-read the watched file and, if it parses and validates, publish it
+    /// as the new current config.
+    ///
+    /// Intended to be called from a file-watch callback or a SIGHUP handler.
+    /// On failure, the previous config remains published and the rejection
+    /// is reported via [`with_reload_error_handler`](Self::with_reload_error_handler)'s
+    /// callback, if one is installed.
+    ///
+    /// Returns `true` if the reload was applied, `false` if it was rejected.
+    pub fn reload_now(&self) -> bool {
+        reload_into(&self.path, &self.current, self.on_reload_error.as_deref())
+    }
+
+    /// The path being watched.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        self.running.store(false, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+/// Re-read `path` and, if it parses and validates, publish it into `current`.
+/// Shared by [`ConfigWatcher::reload_now`] and the background poll thread
+/// started by [`ConfigWatcher::watch`]. On rejection, `on_error` (if any) is
+/// called with `path` and the [`ConfigError`] — this function never writes
+/// to stderr itself.
+fn reload_into(
+    path: &str,
+    current: &Arc<ArcSwap<GovernanceConfig>>,
+    on_error: Option<&(dyn Fn(&str, &ConfigError) + Send + Sync)>,
+) -> bool {
+    match load_config(path).and_then(|config| {
+        validate_ranges(&config)?;
+        Ok(config)
+    }) {
+        Ok(config) => {
+            current.store(Arc::new(config));
+            true
+        }
+        Err(error) => {
+            if let Some(on_error) = on_error {
+                on_error(path, &error);
+            }
+            false
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -274,14 +819,14 @@ fn read_env_u8(key: &str, default: u8) -> Result<u8, ConfigError> {
     }
 }
 
-fn read_env_f64(key: &str, default: f64) -> Result<f64, ConfigError> {
+/// Accepts a suffixed string (`"1.5k"`, `"2d"`) via [`parse_scaled`], falling
+/// back to a plain numeric parse failure when neither applies.
+fn read_env_scaled(key: &str, default: f64, units: &[(&str, u64)]) -> Result<f64, ConfigError> {
     match std::env::var(key) {
-        Ok(val) => val.trim().parse::<f64>().map_err(|source: ParseFloatError| {
-            ConfigError::ParseField {
-                field: key.to_owned(),
-                value: val,
-                reason: source.to_string(),
-            }
+        Ok(val) => parse_scaled(&val, units).ok_or_else(|| ConfigError::ParseField {
+            field: key.to_owned(),
+            value: val.clone(),
+            reason: "cannot parse as a number or a suffixed value".into(),
         }),
         Err(_) => Ok(default),
     }
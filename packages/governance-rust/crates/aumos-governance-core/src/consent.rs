@@ -3,20 +3,67 @@
 
 //! Consent management.
 //!
-//! [`ConsentManager`] exposes three operations only:
+//! [`ConsentManager`] exposes five operations:
 //!
 //! * [`record`](ConsentManager::record)  — record a consent grant
 //! * [`check`](ConsentManager::check)   — check whether active consent exists
 //! * [`revoke`](ConsentManager::revoke) — revoke an existing consent
+//! * [`delegate`](ConsentManager::delegate) — let a consenting agent authorise a delegate to act for it
+//! * [`revoke_delegation`](ConsentManager::revoke_delegation) — withdraw a delegation
 //!
 //! Consent is always explicitly granted or revoked by an authorised party.
 //! The manager never generates proactive consent suggestions.
+//!
+//! A grant carries an optional expiry and an optional purpose (see
+//! [`ConsentGrant`]), matching the `purpose`/`data_type` fields already on
+//! [`Context`]. `check` enforces both: a grant whose `expiry_ms` has passed
+//! is treated as absent, and a grant scoped to a purpose denies any request
+//! that doesn't name that exact purpose.
+//!
+//! # Delegation
+//!
+//! An agent that directly holds consent for an action may delegate the
+//! right to act on its behalf to another agent, for that same action, via
+//! [`delegate`](ConsentManager::delegate). `check` then succeeds for the
+//! delegate too, by walking the delegation chain backwards from the
+//! checked agent until it reaches an agent with a genuinely active grant
+//! (modeled on delegated-account authorization, where a principal's access
+//! check can defer to a designated delegate). The walk is cycle-safe and
+//! depth-bounded by [`MAX_DELEGATION_DEPTH`], and the returned
+//! [`ConsentResult::reason`] names the full chain so audit logs can
+//! reconstruct who ultimately approved the action.
+//!
+//! # Policies
+//!
+//! Beyond the stored grant/delegation lookup, a [`ConsentManager`] can hold
+//! an ordered list of [`ConsentPolicy`] modules (added via
+//! [`add_policy`](ConsentManager::add_policy)), modeled on the admission
+//! policies of a policy-server architecture. `check` runs them, in order,
+//! before touching storage at all: the first [`PolicyVerdict::Deny`]
+//! short-circuits with its reason, the first [`PolicyVerdict::Allow`]
+//! permits, and if every policy returns [`PolicyVerdict::NotApplicable`] the
+//! manager falls back to the stored-grant lookup described above. This lets
+//! conditional rules ("allow `read_pii` only for purpose `support` in scope
+//! `eu`") be expressed and changed without recompiling — see
+//! [`DeclarativePolicy`] for a built-in, data-driven policy.
 
+use alloc::boxed::Box;
+use alloc::sync::Arc;
 use alloc::string::String;
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
 
+use crate::clock::Clock;
+use crate::clock_policy::{ClockPolicy, ClockPolicyError};
 use crate::config::Config;
 use crate::storage::Storage;
-use crate::types::ConsentResult;
+use crate::types::{ConsentGrant, ConsentResult, Context};
+
+/// Maximum number of delegation hops `check` will follow before giving up.
+/// Bounds the cost of a pathological or accidentally cyclic delegation
+/// graph; five hops comfortably covers any legitimate delegation depth.
+pub const MAX_DELEGATION_DEPTH: usize = 5;
 
 /// Manages consent grants for agent-action pairs.
 ///
@@ -27,38 +74,87 @@ use crate::types::ConsentResult;
 ///     consent::ConsentManager,
 ///     storage::InMemoryStorage,
 ///     config::Config,
+///     types::{Context, TrustLevel},
 /// };
 ///
 /// let mut manager = ConsentManager::new(Config::default(), InMemoryStorage::new());
+/// let ctx = Context {
+///     agent_id: "agent-001".into(),
+///     scope: "default".into(),
+///     required_trust: TrustLevel::Observer,
+///     cost: None,
+///     category: "default".into(),
+///     data_type: Some("read_pii".into()),
+///     purpose: None,
+/// };
 ///
 /// // No consent yet.
-/// let result = manager.check("agent-001", "read_pii");
+/// let result = manager.check("agent-001", "read_pii", &ctx, 0);
 /// assert!(!result.permitted);
 ///
-/// // Record consent.
-/// manager.record("agent-001", "read_pii");
-/// assert!(manager.check("agent-001", "read_pii").permitted);
+/// // Record consent, with no expiry or purpose restriction.
+/// manager.record("agent-001", "read_pii", None, None);
+/// assert!(manager.check("agent-001", "read_pii", &ctx, 0).permitted);
 ///
 /// // Revoke consent.
 /// manager.revoke("agent-001", "read_pii");
-/// assert!(!manager.check("agent-001", "read_pii").permitted);
+/// assert!(!manager.check("agent-001", "read_pii", &ctx, 0).permitted);
 /// ```
 pub struct ConsentManager<S: Storage> {
     config: Config,
     storage: S,
+    policies: Vec<Box<dyn ConsentPolicy>>,
+    clock: Arc<dyn Clock + Send + Sync>,
+    clock_policy: ClockPolicy,
 }
 
 impl<S: Storage> ConsentManager<S> {
-    /// Create a new [`ConsentManager`].
+    /// Create a new [`ConsentManager`] with no policies installed.
+    ///
+    /// Reads time from [`SystemClock`](crate::clock::SystemClock) by
+    /// default — install a different time source with
+    /// [`with_clock`](Self::with_clock). Builds its [`ClockPolicy`] from
+    /// `config.max_clock_drift_ms`; override with
+    /// [`with_clock_policy`](Self::with_clock_policy).
     pub fn new(config: Config, storage: S) -> Self {
-        Self { config, storage }
+        let clock_policy = ClockPolicy::new(config.max_clock_drift_ms);
+        Self {
+            config,
+            storage,
+            policies: Vec::new(),
+            clock: crate::clock::default_clock(),
+            clock_policy,
+        }
+    }
+
+    /// Install `clock` as this manager's time source, replacing the default.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = clock;
+        self
     }
 
-    /// Record that consent has been granted for `(agent_id, action)`.
+    /// Install `clock_policy` as this manager's clock-skew policy, replacing
+    /// the one derived from `Config::max_clock_drift_ms`.
+    pub fn with_clock_policy(mut self, clock_policy: ClockPolicy) -> Self {
+        self.clock_policy = clock_policy;
+        self
+    }
+
+    /// Append a [`ConsentPolicy`] to the end of the evaluation order.
+    ///
+    /// Policies run in the order they were added; install the most specific
+    /// ones first if you need a particular precedence.
+    pub fn add_policy(&mut self, policy: impl ConsentPolicy + 'static) {
+        self.policies.push(Box::new(policy));
+    }
+
+    /// Record that consent has been granted for `(agent_id, action)`, optionally
+    /// bounded by an expiry and/or scoped to a purpose.
     ///
     /// Consent is always granted by an authorised party — never by the engine
-    /// automatically.  Calling `record` again on an already-consented pair is
-    /// idempotent.
+    /// automatically. Calling `record` again on an already-consented pair
+    /// overwrites the previous grant (including any expiry or purpose it
+    /// carried) rather than merging with it.
     ///
     /// # Examples
     ///
@@ -69,19 +165,69 @@ impl<S: Storage> ConsentManager<S> {
     ///     config::Config,
     /// };
     ///
+    /// use aumos_governance_core::types::{Context, TrustLevel};
+    ///
     /// let mut manager = ConsentManager::new(Config::default(), InMemoryStorage::new());
-    /// manager.record("agent-001", "send_email");
-    /// assert!(manager.check("agent-001", "send_email").permitted);
+    /// manager.record("agent-001", "send_email", None, None);
+    ///
+    /// let ctx = Context {
+    ///     agent_id: "agent-001".into(),
+    ///     scope: "default".into(),
+    ///     required_trust: TrustLevel::Observer,
+    ///     cost: None,
+    ///     category: "default".into(),
+    ///     data_type: Some("send_email".into()),
+    ///     purpose: None,
+    /// };
+    /// assert!(manager.check("agent-001", "send_email", &ctx, 0).permitted);
     /// ```
-    pub fn record(&mut self, agent_id: &str, action: &str) {
-        self.storage.set_consent(agent_id, action, true);
+    pub fn record(&mut self, agent_id: &str, action: &str, expiry_ms: Option<u64>, purpose: Option<&str>) {
+        let grant = ConsentGrant {
+            granted: true,
+            expiry_ms,
+            purpose: purpose.map(String::from),
+        };
+        self.storage.set_consent(agent_id, action, grant);
+    }
+
+    /// Like [`record`](Self::record), but rejects `expiry_ms`, when present,
+    /// unless it is strictly after this manager's own clock reading at the
+    /// time of the call, per this manager's [`ClockPolicy`]. Nothing is
+    /// written on rejection.
+    pub fn record_checked(
+        &mut self,
+        agent_id: &str,
+        action: &str,
+        expiry_ms: Option<u64>,
+        purpose: Option<&str>,
+    ) -> Result<(), ClockPolicyError> {
+        self.clock_policy
+            .check_expiry_after(self.clock.now_ms(), expiry_ms)?;
+        self.record(agent_id, action, expiry_ms, purpose);
+        Ok(())
     }
 
-    /// Check whether active consent exists for `(agent_id, action)`.
+    /// Check whether `agent_id` may perform `action` as of `now_ms`, given
+    /// the full request `ctx`.
+    ///
+    /// Installed [`ConsentPolicy`] modules run first, in the order they were
+    /// added: a [`PolicyVerdict::Deny`] short-circuits with its reason, the
+    /// first [`PolicyVerdict::Allow`] permits, and if every policy returns
+    /// [`PolicyVerdict::NotApplicable`] evaluation falls back to the stored
+    /// grant/delegation lookup described below.
+    ///
+    /// The stored lookup checks active, unexpired, purpose-matching consent
+    /// (`ctx.purpose`) for `(agent_id, action)`. A grant with no `expiry_ms`
+    /// never lapses. A grant with no `purpose` accepts any requested
+    /// `purpose` (including `None`); a grant scoped to a purpose denies any
+    /// request whose `purpose` doesn't match it exactly — including a
+    /// request that supplies no purpose at all. If `agent_id` holds no
+    /// direct grant, the delegation chain (see the [module docs](self)) is
+    /// walked before giving up.
     ///
     /// When `Config::require_consent` is `false` **and** the action does not
     /// carry a `data_type`, the engine skips this check entirely (handled in
-    /// [`GovernanceEngine::check`]).  This method always evaluates faithfully
+    /// [`GovernanceEngine::check`]). This method always evaluates faithfully
     /// regardless of config — use it for direct consent queries.
     ///
     /// # Examples
@@ -91,35 +237,223 @@ impl<S: Storage> ConsentManager<S> {
     ///     consent::ConsentManager,
     ///     storage::InMemoryStorage,
     ///     config::Config,
+    ///     types::{Context, TrustLevel},
     /// };
     ///
+    /// fn ctx(purpose: Option<&str>) -> Context {
+    ///     Context {
+    ///         agent_id: "agent-001".into(),
+    ///         scope: "default".into(),
+    ///         required_trust: TrustLevel::Observer,
+    ///         cost: None,
+    ///         category: "default".into(),
+    ///         data_type: Some("read_logs".into()),
+    ///         purpose: purpose.map(String::from),
+    ///     }
+    /// }
+    ///
     /// let mut manager = ConsentManager::new(Config::default(), InMemoryStorage::new());
-    /// let result = manager.check("agent-001", "read_logs");
+    /// let result = manager.check("agent-001", "read_logs", &ctx(None), 0);
     /// assert!(!result.permitted);
     /// assert!(result.reason.contains("No consent"));
+    ///
+    /// manager.record("agent-001", "read_logs", Some(1_000), Some("fraud_detection"));
+    /// assert!(!manager.check("agent-001", "read_logs", &ctx(Some("marketing")), 500).permitted);
+    /// assert!(!manager.check("agent-001", "read_logs", &ctx(Some("fraud_detection")), 2_000).permitted);
+    /// assert!(manager.check("agent-001", "read_logs", &ctx(Some("fraud_detection")), 500).permitted);
     /// ```
-    pub fn check(&self, agent_id: &str, action: &str) -> ConsentResult {
-        let granted = self.storage.get_consent(agent_id, action);
-        let reason: String = if granted {
-            format!(
-                "Active consent exists for agent '{}' on action '{}'.",
-                agent_id, action
-            )
-        } else {
-            format!(
-                "No consent recorded for agent '{}' on action '{}'.",
-                agent_id, action
-            )
+    pub fn check(&self, agent_id: &str, action: &str, ctx: &Context, now_ms: u64) -> ConsentResult {
+        for policy in &self.policies {
+            match policy.evaluate(agent_id, action, ctx) {
+                PolicyVerdict::Deny(reason) => return ConsentResult { permitted: false, reason },
+                PolicyVerdict::Allow => {
+                    return ConsentResult {
+                        permitted: true,
+                        reason: format!(
+                            "Consent for agent '{}' on action '{}' permitted by policy.",
+                            agent_id, action
+                        ),
+                    };
+                }
+                PolicyVerdict::NotApplicable => {}
+            }
+        }
+
+        let purpose = ctx.purpose.as_deref();
+        let direct = self.check_direct(agent_id, action, now_ms, purpose);
+        if direct.permitted {
+            return direct;
+        }
+
+        if let Some(delegated) = self.check_delegated(agent_id, action, now_ms, purpose) {
+            return delegated;
+        }
+
+        direct
+    }
+
+    /// Evaluate only `agent_id`'s own recorded grant, ignoring delegation.
+    fn check_direct(&self, agent_id: &str, action: &str, now_ms: u64, purpose: Option<&str>) -> ConsentResult {
+        let grant = self.storage.get_consent(agent_id, action);
+
+        let (permitted, reason) = match grant {
+            None => (
+                false,
+                format!(
+                    "No consent recorded for agent '{}' on action '{}'.",
+                    agent_id, action
+                ),
+            ),
+            Some(grant) if !grant.granted => (
+                false,
+                format!(
+                    "Consent for agent '{}' on action '{}' has been revoked.",
+                    agent_id, action
+                ),
+            ),
+            Some(grant) if grant.expiry_ms.is_some_and(|expiry_ms| now_ms > expiry_ms) => (
+                false,
+                format!(
+                    "Consent for agent '{}' on action '{}' expired.",
+                    agent_id, action
+                ),
+            ),
+            Some(grant) if grant.purpose.is_some() && grant.purpose.as_deref() != purpose => (
+                false,
+                format!(
+                    "Consent for agent '{}' on action '{}' is scoped to a different purpose.",
+                    agent_id, action
+                ),
+            ),
+            Some(_) => (
+                true,
+                format!(
+                    "Active consent exists for agent '{}' on action '{}'.",
+                    agent_id, action
+                ),
+            ),
         };
-        ConsentResult {
-            permitted: granted,
-            reason,
+
+        ConsentResult { permitted, reason }
+    }
+
+    /// Walk the delegation chain backwards from `agent_id`, looking for an
+    /// ancestor that directly holds active consent for `action`. Returns
+    /// `None` if no such ancestor is reachable within
+    /// [`MAX_DELEGATION_DEPTH`] hops or the chain cycles back on itself.
+    fn check_delegated(&self, agent_id: &str, action: &str, now_ms: u64, purpose: Option<&str>) -> Option<ConsentResult> {
+        let mut path: Vec<String> = alloc::vec![String::from(agent_id)];
+        let mut current = String::from(agent_id);
+
+        for _ in 0..MAX_DELEGATION_DEPTH {
+            let grantor = self.storage.get_delegation(&current, action)?;
+            if path.contains(&grantor) {
+                // Cyclic delegation graph; no legitimate grantor to find.
+                return None;
+            }
+            path.push(grantor.clone());
+
+            if self.check_direct(&grantor, action, now_ms, purpose).permitted {
+                let mut chain = String::new();
+                for (index, hop) in path.iter().rev().enumerate() {
+                    if index > 0 {
+                        chain.push_str(" -> ");
+                    }
+                    chain.push_str(hop);
+                }
+                return Some(ConsentResult {
+                    permitted: true,
+                    reason: format!(
+                        "Consent for action '{}' granted via delegation chain: {} (agent '{}' holds direct consent).",
+                        action, chain, grantor
+                    ),
+                });
+            }
+
+            current = grantor;
         }
+
+        None
+    }
+
+    /// Let `grantor`, who must directly hold consent for `action`, authorise
+    /// `delegate` to act on its behalf for that same action.
+    ///
+    /// Calling `delegate` again for the same `(delegate, action)` pair
+    /// overwrites the previous delegation (i.e. a delegate may be
+    /// re-pointed at a new grantor). `delegate` does not itself check that
+    /// `grantor` currently holds consent — [`check`](Self::check) evaluates
+    /// that at lookup time, so a delegation recorded ahead of its grantor's
+    /// consent (or after it lapses) behaves correctly either way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aumos_governance_core::{
+    ///     consent::ConsentManager,
+    ///     storage::InMemoryStorage,
+    ///     config::Config,
+    ///     types::{Context, TrustLevel},
+    /// };
+    ///
+    /// let mut manager = ConsentManager::new(Config::default(), InMemoryStorage::new());
+    /// manager.record("agent-001", "send_email", None, None);
+    /// manager.delegate("agent-001", "agent-002", "send_email");
+    ///
+    /// let ctx = Context {
+    ///     agent_id: "agent-002".into(),
+    ///     scope: "default".into(),
+    ///     required_trust: TrustLevel::Observer,
+    ///     cost: None,
+    ///     category: "default".into(),
+    ///     data_type: Some("send_email".into()),
+    ///     purpose: None,
+    /// };
+    /// assert!(manager.check("agent-002", "send_email", &ctx, 0).permitted);
+    /// ```
+    pub fn delegate(&mut self, grantor: &str, delegate: &str, action: &str) {
+        self.storage.set_delegation(delegate, action, grantor);
+    }
+
+    /// Withdraw a previously recorded delegation for `(delegate, action)`.
+    ///
+    /// A no-op if no such delegation exists.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aumos_governance_core::{
+    ///     consent::ConsentManager,
+    ///     storage::InMemoryStorage,
+    ///     config::Config,
+    ///     types::{Context, TrustLevel},
+    /// };
+    ///
+    /// let mut manager = ConsentManager::new(Config::default(), InMemoryStorage::new());
+    /// manager.record("agent-001", "send_email", None, None);
+    /// manager.delegate("agent-001", "agent-002", "send_email");
+    /// manager.revoke_delegation("agent-002", "send_email");
+    ///
+    /// let ctx = Context {
+    ///     agent_id: "agent-002".into(),
+    ///     scope: "default".into(),
+    ///     required_trust: TrustLevel::Observer,
+    ///     cost: None,
+    ///     category: "default".into(),
+    ///     data_type: Some("send_email".into()),
+    ///     purpose: None,
+    /// };
+    /// assert!(!manager.check("agent-002", "send_email", &ctx, 0).permitted);
+    /// ```
+    pub fn revoke_delegation(&mut self, delegate: &str, action: &str) {
+        self.storage.remove_delegation(delegate, action);
     }
 
     /// Revoke any previously recorded consent for `(agent_id, action)`.
     ///
-    /// Calling `revoke` on a pair with no existing consent is a no-op.
+    /// Calling `revoke` on a pair with no existing consent is a no-op. Unlike
+    /// [`record`](Self::record), `revoke` clears any expiry or purpose the
+    /// grant carried.
     ///
     /// # Examples
     ///
@@ -128,16 +462,31 @@ impl<S: Storage> ConsentManager<S> {
     ///     consent::ConsentManager,
     ///     storage::InMemoryStorage,
     ///     config::Config,
+    ///     types::{Context, TrustLevel},
     /// };
     ///
     /// let mut manager = ConsentManager::new(Config::default(), InMemoryStorage::new());
-    /// manager.record("agent-001", "send_email");
+    /// manager.record("agent-001", "send_email", None, None);
     /// manager.revoke("agent-001", "send_email");
     ///
-    /// assert!(!manager.check("agent-001", "send_email").permitted);
+    /// let ctx = Context {
+    ///     agent_id: "agent-001".into(),
+    ///     scope: "default".into(),
+    ///     required_trust: TrustLevel::Observer,
+    ///     cost: None,
+    ///     category: "default".into(),
+    ///     data_type: Some("send_email".into()),
+    ///     purpose: None,
+    /// };
+    /// assert!(!manager.check("agent-001", "send_email", &ctx, 0).permitted);
     /// ```
     pub fn revoke(&mut self, agent_id: &str, action: &str) {
-        self.storage.set_consent(agent_id, action, false);
+        let grant = ConsentGrant {
+            granted: false,
+            expiry_ms: None,
+            purpose: None,
+        };
+        self.storage.set_consent(agent_id, action, grant);
     }
 
     /// Borrow the underlying storage.
@@ -145,3 +494,140 @@ impl<S: Storage> ConsentManager<S> {
         &self.storage
     }
 }
+
+// ---------------------------------------------------------------------------
+// Policies
+// ---------------------------------------------------------------------------
+
+/// Outcome of a [`ConsentPolicy`] evaluation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum PolicyVerdict {
+    /// The policy permits the action; no further policies are consulted.
+    Allow,
+    /// The policy denies the action, with a human-readable reason; no
+    /// further policies are consulted and the stored-grant lookup is
+    /// skipped entirely.
+    Deny(String),
+    /// The policy has no opinion on this `(agent_id, action, ctx)`; the
+    /// next policy (or, if none remain, the stored-grant lookup) decides.
+    NotApplicable,
+}
+
+/// A sandboxed module that [`ConsentManager::check`] consults before falling
+/// back to the stored consent grant/delegation lookup.
+///
+/// Implementations must be pure with respect to their inputs — `check` may
+/// invoke a policy any number of times for the same request and expects the
+/// same [`PolicyVerdict`] back each time.
+pub trait ConsentPolicy: Send + Sync {
+    /// Evaluate `(agent_id, action)` against the full request context.
+    fn evaluate(&self, agent_id: &str, action: &str, ctx: &Context) -> PolicyVerdict;
+}
+
+/// A single declarative rule evaluated by [`DeclarativePolicy`].
+///
+/// `action` must match exactly; `purpose`, `scope`, and `data_type`, when
+/// present, must each match the corresponding [`Context`] field exactly.
+/// `None` on a predicate means "don't care" — it matches any value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConsentRule {
+    /// The action this rule applies to.
+    pub action: String,
+    /// If set, the request's `purpose` must match this exactly.
+    pub purpose: Option<String>,
+    /// If set, the request's `scope` must match this exactly.
+    pub scope: Option<String>,
+    /// If set, the request's `data_type` must match this exactly.
+    pub data_type: Option<String>,
+    /// The verdict to return when this rule matches.
+    pub verdict: PolicyVerdict,
+}
+
+impl ConsentRule {
+    fn matches(&self, action: &str, ctx: &Context) -> bool {
+        self.action == action
+            && self
+                .purpose
+                .as_deref()
+                .is_none_or(|purpose| ctx.purpose.as_deref() == Some(purpose))
+            && self
+                .scope
+                .as_deref()
+                .is_none_or(|scope| ctx.scope == scope)
+            && self
+                .data_type
+                .as_deref()
+                .is_none_or(|data_type| ctx.data_type.as_deref() == Some(data_type))
+    }
+}
+
+/// A built-in [`ConsentPolicy`] that evaluates an ordered list of
+/// declarative [`ConsentRule`]s, so conditional consent ("allow `read_pii`
+/// only for purpose `support` in scope `eu`") can be expressed as data and
+/// changed without recompiling.
+///
+/// The first matching rule's [`PolicyVerdict`] is returned; if no rule
+/// matches, evaluation returns [`PolicyVerdict::NotApplicable`] so the next
+/// policy (or the stored-grant lookup) decides. Construct one from JSON at
+/// the application layer — e.g. `aumos-governance-wasm`'s
+/// `load_consent_policy`, which keeps this core crate `no_std` by doing the
+/// deserialisation downstream.
+///
+/// # Examples
+///
+/// ```rust
+/// use aumos_governance_core::{
+///     consent::{ConsentManager, ConsentRule, DeclarativePolicy, PolicyVerdict},
+///     storage::InMemoryStorage,
+///     config::Config,
+///     types::{Context, TrustLevel},
+/// };
+///
+/// let mut manager = ConsentManager::new(Config::default(), InMemoryStorage::new());
+/// manager.add_policy(DeclarativePolicy::new(vec![ConsentRule {
+///     action: "read_pii".into(),
+///     purpose: Some("support".into()),
+///     scope: Some("eu".into()),
+///     data_type: None,
+///     verdict: PolicyVerdict::Allow,
+/// }]));
+///
+/// let ctx = Context {
+///     agent_id: "agent-001".into(),
+///     scope: "eu".into(),
+///     required_trust: TrustLevel::Observer,
+///     cost: None,
+///     category: "default".into(),
+///     data_type: Some("read_pii".into()),
+///     purpose: Some("support".into()),
+/// };
+/// // No stored grant exists, but the policy allows it outright.
+/// assert!(manager.check("agent-001", "read_pii", &ctx, 0).permitted);
+///
+/// let other_scope = Context { scope: "us".into(), ..ctx };
+/// // Falls through to the stored lookup, which has nothing recorded.
+/// assert!(!manager.check("agent-001", "read_pii", &other_scope, 0).permitted);
+/// ```
+pub struct DeclarativePolicy {
+    rules: Vec<ConsentRule>,
+}
+
+impl DeclarativePolicy {
+    /// Create a [`DeclarativePolicy`] from an ordered list of rules.
+    pub fn new(rules: Vec<ConsentRule>) -> Self {
+        Self { rules }
+    }
+}
+
+impl ConsentPolicy for DeclarativePolicy {
+    fn evaluate(&self, _agent_id: &str, action: &str, ctx: &Context) -> PolicyVerdict {
+        for rule in &self.rules {
+            if rule.matches(action, ctx) {
+                return rule.verdict.clone();
+            }
+        }
+        PolicyVerdict::NotApplicable
+    }
+}
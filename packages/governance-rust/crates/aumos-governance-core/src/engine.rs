@@ -31,17 +31,26 @@
 //! Any gate failure short-circuits the remaining steps and returns a denied
 //! [`Decision`] immediately.  The audit record is always written.
 //!
+//! Step 2's debit is speculative until step 4: it runs inside a
+//! [`BudgetManager`] checkpoint, so a step 3 denial reverts it — the agent is
+//! never left charged for an action consent ultimately refused.
+//!
 //! There is no cross-protocol optimisation, no parallel evaluation, and no
 //! conditional gate skipping.
 
+use alloc::boxed::Box;
+use alloc::sync::Arc;
 use alloc::string::String;
 use alloc::vec::Vec;
 
 use crate::audit::AuditLogger;
 use crate::budget::BudgetManager;
+use crate::clock::Clock;
 use crate::config::Config;
 use crate::consent::ConsentManager;
+use crate::policy::{GovernanceInstruction, PolicyChange, PolicySet, PolicySnapshot};
 use crate::storage::Storage;
+use crate::telemetry::{NoopTelemetry, Telemetry};
 use crate::trust::TrustManager;
 use crate::types::{
     AuditFilter, AuditRecord, BudgetResult, ConsentResult, Context, Decision, TrustResult,
@@ -116,6 +125,23 @@ pub struct GovernanceEngine<S: Storage> {
     pub consent: ConsentManager<S>,
     /// Immutable audit chain.
     pub audit: AuditLogger<S>,
+    /// Tracing/metrics hooks for [`check`](Self::check) and each gate it
+    /// runs. Defaults to [`NoopTelemetry`]; install a real implementation
+    /// with [`with_telemetry`](Self::with_telemetry).
+    pub telemetry: Box<dyn Telemetry>,
+    /// Time source for [`check`](Self::check)'s `timestamp_ms` stamp.
+    /// Defaults to [`SystemClock`](crate::clock::SystemClock) (a bare `0`
+    /// under pure `no_std`); install a [`ManualClock`](crate::clock::ManualClock)
+    /// with [`with_clock`](Self::with_clock) to pin time in tests. Installing
+    /// one here also installs it on `trust`, `budget`, and `audit`, so every
+    /// manager agrees on the current time.
+    pub clock: Arc<dyn Clock + Send + Sync>,
+    /// Versioned policy snapshot stamped onto every [`Decision`] this engine
+    /// produces via [`check`](Self::check). Defaults to an empty
+    /// [`PolicySnapshot`] at epoch `0`; mutate it only through
+    /// [`apply_policy_instruction`](Self::apply_policy_instruction) so
+    /// every change bumps the epoch and is audited.
+    pub policy: PolicySet,
 }
 
 impl<S: Storage + Clone> GovernanceEngine<S> {
@@ -134,11 +160,15 @@ impl<S: Storage + Clone> GovernanceEngine<S> {
     /// storage wrapper such as `Arc<Mutex<S>>` and implement `Clone` on the
     /// wrapper to share the inner store.
     pub fn new(config: Config, storage: S) -> Self {
+        let clock: Arc<dyn Clock + Send + Sync> = crate::clock::default_clock();
         Self {
-            trust:   TrustManager::new(config.clone(), storage.clone()),
-            budget:  BudgetManager::new(config.clone(), storage.clone()),
-            consent: ConsentManager::new(config.clone(), storage.clone()),
-            audit:   AuditLogger::new(storage),
+            trust:     TrustManager::new(config.clone(), storage.clone()).with_clock(clock.clone()),
+            budget:    BudgetManager::new(config.clone(), storage.clone()).with_clock(clock.clone()),
+            consent:   ConsentManager::new(config.clone(), storage.clone()),
+            audit:     AuditLogger::new(storage).with_clock(clock.clone()),
+            telemetry: Box::new(NoopTelemetry),
+            clock,
+            policy: PolicySet::new(PolicySnapshot::default()),
         }
     }
 }
@@ -180,7 +210,52 @@ impl<S: Storage> GovernanceEngine<S> {
         consent: ConsentManager<S>,
         audit: AuditLogger<S>,
     ) -> Self {
-        Self { trust, budget, consent, audit }
+        Self {
+            trust,
+            budget,
+            consent,
+            audit,
+            telemetry: Box::new(NoopTelemetry),
+            clock: crate::clock::default_clock(),
+            policy: PolicySet::new(PolicySnapshot::default()),
+        }
+    }
+
+    /// Install `telemetry` as this engine's tracing/metrics hook, replacing
+    /// the default [`NoopTelemetry`].
+    pub fn with_telemetry(mut self, telemetry: Box<dyn Telemetry>) -> Self {
+        self.telemetry = telemetry;
+        self
+    }
+
+    /// Install `clock` as this engine's time source, replacing the default —
+    /// and propagate it to `trust`, `budget`, and `audit` too, so every
+    /// manager reads the same injected time rather than just the engine's
+    /// own `timestamp_ms` stamp.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.trust = self.trust.with_clock(clock.clone());
+        self.budget = self.budget.with_clock(clock.clone());
+        self.audit = self.audit.with_clock(clock.clone());
+        self.clock = clock;
+        self
+    }
+
+    /// Install `policy` as this engine's versioned policy, replacing the
+    /// default empty snapshot at epoch `0`.
+    pub fn with_policy(mut self, policy: PolicySet) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Apply `instruction` to [`policy`](Self#structfield.policy) atomically,
+    /// and audit the change itself via
+    /// [`AuditLogger::log_policy_change`](crate::audit::AuditLogger::log_policy_change)
+    /// so a policy upgrade is as auditable as any governed action. Returns
+    /// the resulting [`PolicyChange`].
+    pub fn apply_policy_instruction(&mut self, instruction: GovernanceInstruction) -> PolicyChange {
+        let change = self.policy.apply(instruction);
+        self.audit.log_policy_change(&change, self.clock.now_ms());
+        change
     }
 
     /// Evaluate a governance action and return a [`Decision`].
@@ -189,9 +264,10 @@ impl<S: Storage> GovernanceEngine<S> {
     ///
     /// 1. Trust gate — fails if the agent's level is below `ctx.required_trust`.
     /// 2. Budget gate — fails if `ctx.cost` is `Some` and the envelope has
-    ///    insufficient headroom.  When permitted, the envelope is debited.
+    ///    insufficient headroom.  When permitted, the envelope is debited
+    ///    under a checkpoint that a later gate's denial can revert.
     /// 3. Consent gate — fails if `ctx.data_type` is `Some` and no active
-    ///    consent exists.
+    ///    consent exists; on failure, step 2's debit is reverted.
     /// 4. Audit — always appended, regardless of outcome.
     ///
     /// # Examples
@@ -222,13 +298,20 @@ impl<S: Storage> GovernanceEngine<S> {
     /// assert!(decision.reason.contains("Trust"));
     /// ```
     pub fn check(&mut self, action: &str, ctx: &Context) -> Decision {
-        let timestamp_ms = current_time_ms();
+        let timestamp_ms = self.clock.now_ms();
+        let policy_stamp = self.policy.stamp();
 
         // ------------------------------------------------------------------
         // Step 1: Trust gate
         // ------------------------------------------------------------------
         let trust_result: TrustResult =
             self.trust.check_level(&ctx.agent_id, &ctx.scope, ctx.required_trust);
+        self.telemetry.on_gate(
+            "trust",
+            trust_result.permitted,
+            &trust_result.reason,
+            self.clock.now_ms().saturating_sub(timestamp_ms),
+        );
 
         if !trust_result.permitted {
             let decision = Decision {
@@ -237,40 +320,99 @@ impl<S: Storage> GovernanceEngine<S> {
                 budget: skipped_budget_result(&ctx.category),
                 consent: skipped_consent_result(),
                 action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
                 timestamp_ms,
                 reason: "Trust gate denied.".into(),
+                policy_epoch: policy_stamp.epoch,
+                policy_hash: policy_stamp.hash.clone(),
             };
             self.audit.log(decision.clone());
+            self.emit_decision_telemetry(&decision, timestamp_ms);
             return decision;
         }
 
         // ------------------------------------------------------------------
         // Step 2: Budget gate (only when the action carries a positive cost)
         // ------------------------------------------------------------------
+        //
+        // A checkpoint is opened before the debit so that a later gate
+        // denying the action (consent, step 3) can undo the debit exactly —
+        // the audit record still reports this step's `BudgetResult` (the
+        // *attempted* spend) either way, since reverting only restores the
+        // envelope's stored state, not the already-built `budget_result`.
+        let budget_checkpoint = self.budget.checkpoint();
         let budget_result: BudgetResult = match ctx.cost {
             Some(amount) if amount > 0.0 => {
-                let result = self.budget.check(&ctx.category, amount);
+                let result = self.budget.check(&ctx.category, amount, timestamp_ms);
                 if result.permitted {
                     // Debit the envelope so subsequent checks within the same
-                    // period see the correct remaining headroom.
-                    self.budget.record(&ctx.category, amount);
+                    // period see the correct remaining headroom. `record`
+                    // re-validates headroom itself, so a race against another
+                    // writer since `check` surfaces here rather than silently
+                    // overspending.
+                    if let Err(error) = self.budget.record(&ctx.category, amount, timestamp_ms) {
+                        // Nothing was debited, so there's nothing to revert —
+                        // the checkpoint only needs closing.
+                        self.budget.discard(budget_checkpoint);
+                        let decision = Decision {
+                            permitted: false,
+                            trust: trust_result,
+                            budget: BudgetResult {
+                                permitted: false,
+                                available: result.available,
+                                requested: amount,
+                                category: ctx.category.clone(),
+                                reason: format!("{}", error),
+                                dimension: None,
+                            },
+                            consent: skipped_consent_result(),
+                            action: action.into(),
+                            agent_id: ctx.agent_id.clone(),
+                            scope: ctx.scope.clone(),
+                            timestamp_ms,
+                            reason: "Budget gate denied.".into(),
+                            policy_epoch: policy_stamp.epoch,
+                            policy_hash: policy_stamp.hash.clone(),
+                        };
+                        self.audit.log(decision.clone());
+                        self.emit_decision_telemetry(&decision, timestamp_ms);
+                        return decision;
+                    }
                 }
                 result
             }
             _ => skipped_budget_result(&ctx.category),
         };
+        if matches!(ctx.cost, Some(amount) if amount > 0.0) {
+            self.telemetry.on_gate(
+                "budget",
+                budget_result.permitted,
+                &budget_result.reason,
+                self.clock.now_ms().saturating_sub(timestamp_ms),
+            );
+            self.telemetry.on_budget_remaining(&budget_result.category, budget_result.available);
+        }
 
         if !budget_result.permitted {
+            // The check itself failed, so `record` was never called — again,
+            // nothing to revert.
+            self.budget.discard(budget_checkpoint);
             let decision = Decision {
                 permitted: false,
                 trust: trust_result,
                 budget: budget_result,
                 consent: skipped_consent_result(),
                 action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
                 timestamp_ms,
                 reason: "Budget gate denied.".into(),
+                policy_epoch: policy_stamp.epoch,
+                policy_hash: policy_stamp.hash.clone(),
             };
             self.audit.log(decision.clone());
+            self.emit_decision_telemetry(&decision, timestamp_ms);
             return decision;
         }
 
@@ -278,38 +420,61 @@ impl<S: Storage> GovernanceEngine<S> {
         // Step 3: Consent gate (only when a data type is specified)
         // ------------------------------------------------------------------
         let consent_result: ConsentResult = match &ctx.data_type {
-            Some(data_type) => self.consent.check(&ctx.agent_id, data_type),
+            Some(data_type) => self.consent.check(&ctx.agent_id, data_type, ctx, timestamp_ms),
             None => skipped_consent_result(),
         };
+        if ctx.data_type.is_some() {
+            self.telemetry.on_gate(
+                "consent",
+                consent_result.permitted,
+                &consent_result.reason,
+                self.clock.now_ms().saturating_sub(timestamp_ms),
+            );
+        }
 
         if !consent_result.permitted {
+            // Consent denied after the budget was already debited in step 2
+            // — restore the envelope to its pre-debit state so the agent
+            // isn't charged for an action that was ultimately refused.
+            self.budget.revert_to(budget_checkpoint);
             let decision = Decision {
                 permitted: false,
                 trust: trust_result,
                 budget: budget_result,
                 consent: consent_result,
                 action: action.into(),
+                agent_id: ctx.agent_id.clone(),
+                scope: ctx.scope.clone(),
                 timestamp_ms,
                 reason: "Consent gate denied.".into(),
+                policy_epoch: policy_stamp.epoch,
+                policy_hash: policy_stamp.hash.clone(),
             };
             self.audit.log(decision.clone());
+            self.emit_decision_telemetry(&decision, timestamp_ms);
             return decision;
         }
 
         // ------------------------------------------------------------------
-        // Step 4: All gates passed — permit
+        // Step 4: All gates passed — commit the budget debit and permit
         // ------------------------------------------------------------------
+        self.budget.discard(budget_checkpoint);
         let decision = Decision {
             permitted: true,
             trust: trust_result,
             budget: budget_result,
             consent: consent_result,
             action: action.into(),
+            agent_id: ctx.agent_id.clone(),
+            scope: ctx.scope.clone(),
             timestamp_ms,
             reason: "All governance gates passed.".into(),
+            policy_epoch: policy_stamp.epoch,
+            policy_hash: policy_stamp.hash.clone(),
         };
 
         self.audit.log(decision.clone());
+        self.emit_decision_telemetry(&decision, timestamp_ms);
         decision
     }
 
@@ -319,6 +484,19 @@ impl<S: Storage> GovernanceEngine<S> {
     pub fn query_audit(&self, filter: &AuditFilter) -> Vec<AuditRecord> {
         self.audit.query(filter)
     }
+
+    /// Emit the end-to-end [`Telemetry::on_decision`] call for a finished
+    /// `decision`, using `started_ms` (the `check()` entry timestamp) to
+    /// compute total elapsed time.
+    fn emit_decision_telemetry(&self, decision: &Decision, started_ms: u64) {
+        self.telemetry.on_decision(
+            &decision.agent_id,
+            &decision.scope,
+            &decision.action,
+            decision.permitted,
+            self.clock.now_ms().saturating_sub(started_ms),
+        );
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -326,18 +504,19 @@ impl<S: Storage> GovernanceEngine<S> {
 // ---------------------------------------------------------------------------
 
 /// Build a skipped-gate [`BudgetResult`] for actions without a cost.
-fn skipped_budget_result(category: &str) -> BudgetResult {
+pub(crate) fn skipped_budget_result(category: &str) -> BudgetResult {
     BudgetResult {
         permitted: true,
         available: f64::MAX,
         requested: 0.0,
         category: category.into(),
         reason: "Budget gate skipped (no cost specified).".into(),
+        dimension: None,
     }
 }
 
 /// Build a skipped-gate [`ConsentResult`] for actions without a data type.
-fn skipped_consent_result() -> ConsentResult {
+pub(crate) fn skipped_consent_result() -> ConsentResult {
     ConsentResult {
         permitted: true,
         reason: "Consent gate skipped (no data type specified).".into(),
@@ -345,7 +524,7 @@ fn skipped_consent_result() -> ConsentResult {
 }
 
 /// Return current Unix epoch milliseconds.
-fn current_time_ms() -> u64 {
+pub(crate) fn current_time_ms() -> u64 {
     #[cfg(feature = "std")]
     {
         use std::time::{SystemTime, UNIX_EPOCH};
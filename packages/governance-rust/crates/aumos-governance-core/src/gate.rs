@@ -0,0 +1,447 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! A pluggable, reorderable evaluation pipeline.
+//!
+//! [`GovernanceEngine`](crate::engine::GovernanceEngine) hard-codes its
+//! trust→budget→consent sequence — that's the right default, and most
+//! callers never need anything else. [`GatePipeline`] is for the callers who
+//! do: it replaces the four fixed managers with an ordered
+//! `Vec<Box<dyn Gate>>`, so gates can be reordered, dropped, or extended with
+//! custom checks (rate limiting, geofencing, time-of-day windows) that have
+//! no typed `TrustResult`/`BudgetResult`/`ConsentResult` of their own.
+//!
+//! [`GatePipeline::from_engine`] decomposes an existing
+//! [`GovernanceEngine`](crate::engine::GovernanceEngine) into the three
+//! built-in gates ([`TrustGate`], [`BudgetGate`], [`ConsentGate`]) in the
+//! engine's default order, so today's default ordering is always one call
+//! away — `GovernanceEngine::new` itself is untouched and keeps working
+//! exactly as before.
+//!
+//! ## Evaluation
+//!
+//! [`GatePipeline::check`] runs each gate's [`Gate::evaluate`] in order and
+//! short-circuits on the first denial, exactly like
+//! [`GovernanceEngine::check`](crate::engine::GovernanceEngine::check). A
+//! gate that holds state open pending the rest of the pipeline (the way
+//! [`BudgetGate`] holds a debit open pending the consent gate) implements
+//! [`Gate::rollback`] to undo it on a later denial, and [`Gate::commit`] to
+//! confirm it once every gate has passed — mirroring the checkpoint
+//! discipline in [`BudgetManager`](crate::budget::BudgetManager). The audit
+//! record is always written, regardless of outcome.
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::audit::AuditLogger;
+use crate::budget::{BudgetManager, CheckpointId};
+use crate::consent::ConsentManager;
+use crate::engine::{current_time_ms, skipped_budget_result, skipped_consent_result, GovernanceEngine};
+use crate::storage::Storage;
+use crate::trust::TrustManager;
+use crate::types::{BudgetResult, ConsentResult, Context, Decision, TrustResult};
+
+// ---------------------------------------------------------------------------
+// Gate trait
+// ---------------------------------------------------------------------------
+
+/// One evaluation step in a [`GatePipeline`].
+///
+/// Implementations are expected to own whatever state they need (their own
+/// manager, their own storage handle) — the trait itself carries no `S`
+/// parameter, so gates backed by entirely different storage, or no storage
+/// at all (e.g. a pure time-of-day check), can sit in the same pipeline.
+pub trait Gate: Send + Sync {
+    /// A short, stable identifier for this gate (e.g. `"trust"`), used in
+    /// denial reasons and nowhere else — it is not parsed.
+    fn name(&self) -> &str;
+
+    /// Evaluate `action` for `ctx` and return whether this gate permits it.
+    fn evaluate(&mut self, action: &str, ctx: &Context) -> GateOutcome;
+
+    /// Confirm whatever state this gate's last [`evaluate`](Self::evaluate)
+    /// call held open, now that every gate in the pipeline has passed. The
+    /// default is a no-op, for gates whose `evaluate` has no side effect to
+    /// confirm.
+    fn commit(&mut self) {}
+
+    /// Undo whatever state this gate's last [`evaluate`](Self::evaluate)
+    /// call held open, because a later gate denied the action. The default
+    /// is a no-op, for gates whose `evaluate` is a pure read.
+    fn rollback(&mut self) {}
+}
+
+/// The result of one [`Gate::evaluate`] call.
+///
+/// Carries a human-readable `reason` plus, for the built-in gates, a typed
+/// [`GateDetail`] so [`GatePipeline::check`] can still populate a
+/// [`Decision`]'s legacy `trust`/`budget`/`consent` fields. Custom gates
+/// that have no such typed result simply leave `detail` at
+/// [`GateDetail::None`].
+#[derive(Debug, Clone)]
+pub enum GateOutcome {
+    /// The gate permits the action.
+    Permit {
+        /// Why the gate permitted it.
+        reason: String,
+        /// Typed detail for a built-in gate, or `None` for a custom one.
+        detail: GateDetail,
+    },
+    /// The gate denies the action.
+    Deny {
+        /// Why the gate denied it.
+        reason: String,
+        /// Typed detail for a built-in gate, or `None` for a custom one.
+        detail: GateDetail,
+    },
+}
+
+impl GateOutcome {
+    /// Build a [`GateOutcome::Permit`] with no typed detail.
+    pub fn permit(reason: impl Into<String>) -> Self {
+        GateOutcome::Permit { reason: reason.into(), detail: GateDetail::None }
+    }
+
+    /// Build a [`GateOutcome::Deny`] with no typed detail.
+    pub fn deny(reason: impl Into<String>) -> Self {
+        GateOutcome::Deny { reason: reason.into(), detail: GateDetail::None }
+    }
+
+    /// Attach `detail` to this outcome, replacing whatever was there.
+    pub fn with_detail(self, detail: GateDetail) -> Self {
+        match self {
+            GateOutcome::Permit { reason, .. } => GateOutcome::Permit { reason, detail },
+            GateOutcome::Deny { reason, .. } => GateOutcome::Deny { reason, detail },
+        }
+    }
+
+    /// `true` for [`GateOutcome::Permit`].
+    pub fn permitted(&self) -> bool {
+        matches!(self, GateOutcome::Permit { .. })
+    }
+
+    /// The outcome's reason string, regardless of permit/deny.
+    pub fn reason(&self) -> &str {
+        match self {
+            GateOutcome::Permit { reason, .. } | GateOutcome::Deny { reason, .. } => reason,
+        }
+    }
+
+    /// The outcome's typed detail, regardless of permit/deny.
+    pub fn detail(&self) -> &GateDetail {
+        match self {
+            GateOutcome::Permit { detail, .. } | GateOutcome::Deny { detail, .. } => detail,
+        }
+    }
+}
+
+/// Typed detail a built-in [`Gate`] attaches to its [`GateOutcome`], so
+/// [`GatePipeline::check`] can fold it into a [`Decision`]'s legacy fields.
+#[derive(Debug, Clone)]
+pub enum GateDetail {
+    /// No typed detail — the common case for custom gates.
+    None,
+    /// Attached by [`TrustGate`].
+    Trust(TrustResult),
+    /// Attached by [`BudgetGate`].
+    Budget(BudgetResult),
+    /// Attached by [`ConsentGate`].
+    Consent(ConsentResult),
+}
+
+// ---------------------------------------------------------------------------
+// Built-in gates
+// ---------------------------------------------------------------------------
+
+/// [`Gate`] wrapper around a [`TrustManager`] — always evaluated, since
+/// [`Context::required_trust`](crate::types::Context::required_trust) is
+/// never optional. A pure read: `commit`/`rollback` are both no-ops.
+pub struct TrustGate<S: Storage> {
+    manager: TrustManager<S>,
+}
+
+impl<S: Storage> TrustGate<S> {
+    /// Wrap an existing [`TrustManager`] as a gate.
+    pub fn new(manager: TrustManager<S>) -> Self {
+        Self { manager }
+    }
+}
+
+impl<S: Storage> Gate for TrustGate<S> {
+    fn name(&self) -> &str {
+        "trust"
+    }
+
+    fn evaluate(&mut self, _action: &str, ctx: &Context) -> GateOutcome {
+        let result = self.manager.check_level(&ctx.agent_id, &ctx.scope, ctx.required_trust);
+        let outcome = if result.permitted {
+            GateOutcome::permit(result.reason.clone())
+        } else {
+            GateOutcome::deny(result.reason.clone())
+        };
+        outcome.with_detail(GateDetail::Trust(result))
+    }
+}
+
+/// [`Gate`] wrapper around a [`BudgetManager`] — skipped (auto-permits) when
+/// `ctx.cost` is `None`. A permitted debit is held open under a
+/// [`BudgetManager`] checkpoint until [`commit`](Gate::commit) confirms it or
+/// [`rollback`](Gate::rollback) reverts it, exactly like the checkpoint
+/// discipline in [`GovernanceEngine::check`](crate::engine::GovernanceEngine::check).
+pub struct BudgetGate<S: Storage> {
+    manager: BudgetManager<S>,
+    checkpoint: Option<CheckpointId>,
+}
+
+impl<S: Storage> BudgetGate<S> {
+    /// Wrap an existing [`BudgetManager`] as a gate.
+    pub fn new(manager: BudgetManager<S>) -> Self {
+        Self { manager, checkpoint: None }
+    }
+}
+
+impl<S: Storage> Gate for BudgetGate<S> {
+    fn name(&self) -> &str {
+        "budget"
+    }
+
+    fn evaluate(&mut self, _action: &str, ctx: &Context) -> GateOutcome {
+        let amount = match ctx.cost {
+            Some(amount) if amount > 0.0 => amount,
+            _ => return GateOutcome::permit("Budget gate skipped (no cost specified).")
+                .with_detail(GateDetail::Budget(skipped_budget_result(&ctx.category))),
+        };
+
+        let now_ms = self.manager.now_ms();
+        let checkpoint = self.manager.checkpoint();
+        let result = self.manager.check(&ctx.category, amount, now_ms);
+
+        if !result.permitted {
+            self.manager.discard(checkpoint);
+            return GateOutcome::deny(result.reason.clone()).with_detail(GateDetail::Budget(result));
+        }
+
+        if let Err(error) = self.manager.record(&ctx.category, amount, now_ms) {
+            self.manager.discard(checkpoint);
+            let denied = BudgetResult {
+                permitted: false,
+                available: result.available,
+                requested: amount,
+                category: ctx.category.clone(),
+                reason: format!("{}", error),
+                dimension: None,
+            };
+            return GateOutcome::deny(denied.reason.clone()).with_detail(GateDetail::Budget(denied));
+        }
+
+        self.checkpoint = Some(checkpoint);
+        GateOutcome::permit(result.reason.clone()).with_detail(GateDetail::Budget(result))
+    }
+
+    fn commit(&mut self) {
+        if let Some(checkpoint) = self.checkpoint.take() {
+            self.manager.discard(checkpoint);
+        }
+    }
+
+    fn rollback(&mut self) {
+        if let Some(checkpoint) = self.checkpoint.take() {
+            self.manager.revert_to(checkpoint);
+        }
+    }
+}
+
+/// [`Gate`] wrapper around a [`ConsentManager`] — skipped (auto-permits)
+/// when `ctx.data_type` is `None`. A pure read: `commit`/`rollback` are both
+/// no-ops.
+pub struct ConsentGate<S: Storage> {
+    manager: ConsentManager<S>,
+}
+
+impl<S: Storage> ConsentGate<S> {
+    /// Wrap an existing [`ConsentManager`] as a gate.
+    pub fn new(manager: ConsentManager<S>) -> Self {
+        Self { manager }
+    }
+}
+
+impl<S: Storage> Gate for ConsentGate<S> {
+    fn name(&self) -> &str {
+        "consent"
+    }
+
+    fn evaluate(&mut self, _action: &str, ctx: &Context) -> GateOutcome {
+        let data_type = match &ctx.data_type {
+            Some(data_type) => data_type,
+            None => {
+                return GateOutcome::permit("Consent gate skipped (no data type specified).")
+                    .with_detail(GateDetail::Consent(skipped_consent_result()))
+            }
+        };
+
+        let now_ms = current_time_ms();
+        let result = self.manager.check(&ctx.agent_id, data_type, ctx, now_ms);
+        let outcome = if result.permitted {
+            GateOutcome::permit(result.reason.clone())
+        } else {
+            GateOutcome::deny(result.reason.clone())
+        };
+        outcome.with_detail(GateDetail::Consent(result))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// GatePipeline
+// ---------------------------------------------------------------------------
+
+/// An ordered, reorderable sequence of [`Gate`]s plus an audit log.
+///
+/// # Examples
+///
+/// ```rust
+/// use aumos_governance_core::{
+///     engine::GovernanceEngine,
+///     gate::GatePipeline,
+///     storage::InMemoryStorage,
+///     types::{Context, TrustLevel},
+///     config::Config,
+/// };
+///
+/// let mut engine = GovernanceEngine::new(Config::default(), InMemoryStorage::new());
+/// engine.trust.set_level("agent-001", "default", TrustLevel::Suggest, "owner");
+///
+/// // Start from today's default trust→budget→consent order, then drop the
+/// // consent gate since this deployment doesn't use it.
+/// let mut pipeline = GatePipeline::from_engine(engine);
+/// pipeline.gates_mut().pop();
+///
+/// let ctx = Context {
+///     agent_id:       "agent-001".into(),
+///     scope:          "default".into(),
+///     required_trust: TrustLevel::Suggest,
+///     cost:           None,
+///     category:       "default".into(),
+///     data_type:      None,
+///     purpose:        None,
+/// };
+/// let decision = pipeline.check("send_report", &ctx);
+/// assert!(decision.permitted);
+/// ```
+pub struct GatePipeline<S: Storage> {
+    gates: Vec<Box<dyn Gate>>,
+    audit: AuditLogger<S>,
+}
+
+impl<S: Storage + 'static> GatePipeline<S> {
+    /// Build a pipeline from an explicit gate order and audit log.
+    pub fn new(gates: Vec<Box<dyn Gate>>, audit: AuditLogger<S>) -> Self {
+        Self { gates, audit }
+    }
+
+    /// Decompose `engine` into a [`GatePipeline`] in its default
+    /// trust→budget→consent order, reusing its audit log unchanged.
+    pub fn from_engine(engine: GovernanceEngine<S>) -> Self {
+        let GovernanceEngine { trust, budget, consent, audit, .. } = engine;
+        let gates: Vec<Box<dyn Gate>> = alloc::vec![
+            Box::new(TrustGate::new(trust)),
+            Box::new(BudgetGate::new(budget)),
+            Box::new(ConsentGate::new(consent)),
+        ];
+        Self { gates, audit }
+    }
+
+    /// Mutable access to the gate order, so callers can reorder, drop, or
+    /// append (e.g. `pipeline.gates_mut().push(Box::new(MyRateLimitGate))`)
+    /// gates between evaluations.
+    pub fn gates_mut(&mut self) -> &mut Vec<Box<dyn Gate>> {
+        &mut self.gates
+    }
+
+    /// Evaluate `action` for `ctx` through every gate in order, stopping at
+    /// the first denial. Gates already evaluated at that point have
+    /// [`Gate::rollback`] called on them (most recent first is not required —
+    /// each gate only needs to undo its own state); when every gate passes,
+    /// [`Gate::commit`] is called on all of them instead. The audit record is
+    /// always written.
+    pub fn check(&mut self, action: &str, ctx: &Context) -> Decision {
+        let timestamp_ms = self.audit.now_ms();
+
+        let mut trust_result = None;
+        let mut budget_result = None;
+        let mut consent_result = None;
+        let mut denial: Option<(usize, GateOutcome)> = None;
+
+        let total = self.gates.len();
+        let mut evaluated = 0;
+        for (index, gate) in self.gates.iter_mut().enumerate() {
+            let outcome = gate.evaluate(action, ctx);
+            evaluated = index + 1;
+
+            match outcome.detail() {
+                GateDetail::Trust(result) => trust_result = Some(result.clone()),
+                GateDetail::Budget(result) => budget_result = Some(result.clone()),
+                GateDetail::Consent(result) => consent_result = Some(result.clone()),
+                GateDetail::None => {}
+            }
+
+            if !outcome.permitted() {
+                denial = Some((index, outcome));
+                break;
+            }
+        }
+
+        let reason = match &denial {
+            Some((index, outcome)) => {
+                for gate in &mut self.gates[..*index] {
+                    gate.rollback();
+                }
+                format!("Gate '{}' denied: {}", self.gates[*index].name(), outcome.reason())
+            }
+            None => {
+                for gate in &mut self.gates[..evaluated.min(total)] {
+                    gate.commit();
+                }
+                "All gates passed.".into()
+            }
+        };
+
+        let decision = Decision {
+            permitted: denial.is_none(),
+            trust: trust_result.unwrap_or_else(skipped_trust_result),
+            budget: budget_result.unwrap_or_else(|| skipped_budget_result(&ctx.category)),
+            consent: consent_result.unwrap_or_else(skipped_consent_result),
+            action: action.into(),
+            agent_id: ctx.agent_id.clone(),
+            scope: ctx.scope.clone(),
+            timestamp_ms,
+            reason,
+            // `GatePipeline` has no `PolicySet` of its own (only
+            // `GovernanceEngine` does) — left at the "no policy wired in"
+            // default documented on `Decision::policy_epoch`.
+            policy_epoch: 0,
+            policy_hash: String::new(),
+        };
+
+        self.audit.log(decision.clone());
+        decision
+    }
+
+    /// Query the audit log directly.
+    pub fn query_audit(&self, filter: &crate::types::AuditFilter) -> Vec<crate::types::AuditRecord> {
+        self.audit.query(filter)
+    }
+}
+
+/// Fallback [`TrustResult`] for a pipeline with no [`TrustGate`] installed —
+/// `GovernanceEngine` never needs this (its trust gate is unconditional),
+/// but a caller-built `GatePipeline` may have dropped it.
+fn skipped_trust_result() -> TrustResult {
+    TrustResult {
+        permitted: true,
+        current_level: crate::types::TrustLevel::Autonomous,
+        required_level: crate::types::TrustLevel::Autonomous,
+        reason: "Trust gate not present in pipeline.".into(),
+    }
+}
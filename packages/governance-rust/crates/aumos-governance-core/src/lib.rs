@@ -15,7 +15,7 @@
 //! GovernanceEngine<S: Storage>
 //!   ├── TrustManager<S>    — assign / query / check agent trust levels
 //!   ├── BudgetManager<S>   — create / check / record spending envelopes
-//!   ├── ConsentManager<S>  — record / check / revoke consent grants
+//!   ├── ConsentManager<S>  — record / check / revoke / delegate consent grants
 //!   └── AuditLogger<S>     — log decisions, query audit chain
 //! ```
 //!
@@ -55,11 +55,31 @@
 extern crate alloc;
 
 pub mod audit;
+pub mod authorization;
 pub mod budget;
+pub mod clock;
+pub mod clock_policy;
 pub mod config;
 pub mod consent;
 pub mod engine;
+pub mod gate;
+pub mod metered_budget;
+pub mod policy;
+
+// PROV-JSON export — goes through `serde_json::Value`, so it is only
+// compiled when the "std" feature (on by default) is enabled, matching
+// `audit::recompute_hash`'s own std-gated use of `serde_json`.
+#[cfg(feature = "std")]
+pub mod prov;
+
+// Signed trust assignments — pulls in ed25519_dalek, a dependency the rest
+// of this crate does not otherwise need. Only compiled when the
+// "signed-trust" feature is enabled.
+#[cfg(feature = "signed-trust")]
+pub mod signed_trust;
+
 pub mod storage;
+pub mod telemetry;
 pub mod trust;
 pub mod types;
 
@@ -76,11 +96,21 @@ pub mod config_loader;
 // Re-export the most commonly used items at the crate root so consumers can
 // write `use aumos_governance_core::GovernanceEngine;` instead of the fully
 // qualified path.
+pub use clock::{Clock, ManualClock};
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+pub use clock_policy::{ClockPolicy, ClockPolicyError};
 pub use engine::GovernanceEngine;
+pub use gate::{Gate, GateDetail, GateOutcome, GatePipeline};
+#[cfg(feature = "std")]
+pub use prov::ProvDocument;
 pub use storage::{InMemoryStorage, Storage};
+pub use telemetry::{NoopTelemetry, Telemetry};
+#[cfg(feature = "signed-trust")]
+pub use signed_trust::{ApproverSet, TrustError};
 pub use types::{
     AuditFilter, AuditRecord, BudgetResult, ConsentResult, Context, Decision, Envelope,
-    TrustAssignment, TrustLevel, TrustResult,
+    TrustAssignment, TrustDelegation, TrustLevel, TrustResult, TrustSignature,
 };
 
 // Re-export the async engine at the crate root for ergonomic imports.
@@ -89,4 +119,6 @@ pub use async_engine::AsyncGovernanceEngine;
 
 // Re-export config loader types at the crate root.
 #[cfg(feature = "config-loader")]
-pub use config_loader::{AuditLevel, ConfigError, GovernanceConfig, load_config, load_config_from_env};
+pub use config_loader::{
+    AuditLevel, ConfigError, ConfigWatcher, GovernanceConfig, load_config, load_config_from_env,
+};
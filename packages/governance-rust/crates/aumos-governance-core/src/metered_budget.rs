@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! Typed, integer-unit metered budgets for actions that consume several
+//! distinct resources per call.
+//!
+//! [`BudgetManager`](crate::budget::BudgetManager)'s multi-dimensional
+//! envelopes track `f64` amounts against dimension *names* the caller makes
+//! up (`"tokens"`, `"usd"`) — flexible, but every dimension means the same
+//! thing only by convention. [`MeteredEnvelope`] instead meters against a
+//! closed, typed [`ResourceDimension`] axis in `u64` units: token counts,
+//! millisecond durations, and call counts are all naturally whole numbers,
+//! and summing `u64` spend avoids the rounding drift `f64` accumulation can
+//! introduce over a long-running envelope.
+//!
+//! [`CostModel`] is the other half: it maps a requested action to the
+//! per-dimension cost vector [`can_spend`](MeteredEnvelope::can_spend) /
+//! [`spend`](MeteredEnvelope::spend) check against, so call sites work in
+//! terms of `"generate_report"` rather than hand-building a cost map inline.
+//!
+//! This is a standalone, storage-free model — unlike [`BudgetManager`], a
+//! [`MeteredEnvelope`] is not backed by [`Storage`](crate::storage::Storage);
+//! callers own persistence themselves (e.g. storing the serialised envelope
+//! under one [`Storage::set_envelope`](crate::storage::Storage::set_envelope)
+//! category today, or a dedicated store of their own).
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+/// A typed resource axis a [`MeteredEnvelope`] can meter separately.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ResourceDimension {
+    /// Tokens consumed from the prompt/input side of an LLM call.
+    LlmInputTokens,
+    /// Tokens produced on the completion/output side of an LLM call.
+    LlmOutputTokens,
+    /// Wall-clock compute time, in milliseconds.
+    ComputeMs,
+    /// Count of external API calls made.
+    ApiCalls,
+    /// An application-defined resource axis not covered above.
+    Custom(String),
+}
+
+/// A per-[`ResourceDimension`] spending envelope, metered in integer units.
+///
+/// Mirrors [`Envelope`](crate::types::Envelope)'s period-rollover semantics:
+/// `period_ms == 0` means no automatic reset, otherwise whole elapsed
+/// periods since `starts_at_ms` reset every dimension's `spent` to `0` and
+/// advance `starts_at_ms` before the requested amount is evaluated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MeteredEnvelope {
+    /// The logical cost category this envelope tracks (e.g. `"llm-calls"`).
+    pub category: String,
+    /// Per-dimension maximum for the current period.
+    pub limits: BTreeMap<ResourceDimension, u64>,
+    /// Per-dimension amount spent so far in the current period.
+    pub spent: BTreeMap<ResourceDimension, u64>,
+    /// Period length in milliseconds (`0` disables automatic rollover).
+    pub period_ms: u64,
+    /// Unix epoch milliseconds at which the current period began.
+    pub starts_at_ms: u64,
+}
+
+impl MeteredEnvelope {
+    /// Build a new [`MeteredEnvelope`] with zero spend recorded.
+    pub fn new(
+        category: impl Into<String>,
+        limits: BTreeMap<ResourceDimension, u64>,
+        period_ms: u64,
+        starts_at_ms: u64,
+    ) -> Self {
+        Self {
+            category: category.into(),
+            limits,
+            spent: BTreeMap::new(),
+            period_ms,
+            starts_at_ms,
+        }
+    }
+
+    /// Whether `cost` fits within every dimension's remaining headroom as of
+    /// `now_ms`, after rolling over any whole elapsed periods.
+    ///
+    /// Returns the first dimension (in [`BTreeMap`] key order) that `cost`
+    /// would exhaust, or `Ok(())` if every requested dimension fits. A
+    /// dimension `cost` requests but `limits` does not track is treated as a
+    /// `0` limit — unmetered dimensions cannot be spent against.
+    pub fn can_spend(
+        &self,
+        cost: &BTreeMap<ResourceDimension, u64>,
+        now_ms: u64,
+    ) -> Result<(), ResourceDimension> {
+        let rolled = self.rolled_over(now_ms);
+        for (dimension, amount) in cost {
+            let limit = rolled.limits.get(dimension).copied().unwrap_or(0);
+            let spent = rolled.spent.get(dimension).copied().unwrap_or(0);
+            if spent.saturating_add(*amount) > limit {
+                return Err(dimension.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Remaining headroom for `dimension` as of `now_ms` (`0` if untracked).
+    pub fn available(&self, dimension: &ResourceDimension, now_ms: u64) -> u64 {
+        let rolled = self.rolled_over(now_ms);
+        let limit = rolled.limits.get(dimension).copied().unwrap_or(0);
+        let spent = rolled.spent.get(dimension).copied().unwrap_or(0);
+        limit.saturating_sub(spent)
+    }
+
+    /// Roll over any whole elapsed periods, re-validate `cost` against the
+    /// rolled-over state, and debit it in place.
+    ///
+    /// On `Err`, `self` is left rolled-over (period resets still apply) but
+    /// undebited — exactly like [`BudgetManager::record`](crate::budget::BudgetManager::record)
+    /// rejecting a spend that fails re-validation.
+    pub fn spend(
+        &mut self,
+        cost: &BTreeMap<ResourceDimension, u64>,
+        now_ms: u64,
+    ) -> Result<(), ResourceDimension> {
+        *self = self.rolled_over(now_ms);
+        self.can_spend(cost, now_ms)?;
+        for (dimension, amount) in cost {
+            *self.spent.entry(dimension.clone()).or_insert(0) += amount;
+        }
+        Ok(())
+    }
+
+    /// Return the view of this envelope as of `now_ms`, resetting every
+    /// dimension's `spent` to `0` and advancing `starts_at_ms` for each whole
+    /// period elapsed since it was last rolled over.
+    fn rolled_over(&self, now_ms: u64) -> Self {
+        if self.period_ms == 0 || now_ms < self.starts_at_ms {
+            return self.clone();
+        }
+        let elapsed_periods = (now_ms - self.starts_at_ms) / self.period_ms;
+        if elapsed_periods == 0 {
+            return self.clone();
+        }
+        Self {
+            category: self.category.clone(),
+            limits: self.limits.clone(),
+            spent: BTreeMap::new(),
+            period_ms: self.period_ms,
+            starts_at_ms: self.starts_at_ms + elapsed_periods * self.period_ms,
+        }
+    }
+}
+
+/// Maps a requested action to the per-[`ResourceDimension`] cost it incurs.
+///
+/// Implement this against your own action catalog — a [`MeteredEnvelope`]
+/// has no way to know in advance what `"generate_report"` costs.
+///
+/// # Examples
+///
+/// ```rust
+/// use aumos_governance_core::metered_budget::{CostModel, ResourceDimension};
+/// use std::collections::BTreeMap;
+///
+/// struct LlmCostModel;
+///
+/// impl CostModel for LlmCostModel {
+///     fn cost_of(&self, action: &str) -> BTreeMap<ResourceDimension, u64> {
+///         let mut cost = BTreeMap::new();
+///         if action == "summarize" {
+///             cost.insert(ResourceDimension::LlmInputTokens, 2_000);
+///             cost.insert(ResourceDimension::LlmOutputTokens, 200);
+///         }
+///         cost
+///     }
+/// }
+///
+/// let cost = LlmCostModel.cost_of("summarize");
+/// assert_eq!(cost.get(&ResourceDimension::LlmInputTokens), Some(&2_000));
+/// ```
+pub trait CostModel {
+    /// The per-[`ResourceDimension`] cost of performing `action` once.
+    fn cost_of(&self, action: &str) -> BTreeMap<ResourceDimension, u64>;
+}
@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! Versioned governance policy: trust thresholds, budget limits, and
+//! consent requirements as one content-hashed, epoch-numbered snapshot.
+//!
+//! Every [`Decision`](crate::types::Decision) and
+//! [`AuditRecord`](crate::types::AuditRecord) carries a `timestamp_ms`, but
+//! nothing ties it to *which ruleset* produced it — two decisions for the
+//! same agent a week apart could have been evaluated under entirely
+//! different trust thresholds with no way to tell from the record alone.
+//! [`PolicySet`] fixes that: its [`epoch`](PolicySet::epoch) and content
+//! [`hash`](PolicySet::hash) are copied onto `policy_epoch`/`policy_hash` on
+//! every [`Decision`](crate::types::Decision)
+//! [`GovernanceEngine::check`](crate::engine::GovernanceEngine::check)
+//! produces, so a historical decision is reproducible against the exact
+//! policy in force when it was made.
+//!
+//! [`apply`](PolicySet::apply) is the only way a [`PolicySet`] changes — it
+//! mutates the snapshot atomically per [`GovernanceInstruction`] and bumps
+//! the epoch exactly once, returning a [`PolicyChange`] the caller logs via
+//! [`AuditLogger::log_policy_change`](crate::audit::AuditLogger::log_policy_change)
+//! so the change itself is as auditable as any governed action — a staged
+//! upgrade path rather than silent in-place mutation.
+//!
+//! A [`PolicySnapshot`] is a caller-maintained summary, not read live off
+//! [`TrustManager`](crate::trust::TrustManager)/[`BudgetManager`](crate::budget::BudgetManager)/
+//! [`ConsentManager`](crate::consent::ConsentManager) state — those track
+//! per-agent assignments and envelopes, not one canonical ruleset. Keeping
+//! the two decoupled lets a deployment version whatever subset of policy it
+//! cares about without this crate dictating what counts as "the policy".
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use serde::{Deserialize, Serialize};
+
+use crate::types::TrustLevel;
+
+/// The content of a governance policy at one point in time: per-scope trust
+/// thresholds, per-category budget limits, and which actions require
+/// consent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PolicySnapshot {
+    /// Minimum trust level required per scope.
+    pub trust_thresholds: BTreeMap<String, TrustLevel>,
+    /// Spending limit per budget category.
+    pub budget_limits: BTreeMap<String, f64>,
+    /// Whether consent is mandatory per action.
+    pub consent_required_actions: BTreeMap<String, bool>,
+}
+
+/// A monotonically increasing `(epoch, hash)` pair identifying exactly which
+/// [`PolicySnapshot`] a [`Decision`](crate::types::Decision) or
+/// [`AuditRecord`](crate::types::AuditRecord) was produced under.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PolicyStamp {
+    /// Number of [`GovernanceInstruction`]s applied to this policy so far.
+    pub epoch: u64,
+    /// Content hash of the snapshot at this epoch.
+    pub hash: String,
+}
+
+/// A typed, atomically-applied change to a [`PolicySet`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum GovernanceInstruction {
+    /// Set the minimum trust level required for `scope`.
+    SetTrustThreshold { scope: String, level: TrustLevel },
+    /// Set the spending limit for `category`.
+    SetBudgetLimit { category: String, limit: f64 },
+    /// Set whether `action` requires consent.
+    SetConsentRequired { action: String, required: bool },
+    /// Mark the policy upgraded to `new_hash` without changing the tracked
+    /// snapshot fields — for a policy change whose content lives outside
+    /// [`PolicySnapshot`] (e.g. new evaluation logic shipped in code) but
+    /// still needs its own epoch and an auditable marker of the rollover.
+    UpgradePolicy { new_hash: String },
+}
+
+/// The result of applying one [`GovernanceInstruction`] to a [`PolicySet`]:
+/// the stamp immediately before and after, for the caller to audit via
+/// [`AuditLogger::log_policy_change`](crate::audit::AuditLogger::log_policy_change).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PolicyChange {
+    /// The instruction that was applied.
+    pub instruction: GovernanceInstruction,
+    /// The stamp before this instruction was applied.
+    pub before: PolicyStamp,
+    /// The stamp after this instruction was applied.
+    pub after: PolicyStamp,
+}
+
+/// A versioned, content-hashed [`PolicySnapshot`].
+///
+/// # Examples
+///
+/// ```rust
+/// use aumos_governance_core::policy::{GovernanceInstruction, PolicySet, PolicySnapshot};
+/// use aumos_governance_core::types::TrustLevel;
+///
+/// let mut policy = PolicySet::new(PolicySnapshot::default());
+/// assert_eq!(policy.epoch(), 0);
+///
+/// let change = policy.apply(GovernanceInstruction::SetTrustThreshold {
+///     scope: "finance".into(),
+///     level: TrustLevel::ActWithApproval,
+/// });
+/// assert_eq!(change.before.epoch, 0);
+/// assert_eq!(change.after.epoch, 1);
+/// assert_ne!(change.before.hash, change.after.hash);
+/// assert_eq!(policy.epoch(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PolicySet {
+    epoch: u64,
+    snapshot: PolicySnapshot,
+}
+
+impl PolicySet {
+    /// Build a new [`PolicySet`] at epoch `0` from `snapshot`.
+    pub fn new(snapshot: PolicySnapshot) -> Self {
+        Self { epoch: 0, snapshot }
+    }
+
+    /// The number of [`GovernanceInstruction`]s applied so far.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// The current policy content.
+    pub fn snapshot(&self) -> &PolicySnapshot {
+        &self.snapshot
+    }
+
+    /// Content hash of the current snapshot.
+    pub fn hash(&self) -> String {
+        hash_snapshot(&self.snapshot)
+    }
+
+    /// This policy's current `(epoch, hash)` stamp.
+    pub fn stamp(&self) -> PolicyStamp {
+        PolicyStamp {
+            epoch: self.epoch,
+            hash: self.hash(),
+        }
+    }
+
+    /// Apply `instruction` atomically: update the snapshot per the variant
+    /// (a no-op for [`UpgradePolicy`](GovernanceInstruction::UpgradePolicy),
+    /// which carries no snapshot content), then bump the epoch exactly once
+    /// regardless of which variant was applied.
+    pub fn apply(&mut self, instruction: GovernanceInstruction) -> PolicyChange {
+        let before = self.stamp();
+
+        match &instruction {
+            GovernanceInstruction::SetTrustThreshold { scope, level } => {
+                self.snapshot.trust_thresholds.insert(scope.clone(), *level);
+            }
+            GovernanceInstruction::SetBudgetLimit { category, limit } => {
+                self.snapshot.budget_limits.insert(category.clone(), *limit);
+            }
+            GovernanceInstruction::SetConsentRequired { action, required } => {
+                self.snapshot
+                    .consent_required_actions
+                    .insert(action.clone(), *required);
+            }
+            GovernanceInstruction::UpgradePolicy { .. } => {}
+        }
+
+        self.epoch += 1;
+        let after = self.stamp();
+
+        PolicyChange {
+            instruction,
+            before,
+            after,
+        }
+    }
+}
+
+/// Hash `snapshot`'s canonical serialisation — genuine SHA-256 under the
+/// `std` feature, FNV-1a otherwise — mirroring
+/// [`audit::recompute_hash`](crate::audit::recompute_hash)'s own std/no_std
+/// split for the same reason: this crate stays `no_std`-compatible without
+/// a cryptographic hash dependency in that configuration.
+fn hash_snapshot(snapshot: &PolicySnapshot) -> String {
+    #[cfg(feature = "std")]
+    {
+        use sha2::{Digest, Sha256};
+
+        let canonical =
+            serde_json::to_vec(snapshot).unwrap_or_else(|_| alloc::vec::Vec::new());
+
+        let mut hasher = Sha256::new();
+        hasher.update(&canonical);
+        let digest = hasher.finalize();
+
+        let mut out = String::with_capacity(64);
+        for byte in digest {
+            out.push_str(&format!("{:02x}", byte));
+        }
+        out
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        use crate::audit::{fnv1a_64, u64_to_hex};
+        use alloc::format;
+        use alloc::vec::Vec;
+
+        // `BTreeMap` already iterates in key order, so this is deterministic
+        // without a separate sort — hash the actual key/value bytes, not
+        // just each map's length, or two snapshots that differ only in an
+        // existing key's value (e.g. tightening a scope's trust threshold)
+        // hash identically.
+        let mut payload = Vec::new();
+        for (scope, level) in &snapshot.trust_thresholds {
+            payload.extend_from_slice(scope.as_bytes());
+            payload.push(b':');
+            payload.push(*level as u8);
+            payload.push(b'|');
+        }
+        for (category, limit) in &snapshot.budget_limits {
+            payload.extend_from_slice(category.as_bytes());
+            payload.push(b':');
+            payload.extend_from_slice(format!("{}", limit).as_bytes());
+            payload.push(b'|');
+        }
+        for (action, required) in &snapshot.consent_required_actions {
+            payload.extend_from_slice(action.as_bytes());
+            payload.push(b':');
+            payload.push(*required as u8);
+            payload.push(b'|');
+        }
+
+        let hash64 = fnv1a_64(&payload);
+        let hex16 = u64_to_hex(hash64);
+        let mut out = String::with_capacity(64);
+        for _ in 0..4 {
+            out.push_str(&hex16);
+        }
+        out
+    }
+}
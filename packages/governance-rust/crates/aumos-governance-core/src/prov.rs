@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! W3C PROV-JSON export of the audit log.
+//!
+//! [`AuditLogger::export_prov`] turns a slice of the hash-chained audit trail
+//! into a [`ProvDocument`]: each [`AuditRecord`](crate::types::AuditRecord) becomes a `prov:Activity`
+//! (the evaluated action), the acting agent a `prov:Agent`, and the decision
+//! itself a `prov:Entity`, linked by `wasGeneratedBy` (entity → activity),
+//! `wasAssociatedWith` (activity → agent), and `wasAttributedTo` (entity →
+//! agent). The trust/budget/consent gate results that fed the decision are
+//! recorded as their own entities, linked to the activity via `used` edges.
+//!
+//! Unlike a generic provenance exporter, this one has the hash chain to lean
+//! on: consecutive records in the export are additionally linked
+//! entity-to-entity via `wasDerivedFrom`, carrying `prev_hash`/`hash` as
+//! ordering metadata — so the exported graph's activity order is verifiable
+//! against [`AuditLogger::verify_chain`], not just asserted by export order.
+//!
+//! Only compiled under the `std` feature: building the PROV-JSON value tree
+//! goes through `serde_json::Value`, which (like [`recompute_hash`](crate::audit::recompute_hash)'s
+//! canonical-JSON hashing) this crate only reaches for when `std` is available.
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::audit::AuditLogger;
+use crate::storage::Storage;
+use crate::types::AuditFilter;
+
+/// A W3C PROV-JSON document, as produced by [`AuditLogger::export_prov`].
+///
+/// Serialises directly to the PROV-JSON interchange format via
+/// [`to_prov_json`](Self::to_prov_json) (or `serde_json::to_string` /
+/// `to_value`, since this type derives [`Serialize`]).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ProvDocument {
+    pub prefix: BTreeMap<String, String>,
+    pub activity: BTreeMap<String, Value>,
+    pub agent: BTreeMap<String, Value>,
+    pub entity: BTreeMap<String, Value>,
+    #[serde(rename = "wasGeneratedBy")]
+    pub was_generated_by: BTreeMap<String, Value>,
+    #[serde(rename = "wasAssociatedWith")]
+    pub was_associated_with: BTreeMap<String, Value>,
+    #[serde(rename = "wasAttributedTo")]
+    pub was_attributed_to: BTreeMap<String, Value>,
+    pub used: BTreeMap<String, Value>,
+    #[serde(rename = "wasDerivedFrom")]
+    pub was_derived_from: BTreeMap<String, Value>,
+}
+
+impl ProvDocument {
+    /// Serialise this document to the PROV-JSON interchange format.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`serde_json::Error`] if serialisation fails — in practice
+    /// this only happens if a downstream caller has somehow produced a
+    /// non-serialisable `Value`, since every field this module populates is
+    /// already valid JSON.
+    pub fn to_prov_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+impl<S: Storage> AuditLogger<S> {
+    /// Export the records matching `filter` as a [`ProvDocument`].
+    ///
+    /// Records are exported in the order [`query`](Self::query) returns
+    /// them. Each gets an `prov:Activity`/`prov:Agent`/`prov:Entity` triple
+    /// plus `used` edges for its trust/budget/consent gate results (see the
+    /// module docs). Consecutive records are additionally linked via
+    /// `wasDerivedFrom`, carrying the chain's own `prev_hash`/`hash` as
+    /// ordering metadata, so the export's activity order can be checked
+    /// against [`verify_chain`](Self::verify_chain) rather than trusted
+    /// blindly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aumos_governance_core::{
+    ///     audit::AuditLogger,
+    ///     storage::InMemoryStorage,
+    ///     types::{AuditFilter, BudgetResult, ConsentResult, Decision, TrustLevel, TrustResult},
+    /// };
+    ///
+    /// let mut logger = AuditLogger::new(InMemoryStorage::new());
+    /// logger.log(Decision {
+    ///     permitted: true,
+    ///     action: "send_report".into(),
+    ///     agent_id: "agent-001".into(),
+    ///     scope: "default".into(),
+    ///     timestamp_ms: 0,
+    ///     reason: "All governance gates passed.".into(),
+    ///     policy_epoch: 0,
+    ///     policy_hash: String::new(),
+    ///     trust: TrustResult { permitted: true, current_level: TrustLevel::Suggest, required_level: TrustLevel::Suggest, reason: "ok".into() },
+    ///     budget: BudgetResult { permitted: true, available: 100.0, requested: 0.0, category: "default".into(), reason: "ok".into(), dimension: None },
+    ///     consent: ConsentResult { permitted: true, reason: "ok".into() },
+    /// });
+    ///
+    /// let doc = logger.export_prov(&AuditFilter::default());
+    /// assert_eq!(doc.activity.len(), 1);
+    /// assert!(doc.to_prov_json().unwrap().contains("prov:Agent"));
+    /// ```
+    pub fn export_prov(&self, filter: &AuditFilter) -> ProvDocument {
+        let records = self.query(filter);
+        let mut doc = ProvDocument {
+            prefix: BTreeMap::from([
+                ("prov".into(), "http://www.w3.org/ns/prov#".into()),
+                ("aumos".into(), "https://aumos.dev/prov#".into()),
+            ]),
+            ..ProvDocument::default()
+        };
+
+        let mut previous_entity_id: Option<(String, String)> = None; // (entity_id, hash)
+
+        for record in &records {
+            let node_id = &record.id;
+            let decision = &record.decision;
+
+            let activity_id = format!("aumos:activity-{node_id}");
+            let agent_id = format!("aumos:agent-{}", decision.agent_id);
+            let entity_id = format!("aumos:entity-{node_id}");
+
+            doc.activity.insert(
+                activity_id.clone(),
+                json!({
+                    "prov:startTime": decision.timestamp_ms,
+                    "prov:endTime": decision.timestamp_ms,
+                    "aumos:action": decision.action,
+                }),
+            );
+            doc.agent
+                .entry(agent_id.clone())
+                .or_insert_with(|| json!({ "prov:type": "prov:Agent" }));
+            doc.entity.insert(
+                entity_id.clone(),
+                json!({
+                    "aumos:permitted": decision.permitted,
+                    "aumos:reason": decision.reason,
+                }),
+            );
+
+            doc.was_generated_by.insert(
+                format!("_:wgb-{node_id}"),
+                json!({ "prov:entity": entity_id, "prov:activity": activity_id }),
+            );
+            doc.was_associated_with.insert(
+                format!("_:waw-{node_id}"),
+                json!({ "prov:activity": activity_id, "prov:agent": agent_id }),
+            );
+            doc.was_attributed_to.insert(
+                format!("_:wat-{node_id}"),
+                json!({ "prov:entity": entity_id, "prov:agent": agent_id }),
+            );
+
+            for (input_kind, input_json) in [
+                ("trust", serde_json::to_value(&decision.trust)),
+                ("budget", serde_json::to_value(&decision.budget)),
+                ("consent", serde_json::to_value(&decision.consent)),
+            ] {
+                let Ok(input_json) = input_json else { continue };
+                let input_entity_id = format!("aumos:input-{input_kind}-{node_id}");
+                doc.entity.insert(input_entity_id.clone(), input_json);
+                doc.used.insert(
+                    format!("_:used-{input_kind}-{node_id}"),
+                    json!({ "prov:activity": activity_id, "prov:entity": input_entity_id }),
+                );
+            }
+
+            if let Some((prev_entity_id, prev_hash)) = &previous_entity_id {
+                doc.was_derived_from.insert(
+                    format!("_:wdf-{node_id}"),
+                    json!({
+                        "prov:generatedEntity": entity_id,
+                        "prov:usedEntity": prev_entity_id,
+                        "aumos:prev_hash": record.prev_hash,
+                        "aumos:hash": record.hash,
+                        "aumos:chain_verified": &record.prev_hash == prev_hash,
+                    }),
+                );
+            }
+            previous_entity_id = Some((entity_id, record.hash.clone()));
+        }
+
+        doc
+    }
+}
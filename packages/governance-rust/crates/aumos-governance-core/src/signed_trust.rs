@@ -0,0 +1,284 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! M-of-N signed trust assignments.
+//!
+//! Plain [`TrustManager::set_level`](crate::trust::TrustManager::set_level)
+//! records `assigned_by` as a bare string — authority rests on whatever
+//! controls write access to [`Storage`](crate::storage::Storage), with no
+//! way for a downstream consumer to independently check who actually
+//! authorised a level. [`ApproverSet`] plus
+//! [`TrustManager::set_level_signed`](crate::trust::TrustManager::set_level_signed)
+//! add an opt-in quorum mode for levels where that isn't enough — e.g.
+//! requiring two of three named owners to co-sign before an agent reaches
+//! [`TrustLevel::Autonomous`](crate::types::TrustLevel::Autonomous).
+//!
+//! Each approver signs the assignment's [`canonical_payload`] — `agent_id`,
+//! `level`, `scope`, `assigned_at_ms`, and `expires_at_ms`, with the
+//! variable-length `agent_id`/`scope` fields each length-prefixed so no
+//! value can bleed into its neighbour — with Ed25519.
+//! [`set_level_signed`](crate::trust::TrustManager::set_level_signed) rejects
+//! the assignment unless at least `threshold` of those signatures verify
+//! against distinct indices into [`ApproverSet::keys`]; a stored
+//! [`TrustAssignment`] can later be re-checked independently of the manager
+//! via [`TrustAssignment::verify`].
+//!
+//! Only compiled under the `signed-trust` feature, which pulls in
+//! `ed25519_dalek` — a dependency the rest of this `no_std` crate does not
+//! otherwise need.
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt;
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+use crate::clock_policy::ClockPolicyError;
+use crate::types::{TrustAssignment, TrustSignature};
+
+/// The set of keys authorised to co-sign trust assignments, and how many of
+/// them must agree.
+///
+/// `threshold` is compared against the count of *valid, distinct-index*
+/// signatures. A signature set that names the same index twice is rejected
+/// outright rather than counted once (see [`TrustAssignment::verify`]).
+#[derive(Debug, Clone)]
+pub struct ApproverSet {
+    /// Public keys eligible to co-sign, indexed by position — a
+    /// [`TrustSignature::approver_index`] refers into this list.
+    pub keys: Vec<VerifyingKey>,
+    /// Minimum number of distinct, valid signatures required.
+    pub threshold: u8,
+}
+
+impl ApproverSet {
+    /// Build an [`ApproverSet`] from `keys` requiring `threshold` of them.
+    pub fn new(keys: Vec<VerifyingKey>, threshold: u8) -> Self {
+        Self { keys, threshold }
+    }
+}
+
+/// Why a signed [`TrustAssignment`] failed verification.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrustError {
+    /// Fewer than `required` distinct signatures verified.
+    InsufficientSignatures {
+        /// The [`ApproverSet::threshold`] that had to be met.
+        required: u8,
+        /// How many distinct, valid signatures were actually found.
+        valid: u8,
+    },
+    /// Two or more signatures named the same [`ApproverSet`] index.
+    DuplicateApproverIndex(u8),
+    /// A signature named an index past the end of [`ApproverSet::keys`].
+    ApproverIndexOutOfRange(u8),
+    /// A signature did not verify against its claimed approver's key and the
+    /// assignment's canonical payload.
+    InvalidSignature(u8),
+    /// The assignment's `expires_at_ms` failed
+    /// [`TrustManager::set_level_signed`](crate::trust::TrustManager::set_level_signed)'s
+    /// [`ClockPolicy`](crate::clock_policy::ClockPolicy) check.
+    ClockSkew(ClockPolicyError),
+}
+
+impl fmt::Display for TrustError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrustError::InsufficientSignatures { required, valid } => write!(
+                f,
+                "only {valid} of the required {required} approver signatures verified"
+            ),
+            TrustError::DuplicateApproverIndex(index) => {
+                write!(f, "approver index {index} signed more than once")
+            }
+            TrustError::ApproverIndexOutOfRange(index) => {
+                write!(f, "approver index {index} is out of range for this approver set")
+            }
+            TrustError::InvalidSignature(index) => {
+                write!(f, "signature from approver index {index} does not verify")
+            }
+            TrustError::ClockSkew(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+/// The exact bytes each approver signs for `assignment`.
+///
+/// `agent_id` and `scope` are caller-supplied strings with no charset
+/// restriction, so a plain `|`-joined payload is ambiguous: bytes can shift
+/// across the `agent_id`/`scope` boundary (e.g. `agent_id` ending in and
+/// `scope` starting with text that, concatenated, reproduces another
+/// assignment's joined fields) without changing the resulting payload at
+/// all, letting one signed grant verify for a different `(agent_id, scope)`.
+/// Each variable-length field is instead prefixed with its own byte length
+/// (`len:value`, TLV-style) so no choice of field content can make two
+/// structurally different assignments collide on the same payload.
+pub fn canonical_payload(assignment: &TrustAssignment) -> Vec<u8> {
+    let mut payload = Vec::new();
+    write_len_prefixed(&mut payload, assignment.agent_id.as_bytes());
+    write_len_prefixed(&mut payload, assignment.scope.as_bytes());
+    payload.extend_from_slice(
+        format!(
+            "{}|{}|{}",
+            assignment.level as u8,
+            assignment.assigned_at_ms,
+            assignment.expires_at_ms.map(|ms| ms as i64).unwrap_or(-1),
+        )
+        .as_bytes(),
+    );
+    payload
+}
+
+/// Append `field` to `payload` as `"{len}:{field}"`, so the reader never has
+/// to guess where `field` ends — unlike a bare separator, a length prefix
+/// can't be spoofed by choosing `field`'s own content.
+fn write_len_prefixed(payload: &mut Vec<u8>, field: &[u8]) {
+    payload.extend_from_slice(format!("{}:", field.len()).as_bytes());
+    payload.extend_from_slice(field);
+}
+
+impl TrustAssignment {
+    /// Verify that this assignment's [`signatures`](Self::signatures) meet
+    /// `approvers`'s threshold over its [`canonical_payload`].
+    ///
+    /// Signatures are checked in order; the first one naming a duplicate or
+    /// out-of-range index, or that fails to verify, fails the whole check —
+    /// there is no "skip the bad ones and count what's left" leniency, since
+    /// a store that would accept a partially-forged signature set is exactly
+    /// what this mode exists to rule out.
+    pub fn verify(&self, approvers: &ApproverSet) -> Result<(), TrustError> {
+        let payload = canonical_payload(self);
+        let mut seen = Vec::with_capacity(self.signatures.len());
+
+        for sig in &self.signatures {
+            if seen.contains(&sig.approver_index) {
+                return Err(TrustError::DuplicateApproverIndex(sig.approver_index));
+            }
+            let key = approvers
+                .keys
+                .get(sig.approver_index as usize)
+                .ok_or(TrustError::ApproverIndexOutOfRange(sig.approver_index))?;
+            let signature = Signature::from_bytes(&sig.signature);
+            key.verify(&payload, &signature)
+                .map_err(|_| TrustError::InvalidSignature(sig.approver_index))?;
+            seen.push(sig.approver_index);
+        }
+
+        if (seen.len() as u8) < approvers.threshold {
+            return Err(TrustError::InsufficientSignatures {
+                required: approvers.threshold,
+                valid: seen.len() as u8,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TrustLevel;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    fn assignment(agent_id: &str, scope: &str) -> TrustAssignment {
+        TrustAssignment {
+            agent_id: agent_id.into(),
+            level: TrustLevel::Autonomous,
+            scope: scope.into(),
+            assigned_at_ms: 1_700_000_000_000,
+            expires_at_ms: None,
+            assigned_by: "owner".into(),
+            signatures: Vec::new(),
+        }
+    }
+
+    fn sign(key: &SigningKey, approver_index: u8, payload: &[u8]) -> TrustSignature {
+        TrustSignature {
+            approver_index,
+            signature: key.sign(payload).to_bytes(),
+        }
+    }
+
+    #[test]
+    fn canonical_payload_does_not_collide_across_the_agent_id_scope_boundary() {
+        // Two structurally different assignments whose `agent_id`/`scope`
+        // bytes, naively `|`-joined, would concatenate to the same string.
+        let a = assignment("svc-42", "projects/acme|4|1700000000000|-1/deploy");
+        let b = assignment("svc-42|4|projects/acme", "1700000000000|-1/deploy");
+        assert_ne!(canonical_payload(&a), canonical_payload(&b));
+    }
+
+    #[test]
+    fn verify_succeeds_with_enough_distinct_valid_signatures() {
+        let key0 = signing_key(1);
+        let key1 = signing_key(2);
+        let approvers = ApproverSet::new(vec![key0.verifying_key(), key1.verifying_key()], 2);
+
+        let mut a = assignment("agent-001", "finance");
+        let payload = canonical_payload(&a);
+        a.signatures = vec![sign(&key0, 0, &payload), sign(&key1, 1, &payload)];
+
+        assert_eq!(a.verify(&approvers), Ok(()));
+    }
+
+    #[test]
+    fn verify_rejects_insufficient_signatures() {
+        let key0 = signing_key(1);
+        let key1 = signing_key(2);
+        let approvers = ApproverSet::new(vec![key0.verifying_key(), key1.verifying_key()], 2);
+
+        let mut a = assignment("agent-001", "finance");
+        let payload = canonical_payload(&a);
+        a.signatures = vec![sign(&key0, 0, &payload)];
+
+        assert_eq!(
+            a.verify(&approvers),
+            Err(TrustError::InsufficientSignatures { required: 2, valid: 1 })
+        );
+    }
+
+    #[test]
+    fn verify_rejects_duplicate_approver_index() {
+        let key0 = signing_key(1);
+        let approvers = ApproverSet::new(vec![key0.verifying_key()], 1);
+
+        let mut a = assignment("agent-001", "finance");
+        let payload = canonical_payload(&a);
+        a.signatures = vec![sign(&key0, 0, &payload), sign(&key0, 0, &payload)];
+
+        assert_eq!(a.verify(&approvers), Err(TrustError::DuplicateApproverIndex(0)));
+    }
+
+    #[test]
+    fn verify_rejects_out_of_range_approver_index() {
+        let key0 = signing_key(1);
+        let approvers = ApproverSet::new(vec![key0.verifying_key()], 1);
+
+        let mut a = assignment("agent-001", "finance");
+        let payload = canonical_payload(&a);
+        a.signatures = vec![sign(&key0, 5, &payload)];
+
+        assert_eq!(a.verify(&approvers), Err(TrustError::ApproverIndexOutOfRange(5)));
+    }
+
+    #[test]
+    fn verify_rejects_signature_over_a_different_assignment() {
+        let key0 = signing_key(1);
+        let approvers = ApproverSet::new(vec![key0.verifying_key()], 1);
+
+        let mut a = assignment("agent-001", "finance");
+        let other_payload = canonical_payload(&assignment("agent-002", "finance"));
+        a.signatures = vec![sign(&key0, 0, &other_payload)];
+
+        assert_eq!(a.verify(&approvers), Err(TrustError::InvalidSignature(0)));
+    }
+}
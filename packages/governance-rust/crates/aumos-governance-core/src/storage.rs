@@ -12,7 +12,7 @@
 //!
 //! ```rust,no_run
 //! use aumos_governance_core::storage::{Storage, AuditFilter};
-//! use aumos_governance_core::types::{AuditRecord, Envelope, TrustAssignment};
+//! use aumos_governance_core::types::{AuditRecord, ConsentGrant, Envelope, TrustAssignment, TrustDelegation};
 //!
 //! struct MyStorage;
 //!
@@ -23,19 +23,31 @@
 //!     fn set_trust(&mut self, _agent_id: &str, _scope: &str, _assignment: TrustAssignment) {}
 //!     fn get_envelope(&self, _category: &str) -> Option<Envelope> { None }
 //!     fn set_envelope(&mut self, _category: &str, _envelope: Envelope) {}
-//!     fn get_consent(&self, _agent_id: &str, _action: &str) -> bool { false }
-//!     fn set_consent(&mut self, _agent_id: &str, _action: &str, _granted: bool) {}
+//!     fn get_consent(&self, _agent_id: &str, _action: &str) -> Option<ConsentGrant> { None }
+//!     fn set_consent(&mut self, _agent_id: &str, _action: &str, _grant: ConsentGrant) {}
+//!     fn get_delegation(&self, _delegate: &str, _action: &str) -> Option<alloc::string::String> { None }
+//!     fn set_delegation(&mut self, _delegate: &str, _action: &str, _grantor: &str) {}
+//!     fn remove_delegation(&mut self, _delegate: &str, _action: &str) {}
+//!     fn get_authority(&self, _key: &str) -> bool { false }
+//!     fn set_authority(&mut self, _key: &str, _granted: bool) {}
+//!     fn get_trust_delegations(&self, _agent_id: &str, _scope: &str) -> alloc::vec::Vec<TrustDelegation> {
+//!         alloc::vec::Vec::new()
+//!     }
+//!     fn add_trust_delegation(&mut self, _agent_id: &str, _scope: &str, _delegation: TrustDelegation) {}
+//!     fn get_authority_threshold(&self, _agent_id: &str, _scope: &str) -> Option<f64> { None }
+//!     fn set_authority_threshold(&mut self, _agent_id: &str, _scope: &str, _threshold: f64) {}
 //!     fn append_audit(&mut self, _record: AuditRecord) {}
 //!     fn query_audit(&self, _filter: &AuditFilter) -> alloc::vec::Vec<AuditRecord> {
 //!         alloc::vec::Vec::new()
 //!     }
+//!     fn verify_chain(&self) -> Result<(), usize> { Ok(()) }
 //! }
 //! ```
 
 use alloc::vec::Vec;
 use hashbrown::HashMap;
 
-use crate::types::{AuditFilter, AuditRecord, Envelope, TrustAssignment};
+use crate::types::{AuditFilter, AuditRecord, ConsentGrant, Envelope, TrustAssignment, TrustDelegation};
 
 // ---------------------------------------------------------------------------
 // Storage trait
@@ -75,11 +87,56 @@ pub trait Storage: Send + Sync {
     // Consent
     // ------------------------------------------------------------------
 
-    /// Return `true` if active consent exists for `(agent_id, action)`.
-    fn get_consent(&self, agent_id: &str, action: &str) -> bool;
+    /// Retrieve the consent grant for `(agent_id, action)`, if any has ever
+    /// been recorded.
+    fn get_consent(&self, agent_id: &str, action: &str) -> Option<ConsentGrant>;
+
+    /// Persist or overwrite the consent grant for `(agent_id, action)`.
+    fn set_consent(&mut self, agent_id: &str, action: &str, grant: ConsentGrant);
+
+    /// Retrieve the agent that delegated `action` to `delegate`, if any.
+    fn get_delegation(&self, delegate: &str, action: &str) -> Option<alloc::string::String>;
+
+    /// Record that `grantor` has delegated `action` to `delegate`,
+    /// overwriting any previous delegation for the same pair.
+    fn set_delegation(&mut self, delegate: &str, action: &str, grantor: &str);
+
+    /// Withdraw a previously recorded delegation for `(delegate, action)`.
+    /// A no-op if none exists.
+    fn remove_delegation(&mut self, delegate: &str, action: &str);
+
+    // ------------------------------------------------------------------
+    // Authorization
+    // ------------------------------------------------------------------
+
+    /// Return `true` if `key` (a `(principal, operation, scope)` triple,
+    /// opaque to this trait) has been granted authority.
+    fn get_authority(&self, key: &str) -> bool;
+
+    /// Record or update the authority flag for `key`.
+    fn set_authority(&mut self, key: &str, granted: bool);
+
+    // ------------------------------------------------------------------
+    // Delegated trust authority
+    // ------------------------------------------------------------------
+
+    /// Retrieve every weighted vote recorded toward `(agent_id, scope)`'s
+    /// authority, in no particular order.
+    fn get_trust_delegations(&self, agent_id: &str, scope: &str) -> Vec<TrustDelegation>;
 
-    /// Record or update the consent flag for `(agent_id, action)`.
-    fn set_consent(&mut self, agent_id: &str, action: &str, granted: bool);
+    /// Append one weighted vote toward `(agent_id, scope)`'s authority.
+    /// Votes accumulate — this never overwrites a previously recorded one.
+    fn add_trust_delegation(&mut self, agent_id: &str, scope: &str, delegation: TrustDelegation);
+
+    /// Retrieve the weight threshold configured for `(agent_id, scope)`'s
+    /// authority, if [`TrustManager::set_authority_threshold`]
+    /// (crate::trust::TrustManager::set_authority_threshold) has been called
+    /// for it.
+    fn get_authority_threshold(&self, agent_id: &str, scope: &str) -> Option<f64>;
+
+    /// Persist or overwrite the weight threshold for `(agent_id, scope)`'s
+    /// authority.
+    fn set_authority_threshold(&mut self, agent_id: &str, scope: &str, threshold: f64);
 
     // ------------------------------------------------------------------
     // Audit
@@ -90,6 +147,17 @@ pub trait Storage: Send + Sync {
 
     /// Return all audit records that satisfy `filter`.
     fn query_audit(&self, filter: &AuditFilter) -> Vec<AuditRecord>;
+
+    /// Walk the audit log in append order, recomputing each record's hash
+    /// chain link (see [`crate::audit::recompute_hash`]) and comparing it
+    /// against the stored `prev_hash`/`hash` pair.
+    ///
+    /// Returns `Ok(())` if every link is intact, or `Err(index)` naming the
+    /// position of the first record whose link is broken — by a tampered
+    /// field, a deleted record, or a reordered one. This is an on-demand
+    /// check operators can run at any time; it does not run implicitly on
+    /// [`append_audit`](Self::append_audit) or [`query_audit`](Self::query_audit).
+    fn verify_chain(&self) -> Result<(), usize>;
 }
 
 // ---------------------------------------------------------------------------
@@ -109,10 +177,12 @@ pub trait Storage: Send + Sync {
 /// use aumos_governance_core::storage::InMemoryStorage;
 /// use aumos_governance_core::Storage;
 ///
+/// use aumos_governance_core::types::ConsentGrant;
+///
 /// let mut store = InMemoryStorage::new();
-/// store.set_consent("agent-001", "read_pii", true);
-/// assert!(store.get_consent("agent-001", "read_pii"));
-/// assert!(!store.get_consent("agent-001", "delete_records"));
+/// store.set_consent("agent-001", "read_pii", ConsentGrant { granted: true, expiry_ms: None, purpose: None });
+/// assert!(store.get_consent("agent-001", "read_pii").unwrap().granted);
+/// assert!(store.get_consent("agent-001", "delete_records").is_none());
 /// ```
 #[derive(Debug, Default, Clone)]
 pub struct InMemoryStorage {
@@ -120,10 +190,23 @@ pub struct InMemoryStorage {
     trust: HashMap<alloc::string::String, TrustAssignment>,
     /// Key: category name → spending envelope.
     envelopes: HashMap<alloc::string::String, Envelope>,
-    /// Key: `"{agent_id}:{action}"` → consent flag.
-    consent: HashMap<alloc::string::String, bool>,
+    /// Key: `"{agent_id}:{action}"` → consent grant.
+    consent: HashMap<alloc::string::String, ConsentGrant>,
+    /// Key: `"{delegate}:{action}"` → delegating grantor's agent id.
+    delegation: HashMap<alloc::string::String, alloc::string::String>,
+    /// Key: `"{principal}:{operation}:{scope}"` → authority flag.
+    authority: HashMap<alloc::string::String, bool>,
+    /// Key: `"{agent_id}:{scope}"` → weighted votes cast toward that
+    /// authority.
+    trust_delegations: HashMap<alloc::string::String, Vec<TrustDelegation>>,
+    /// Key: `"{agent_id}:{scope}"` → that authority's weight threshold.
+    authority_thresholds: HashMap<alloc::string::String, f64>,
     /// Append-only audit log.
     audit: Vec<AuditRecord>,
+    /// Secondary index: agent id → positions of its records in `audit`, in
+    /// append order. Lets agent-scoped `query_audit` calls visit exactly the
+    /// matching records instead of scanning the whole log.
+    agent_index: HashMap<alloc::string::String, Vec<usize>>,
 }
 
 impl InMemoryStorage {
@@ -161,32 +244,87 @@ impl Storage for InMemoryStorage {
         self.envelopes.insert(category.into(), envelope);
     }
 
-    fn get_consent(&self, agent_id: &str, action: &str) -> bool {
+    fn get_consent(&self, agent_id: &str, action: &str) -> Option<ConsentGrant> {
         let key = Self::composite_key(agent_id, action);
-        self.consent.get(&key).copied().unwrap_or(false)
+        self.consent.get(&key).cloned()
     }
 
-    fn set_consent(&mut self, agent_id: &str, action: &str, granted: bool) {
+    fn set_consent(&mut self, agent_id: &str, action: &str, grant: ConsentGrant) {
         let key = Self::composite_key(agent_id, action);
-        self.consent.insert(key, granted);
+        self.consent.insert(key, grant);
+    }
+
+    fn get_delegation(&self, delegate: &str, action: &str) -> Option<alloc::string::String> {
+        let key = Self::composite_key(delegate, action);
+        self.delegation.get(&key).cloned()
+    }
+
+    fn set_delegation(&mut self, delegate: &str, action: &str, grantor: &str) {
+        let key = Self::composite_key(delegate, action);
+        self.delegation.insert(key, grantor.into());
+    }
+
+    fn remove_delegation(&mut self, delegate: &str, action: &str) {
+        let key = Self::composite_key(delegate, action);
+        self.delegation.remove(&key);
+    }
+
+    fn get_authority(&self, key: &str) -> bool {
+        self.authority.get(key).copied().unwrap_or(false)
+    }
+
+    fn set_authority(&mut self, key: &str, granted: bool) {
+        self.authority.insert(key.into(), granted);
+    }
+
+    fn get_trust_delegations(&self, agent_id: &str, scope: &str) -> Vec<TrustDelegation> {
+        let key = Self::composite_key(agent_id, scope);
+        self.trust_delegations.get(&key).cloned().unwrap_or_default()
+    }
+
+    fn add_trust_delegation(&mut self, agent_id: &str, scope: &str, delegation: TrustDelegation) {
+        let key = Self::composite_key(agent_id, scope);
+        self.trust_delegations.entry(key).or_insert_with(Vec::new).push(delegation);
+    }
+
+    fn get_authority_threshold(&self, agent_id: &str, scope: &str) -> Option<f64> {
+        let key = Self::composite_key(agent_id, scope);
+        self.authority_thresholds.get(&key).copied()
+    }
+
+    fn set_authority_threshold(&mut self, agent_id: &str, scope: &str, threshold: f64) {
+        let key = Self::composite_key(agent_id, scope);
+        self.authority_thresholds.insert(key, threshold);
     }
 
     fn append_audit(&mut self, record: AuditRecord) {
+        let index = self.audit.len();
+        self.agent_index
+            .entry(record.agent_id.clone())
+            .or_insert_with(Vec::new)
+            .push(index);
         self.audit.push(record);
     }
 
     fn query_audit(&self, filter: &AuditFilter) -> Vec<AuditRecord> {
-        self.audit
-            .iter()
+        // When `agent_id` is set, the secondary index narrows the scan to
+        // exactly that agent's record positions (in append order) instead of
+        // the whole log; otherwise every position is a candidate.
+        let candidates: Vec<usize> = match &filter.agent_id {
+            Some(agent_id) => self
+                .agent_index
+                .get(agent_id.as_str())
+                .cloned()
+                .unwrap_or_default(),
+            None => (0..self.audit.len()).collect(),
+        };
+
+        candidates
+            .into_iter()
+            .filter_map(|index| self.audit.get(index))
             .filter(|record| {
-                // agent_id filter: the AuditLogger embeds a record id with the
-                // format "<action>-<hash_prefix>".  The agent is not directly
-                // stored on the record; filter by action or timestamp instead.
-                // If the caller has set agent_id we fall back to a prefix match
-                // on the record id for compatibility with callers that set the
-                // id to include the agent (e.g. custom Storage impls).
-                if let Some(ref agent_id) = filter.agent_id {
-                    if !record.id.starts_with(agent_id.as_str()) {
+                if let Some(ref scope) = filter.scope {
+                    if &record.scope != scope {
                         return false;
                     }
                 }
@@ -211,4 +349,23 @@ impl Storage for InMemoryStorage {
             .cloned()
             .collect()
     }
+
+    fn verify_chain(&self) -> Result<(), usize> {
+        let mut expected_prev_hash = "0".repeat(64);
+
+        for (index, record) in self.audit.iter().enumerate() {
+            if record.prev_hash != expected_prev_hash {
+                return Err(index);
+            }
+
+            let recomputed = crate::audit::recompute_hash(&record.decision, &record.prev_hash);
+            if recomputed != record.hash {
+                return Err(index);
+            }
+
+            expected_prev_hash = record.hash.clone();
+        }
+
+        Ok(())
+    }
 }
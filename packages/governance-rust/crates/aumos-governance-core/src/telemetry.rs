@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! Optional tracing and metrics hooks for governance evaluations.
+//!
+//! [`Telemetry`] is the extension point [`GovernanceEngine`](crate::engine::GovernanceEngine)
+//! calls into for every [`check`](crate::engine::GovernanceEngine::check) and
+//! each sub-gate it runs. The trait has default no-op bodies, so a `no_std`
+//! build (or any caller who doesn't care about observability) pays nothing —
+//! [`NoopTelemetry`] is the engine's default and every method the engine
+//! calls compiles away to nothing.
+//!
+//! Integrators who want real spans, counters, and gauges implement
+//! [`Telemetry`] against whatever exporter they already run (OpenTelemetry,
+//! StatsD, a structured logger) and install it with
+//! [`GovernanceEngine::with_telemetry`](crate::engine::GovernanceEngine::with_telemetry).
+//! This crate does not depend on the `opentelemetry` crate itself — that
+//! dependency, and a concrete OTEL-backed `Telemetry` implementation, belongs
+//! in a downstream `std`-only crate (see `aumos-governance-cf::telemetry` for
+//! the same pattern applied to the Cloudflare middleware).
+
+use alloc::string::String;
+
+/// Tracing/metrics hooks for the governance pipeline.
+///
+/// Every method has a default no-op body, so implementors only override what
+/// they actually record.
+///
+/// # Examples
+///
+/// ```rust
+/// use aumos_governance_core::{
+///     config::Config,
+///     engine::GovernanceEngine,
+///     storage::InMemoryStorage,
+///     telemetry::Telemetry,
+///     types::{Context, TrustLevel},
+/// };
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// #[derive(Default)]
+/// struct CountingTelemetry {
+///     decisions: AtomicU64,
+/// }
+///
+/// impl Telemetry for CountingTelemetry {
+///     fn on_decision(&self, _agent_id: &str, _scope: &str, _action: &str, _permitted: bool, _elapsed_ms: u64) {
+///         self.decisions.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let mut engine = GovernanceEngine::new(Config::default(), InMemoryStorage::new())
+///     .with_telemetry(Box::new(CountingTelemetry::default()));
+///
+/// engine.trust.set_level("agent-001", "default", TrustLevel::ActAndReport, "owner");
+/// let ctx = Context {
+///     agent_id: "agent-001".into(),
+///     scope: "default".into(),
+///     required_trust: TrustLevel::Suggest,
+///     cost: None,
+///     category: "default".into(),
+///     data_type: None,
+///     purpose: None,
+/// };
+/// engine.check("send_report", &ctx);
+/// ```
+pub trait Telemetry {
+    /// Called once per [`GovernanceEngine::check`](crate::engine::GovernanceEngine::check)
+    /// after the final [`Decision`](crate::types::Decision) is known.
+    /// `elapsed_ms` covers the full pipeline, trust through audit append.
+    fn on_decision(&self, agent_id: &str, scope: &str, action: &str, permitted: bool, elapsed_ms: u64) {
+        let _ = (agent_id, scope, action, permitted, elapsed_ms);
+    }
+
+    /// Called once per gate (`"trust"`, `"budget"`, `"consent"`) the pipeline
+    /// actually runs — a gate skipped because its precondition didn't apply
+    /// (e.g. no `cost` set) does not get a call. `reason` is the gate's
+    /// human-readable denial/permit reason, for structured logging.
+    fn on_gate(&self, gate: &str, permitted: bool, reason: &str, elapsed_ms: u64) {
+        let _ = (gate, permitted, reason, elapsed_ms);
+    }
+
+    /// Called after the budget gate runs, reporting the headroom its
+    /// [`BudgetResult::available`](crate::types::BudgetResult::available)
+    /// records for `category` (measured before this action's debit). A debit
+    /// later reverted because the consent gate denied is not reported again.
+    fn on_budget_remaining(&self, category: &str, remaining: f64) {
+        let _ = (category, remaining);
+    }
+}
+
+/// A [`Telemetry`] implementation that records nothing.
+///
+/// Used as [`GovernanceEngine`](crate::engine::GovernanceEngine)'s default —
+/// every call the engine makes through this type is optimised away, so a
+/// caller who never installs telemetry pays no runtime cost and no `alloc`
+/// dependency beyond what the engine already requires.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopTelemetry;
+
+impl Telemetry for NoopTelemetry {}
+
+/// Build the `"permit"` / `"deny"` outcome string [`Telemetry::on_decision`]
+/// and [`Telemetry::on_gate`] callers commonly want alongside the boolean.
+pub fn outcome_label(permitted: bool) -> String {
+    if permitted {
+        "permit".into()
+    } else {
+        "deny".into()
+    }
+}
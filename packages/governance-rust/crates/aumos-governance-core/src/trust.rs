@@ -11,12 +11,38 @@
 //!
 //! Trust levels are **always** set by an authorised owner.  The manager never
 //! promotes or modifies a level on its own.
+//!
+//! ## Delegated authority
+//!
+//! A single [`set_level`](TrustManager::set_level) call is a unilateral
+//! grant by one owner. Some deployments need the opposite — a level that
+//! only takes effect once enough distinct parties have endorsed it (e.g.
+//! two owners must jointly approve promoting an agent to `Autonomous`).
+//! [`delegate_level`](TrustManager::delegate_level) casts one weighted vote
+//! toward granting `(agent_id, scope)` a level, and
+//! [`set_authority_threshold`](TrustManager::set_authority_threshold) sets
+//! the total weight those votes must reach. [`check_level`](TrustManager::check_level)
+//! tries a direct [`set_level`](TrustManager::set_level) assignment first,
+//! and only consults delegated authority as a fallback when no direct
+//! assignment meets `required`.
+//!
+//! A vote only counts if its delegator is *satisfied*: a delegator with no
+//! trust record of its own (an external "owner" identity, exactly like
+//! `set_level`'s `assigned_by`) is trusted at face value, while a delegator
+//! that is itself a tracked agent must have its own trust resolved — by
+//! direct assignment or its own authority, recursively — bounded by
+//! [`Config::max_authority_depth`] to guard against cycles and unbounded
+//! chains; a chain that exceeds the bound is treated as unsatisfied.
 
+use alloc::sync::Arc;
 use alloc::string::String;
+use alloc::vec::Vec;
 
+use crate::clock::Clock;
+use crate::clock_policy::{ClockPolicy, ClockPolicyError};
 use crate::config::Config;
 use crate::storage::Storage;
-use crate::types::{TrustAssignment, TrustLevel, TrustResult};
+use crate::types::{TrustAssignment, TrustDelegation, TrustLevel, TrustResult};
 
 /// Manages trust level assignments and checks for AI agents.
 ///
@@ -45,12 +71,40 @@ use crate::types::{TrustAssignment, TrustLevel, TrustResult};
 pub struct TrustManager<S: Storage> {
     config: Config,
     storage: S,
+    clock: Arc<dyn Clock + Send + Sync>,
+    clock_policy: ClockPolicy,
 }
 
 impl<S: Storage> TrustManager<S> {
     /// Create a new [`TrustManager`] with the given configuration and storage.
+    ///
+    /// Reads time from [`SystemClock`](crate::clock::SystemClock) (a bare `0`
+    /// under pure `no_std`) by default; install a different time source —
+    /// e.g. a [`ManualClock`](crate::clock::ManualClock) for deterministic
+    /// expiry tests — with [`with_clock`](Self::with_clock). Builds its
+    /// [`ClockPolicy`] from `config.max_clock_drift_ms`; override with
+    /// [`with_clock_policy`](Self::with_clock_policy).
     pub fn new(config: Config, storage: S) -> Self {
-        Self { config, storage }
+        let clock_policy = ClockPolicy::new(config.max_clock_drift_ms);
+        Self {
+            config,
+            storage,
+            clock: crate::clock::default_clock(),
+            clock_policy,
+        }
+    }
+
+    /// Install `clock` as this manager's time source, replacing the default.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Install `clock_policy` as this manager's clock-skew policy, replacing
+    /// the one derived from `Config::max_clock_drift_ms`.
+    pub fn with_clock_policy(mut self, clock_policy: ClockPolicy) -> Self {
+        self.clock_policy = clock_policy;
+        self
     }
 
     /// Assign a trust level to an agent within the given scope.
@@ -87,9 +141,10 @@ impl<S: Storage> TrustManager<S> {
             agent_id: agent_id.into(),
             level,
             scope: scope.into(),
-            assigned_at_ms: current_time_ms(),
+            assigned_at_ms: self.clock.now_ms(),
             expires_at_ms: None,
             assigned_by: assigned_by.into(),
+            signatures: Vec::new(),
         };
         self.storage.set_trust(agent_id, scope, assignment);
     }
@@ -109,13 +164,75 @@ impl<S: Storage> TrustManager<S> {
             agent_id: agent_id.into(),
             level,
             scope: scope.into(),
-            assigned_at_ms: current_time_ms(),
+            assigned_at_ms: self.clock.now_ms(),
             expires_at_ms: Some(expires_at_ms),
             assigned_by: assigned_by.into(),
+            signatures: Vec::new(),
         };
         self.storage.set_trust(agent_id, scope, assignment);
     }
 
+    /// Like [`set_level_with_expiry`](Self::set_level_with_expiry), but
+    /// rejects `expires_at_ms` unless it is strictly after the assignment
+    /// time, per this manager's [`ClockPolicy`]. Nothing is written on
+    /// rejection.
+    pub fn set_level_with_expiry_checked(
+        &mut self,
+        agent_id: &str,
+        scope: &str,
+        level: TrustLevel,
+        assigned_by: &str,
+        expires_at_ms: u64,
+    ) -> Result<(), ClockPolicyError> {
+        self.clock_policy
+            .check_expiry_after(self.clock.now_ms(), Some(expires_at_ms))?;
+        self.set_level_with_expiry(agent_id, scope, level, assigned_by, expires_at_ms);
+        Ok(())
+    }
+
+    /// Assign a trust level, requiring a quorum of co-signed approvals
+    /// rather than trusting a bare `assigned_by` string.
+    ///
+    /// `signatures` must include at least `approvers.threshold` valid,
+    /// distinct-index Ed25519 signatures over the assignment's
+    /// [`canonical_payload`](crate::signed_trust::canonical_payload) — see
+    /// [`TrustAssignment::verify`] for exactly what that requires. On
+    /// success the assignment is stored with its signatures attached, so a
+    /// later reader can re-verify it independently of this call; on failure
+    /// nothing is written and the [`TrustError`](crate::signed_trust::TrustError)
+    /// explains which check failed — including, per this manager's
+    /// [`ClockPolicy`], an `expires_at_ms` that doesn't strictly exceed the
+    /// assignment time.
+    #[cfg(feature = "signed-trust")]
+    pub fn set_level_signed(
+        &mut self,
+        agent_id: &str,
+        scope: &str,
+        level: TrustLevel,
+        assigned_by: &str,
+        expires_at_ms: Option<u64>,
+        signatures: Vec<crate::types::TrustSignature>,
+        approvers: &crate::signed_trust::ApproverSet,
+    ) -> Result<(), crate::signed_trust::TrustError> {
+        let assigned_at_ms = self.clock.now_ms();
+        self.clock_policy
+            .check_expiry_after(assigned_at_ms, expires_at_ms)
+            .map_err(crate::signed_trust::TrustError::ClockSkew)?;
+
+        let assignment = TrustAssignment {
+            agent_id: agent_id.into(),
+            level,
+            scope: scope.into(),
+            assigned_at_ms,
+            expires_at_ms,
+            assigned_by: assigned_by.into(),
+            signatures,
+        };
+        assignment.verify(approvers)?;
+        self.storage.set_trust(agent_id, scope, assignment);
+        Ok(())
+    }
+
     /// Retrieve the current trust assignment for `(agent_id, scope)`.
     ///
     /// Returns `None` when no assignment exists or the assignment has expired.
@@ -141,7 +258,7 @@ impl<S: Storage> TrustManager<S> {
         let assignment = self.storage.get_trust(agent_id, scope)?;
         // Treat expired assignments as absent.
         if let Some(expiry) = assignment.expires_at_ms {
-            if current_time_ms() > expiry {
+            if self.clock.now_ms() > expiry {
                 return None;
             }
         }
@@ -178,6 +295,100 @@ impl<S: Storage> TrustManager<S> {
         scope: &str,
         required: TrustLevel,
     ) -> TrustResult {
+        self.resolve(agent_id, scope, required, self.config.max_authority_depth)
+    }
+
+    /// Cast one weighted vote toward granting `(agent_id, scope)` a trust
+    /// `level`. Votes accumulate — casting a second vote for the same
+    /// `delegator` adds another entry rather than replacing the first; call
+    /// sites that mean to update a vote should account for both still
+    /// counting.
+    ///
+    /// `weight` only ever contributes toward
+    /// [`check_level`](Self::check_level) once
+    /// [`set_authority_threshold`](Self::set_authority_threshold) has been
+    /// called for the same `(agent_id, scope)` — a delegation with no
+    /// configured threshold can never be satisfied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aumos_governance_core::{
+    ///     storage::InMemoryStorage,
+    ///     trust::TrustManager,
+    ///     types::TrustLevel,
+    ///     config::Config,
+    /// };
+    ///
+    /// let mut manager = TrustManager::new(Config::default(), InMemoryStorage::new());
+    /// manager.set_authority_threshold("agent-001", "finance", 2.0);
+    /// manager.delegate_level("owner-a", "agent-001", "finance", TrustLevel::Autonomous, 1.0);
+    /// assert!(!manager.check_level("agent-001", "finance", TrustLevel::Autonomous).permitted);
+    ///
+    /// manager.delegate_level("owner-b", "agent-001", "finance", TrustLevel::Autonomous, 1.0);
+    /// assert!(manager.check_level("agent-001", "finance", TrustLevel::Autonomous).permitted);
+    /// ```
+    pub fn delegate_level(
+        &mut self,
+        delegator: &str,
+        agent_id: &str,
+        scope: &str,
+        level: TrustLevel,
+        weight: f64,
+    ) {
+        self.storage.add_trust_delegation(
+            agent_id,
+            scope,
+            TrustDelegation {
+                delegator: delegator.into(),
+                level,
+                weight,
+            },
+        );
+    }
+
+    /// Set the weight threshold `(agent_id, scope)`'s delegated votes must
+    /// reach before [`check_level`](Self::check_level) treats them as
+    /// granting the delegated level. Overwrites any previously configured
+    /// threshold.
+    pub fn set_authority_threshold(&mut self, agent_id: &str, scope: &str, threshold: f64) {
+        self.storage.set_authority_threshold(agent_id, scope, threshold);
+    }
+
+    /// Borrow the underlying storage (read-only).
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Mutably borrow the underlying storage.
+    pub fn storage_mut(&mut self) -> &mut S {
+        &mut self.storage
+    }
+
+    /// Resolve `(agent_id, scope)` against `required`: a direct
+    /// [`set_level`](Self::set_level) assignment wins outright if it meets
+    /// `required`; otherwise, delegated authority is tried as a fallback,
+    /// bounded by `depth_remaining` recursive hops. Returns the direct
+    /// assignment's (unmet) result if authority isn't configured or isn't
+    /// satisfied either, preserving today's reason text for the common case
+    /// where no delegation is in play at all.
+    fn resolve(&self, agent_id: &str, scope: &str, required: TrustLevel, depth_remaining: u8) -> TrustResult {
+        let direct = self.direct_check(agent_id, scope, required);
+        if direct.permitted {
+            return direct;
+        }
+
+        match self.resolve_authority(agent_id, scope, required, depth_remaining) {
+            Some(result) => result,
+            None => direct,
+        }
+    }
+
+    /// Evaluate `required` against `(agent_id, scope)`'s direct
+    /// [`set_level`](Self::set_level) assignment only — no delegated
+    /// authority is consulted. This is exactly the behavior `check_level`
+    /// had before delegated authority existed.
+    fn direct_check(&self, agent_id: &str, scope: &str, required: TrustLevel) -> TrustResult {
         match self.get_level(agent_id, scope) {
             Some(assignment) => {
                 let permitted = assignment.level >= required;
@@ -231,37 +442,78 @@ impl<S: Storage> TrustManager<S> {
         }
     }
 
-    /// Borrow the underlying storage (read-only).
-    pub fn storage(&self) -> &S {
-        &self.storage
-    }
+    /// Try to satisfy `required` for `(agent_id, scope)` via delegated
+    /// authority. Returns `None` when no threshold is configured (authority
+    /// isn't in use for this pair at all) or `depth_remaining` is exhausted;
+    /// otherwise returns a [`TrustResult`] naming which approvers
+    /// contributed, regardless of whether the threshold was reached.
+    fn resolve_authority(
+        &self,
+        agent_id: &str,
+        scope: &str,
+        required: TrustLevel,
+        depth_remaining: u8,
+    ) -> Option<TrustResult> {
+        if depth_remaining == 0 {
+            return None;
+        }
 
-    /// Mutably borrow the underlying storage.
-    pub fn storage_mut(&mut self) -> &mut S {
-        &mut self.storage
-    }
-}
+        let threshold = self.storage.get_authority_threshold(agent_id, scope)?;
+        let delegations = self.storage.get_trust_delegations(agent_id, scope);
 
-// ---------------------------------------------------------------------------
-// Helpers
-// ---------------------------------------------------------------------------
+        let mut satisfied_weight = 0.0;
+        let mut contributors: Vec<String> = Vec::new();
 
-/// Return current Unix epoch milliseconds.
-///
-/// In `std` mode this delegates to [`std::time::SystemTime`].
-/// In `no_std` mode it returns `0` — the caller is expected to inject time
-/// via `set_level_with_expiry` if expiry semantics are needed.
-fn current_time_ms() -> u64 {
-    #[cfg(feature = "std")]
-    {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64
+        for delegation in &delegations {
+            if delegation.level < required {
+                continue;
+            }
+
+            let satisfied = if self.is_tracked_agent(&delegation.delegator, scope) {
+                self.resolve(&delegation.delegator, scope, required, depth_remaining - 1).permitted
+            } else {
+                // No trust record of its own — an external "owner" identity,
+                // trusted at face value exactly like `set_level`'s `assigned_by`.
+                true
+            };
+
+            if satisfied {
+                satisfied_weight += delegation.weight;
+                contributors.push(delegation.delegator.clone());
+            }
+        }
+
+        let permitted = satisfied_weight >= threshold;
+        let reason = if contributors.is_empty() {
+            format!(
+                "Authority for agent '{}' in scope '{}' has no satisfied approvers (0.00/{:.2} weight required).",
+                agent_id, scope, threshold
+            )
+        } else {
+            format!(
+                "Authority for agent '{}' in scope '{}' satisfied by approver(s) {} ({:.2}/{:.2} weight).",
+                agent_id,
+                scope,
+                contributors.join(", "),
+                satisfied_weight,
+                threshold
+            )
+        };
+
+        Some(TrustResult {
+            permitted,
+            current_level: if permitted { required } else { TrustLevel::Observer },
+            required_level: required,
+            reason,
+        })
     }
-    #[cfg(not(feature = "std"))]
-    {
-        0
+
+    /// Whether `agent_id` has any trust record of its own in `scope` — a
+    /// direct assignment or a configured authority threshold — and therefore
+    /// must have its own trust resolved rather than being trusted at face
+    /// value as an external "owner" identity.
+    fn is_tracked_agent(&self, agent_id: &str, scope: &str) -> bool {
+        self.storage.get_trust(agent_id, scope).is_some()
+            || self.storage.get_authority_threshold(agent_id, scope).is_some()
     }
 }
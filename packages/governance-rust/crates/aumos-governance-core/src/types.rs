@@ -6,7 +6,14 @@
 //! All types implement [`Clone`], [`Debug`], [`serde::Serialize`], and
 //! [`serde::Deserialize`] so they can be serialised to JSON, stored, and
 //! transmitted across WASM boundaries without additional conversion steps.
+//!
+//! Behind the `schema` feature (implies `std`), the types that cross the
+//! WASM JSON boundary (`TrustLevel`, `TrustResult`, `BudgetResult`,
+//! `ConsentResult`, `Context`, `Decision`, `AuditFilter`, `AuditRecord`) also
+//! derive [`schemars::JsonSchema`], so a schema can be generated for JS/TS
+//! consumers without hand-maintaining a parallel type definition.
 
+use alloc::collections::BTreeMap;
 use alloc::string::String;
 use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
@@ -32,6 +39,7 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum TrustLevel {
     /// Read-only observer. No side-effecting actions permitted.
     Observer = 0,
@@ -111,10 +119,67 @@ pub struct TrustAssignment {
     pub expires_at_ms: Option<u64>,
     /// Identity of the party that issued this assignment.
     pub assigned_by: String,
+    /// Ed25519 signatures over this assignment's canonical payload, collected
+    /// toward an [`ApproverSet`](crate::signed_trust::ApproverSet)'s
+    /// `threshold` for quorum-gated levels. Empty for assignments made
+    /// without multi-signature authorization — `assigned_by` alone is the
+    /// authority in that case, exactly as before this field existed.
+    #[serde(default)]
+    pub signatures: Vec<TrustSignature>,
+}
+
+/// One approver's signature over a [`TrustAssignment`]'s canonical payload.
+///
+/// `approver_index` is the signer's position in the
+/// [`ApproverSet`](crate::signed_trust::ApproverSet) it was checked against —
+/// kept as a plain `u8` here (rather than the public key itself) so this type
+/// has no dependency on `ed25519_dalek` and stays available outside the
+/// `signed-trust` feature; [`TrustAssignment::verify`](crate::signed_trust)
+/// is what actually resolves it back to a key and checks the signature.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TrustSignature {
+    /// This signer's index into the [`ApproverSet`](crate::signed_trust::ApproverSet)'s `keys`.
+    pub approver_index: u8,
+    /// Raw 64-byte Ed25519 signature.
+    ///
+    /// `serde`'s derive only covers arrays up to length 32, so this field
+    /// goes through [`signature_bytes`] instead of deriving directly; under
+    /// the `schema` feature it's described as a `Vec<u8>` for the same
+    /// reason (`schemars`' built-in array impls stop at the same length).
+    #[serde(with = "signature_bytes")]
+    #[cfg_attr(feature = "schema", schemars(with = "alloc::vec::Vec<u8>"))]
+    pub signature: [u8; 64],
+}
+
+/// (De)serialises a 64-byte array as a plain byte sequence, since `serde`'s
+/// derive only implements `Serialize`/`Deserialize` for arrays up to length
+/// 32. Used via `#[serde(with = "signature_bytes")]` on
+/// [`TrustSignature::signature`].
+mod signature_bytes {
+    use alloc::vec::Vec;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(bytes: &[u8; 64], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        bytes.as_slice().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<[u8; 64], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = Vec::<u8>::deserialize(deserializer)?;
+        <[u8; 64]>::try_from(bytes.as_slice())
+            .map_err(|_| serde::de::Error::custom("expected a 64-byte Ed25519 signature"))
+    }
 }
 
 /// Result of a [`TrustManager::check_level`] evaluation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TrustResult {
     /// Whether the agent's current level meets or exceeds the required level.
     pub permitted: bool,
@@ -126,6 +191,25 @@ pub struct TrustResult {
     pub reason: String,
 }
 
+/// One weighted vote toward granting `(agent_id, scope)` a trust `level`,
+/// recorded by [`TrustManager::delegate_level`].
+///
+/// A delegation's weight only counts toward its authority's threshold when
+/// [`delegator`](Self::delegator) is itself *satisfied* — see the
+/// "Delegated authority" section of the [`trust`](crate::trust) module
+/// documentation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TrustDelegation {
+    /// Identity casting this vote — an owner, or another agent whose own
+    /// trust must in turn be resolved for this vote to count.
+    pub delegator: String,
+    /// The trust level this vote endorses granting.
+    pub level: TrustLevel,
+    /// This vote's contribution toward the authority's threshold.
+    pub weight: f64,
+}
+
 // ---------------------------------------------------------------------------
 // Budget
 // ---------------------------------------------------------------------------
@@ -135,14 +219,23 @@ pub struct TrustResult {
 /// Managed by [`BudgetManager`] and persisted via the [`Storage`] trait.
 /// Budget allocations are always static — there is no adaptive or ML-based
 /// reallocation.
+///
+/// An envelope may track more than one resource **dimension** at once (e.g.
+/// `"tokens"` and `"usd"` under the same `"llm-calls"` category) — a spend is
+/// only permitted if every dimension it touches fits its own headroom.
+/// Single-dimension callers use the implicit [`Envelope::DEFAULT_DIMENSION`]
+/// and never need to know dimensions exist. `limits`/`spent` use a
+/// [`BTreeMap`] (not `hashbrown`) so iteration order is deterministic — this
+/// matters for picking a stable binding dimension and for stable
+/// serialization.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Envelope {
     /// Logical category this envelope tracks (e.g. "llm-tokens", "financial").
     pub category: String,
-    /// Maximum amount permitted within the current period.
-    pub limit: f64,
-    /// Cumulative amount spent in the current period.
-    pub spent: f64,
+    /// Maximum amount permitted per dimension within the current period.
+    pub limits: BTreeMap<String, f64>,
+    /// Cumulative amount spent per dimension in the current period.
+    pub spent: BTreeMap<String, f64>,
     /// Duration of one budget period in milliseconds.
     pub period_ms: u64,
     /// Unix epoch milliseconds at which the current period began.
@@ -150,19 +243,33 @@ pub struct Envelope {
 }
 
 impl Envelope {
-    /// Amount remaining in this envelope before the limit is reached.
-    pub fn available(&self) -> f64 {
-        (self.limit - self.spent).max(0.0)
+    /// Dimension name used by the single-value convenience constructors.
+    pub const DEFAULT_DIMENSION: &'static str = "default";
+
+    /// Amount remaining in `dimension` before its limit is reached.
+    ///
+    /// A dimension this envelope doesn't track is treated as unbounded
+    /// (`f64::MAX`) rather than zero, matching `Config::pass_on_missing_envelope`'s
+    /// "open budget" convention for an envelope that doesn't exist at all.
+    pub fn available(&self, dimension: &str) -> f64 {
+        match self.limits.get(dimension) {
+            Some(&limit) => (limit - self.spent.get(dimension).copied().unwrap_or(0.0)).max(0.0),
+            None => f64::MAX,
+        }
     }
 
-    /// Whether the given `amount` fits within the remaining headroom.
-    pub fn can_spend(&self, amount: f64) -> bool {
-        self.spent + amount <= self.limit
+    /// Whether `amount` fits within `dimension`'s remaining headroom.
+    pub fn can_spend(&self, dimension: &str, amount: f64) -> bool {
+        match self.limits.get(dimension) {
+            Some(&limit) => self.spent.get(dimension).copied().unwrap_or(0.0) + amount <= limit,
+            None => true,
+        }
     }
 }
 
 /// Result of a [`BudgetManager::check`] evaluation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct BudgetResult {
     /// Whether the requested spend is within the envelope limit.
     pub permitted: bool,
@@ -174,32 +281,44 @@ pub struct BudgetResult {
     pub category: String,
     /// Human-readable explanation of the outcome.
     pub reason: String,
+    /// The first [`ResourceDimension`](crate::metered_budget::ResourceDimension)
+    /// that exhausted a [`MeteredEnvelope`](crate::metered_budget::MeteredEnvelope),
+    /// for results produced by [`BudgetManager::check_metered`](crate::budget::BudgetManager::check_metered).
+    /// `None` for the `f64`/named-dimension family ([`check`](crate::budget::BudgetManager::check),
+    /// [`check_dimensions`](crate::budget::BudgetManager::check_dimensions)), which
+    /// has no typed dimension to report.
+    #[serde(default)]
+    pub dimension: Option<crate::metered_budget::ResourceDimension>,
 }
 
 // ---------------------------------------------------------------------------
 // Consent
 // ---------------------------------------------------------------------------
 
-/// A single recorded consent grant.
+/// A single recorded consent grant, as persisted by the [`Storage`] trait.
 ///
 /// Produced by [`ConsentManager::record`] and invalidated by
-/// [`ConsentManager::revoke`].
+/// [`ConsentManager::revoke`]. Carrying an expiry and a purpose (rather than
+/// a bare `bool`) lets [`ConsentManager::check`] enforce GDPR-style purpose
+/// limitation and automatic lapse without a separate revocation call.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ConsentRecord {
-    /// Stable identifier for the AI agent the consent applies to.
-    pub agent_id: String,
-    /// The data type or action class this consent covers.
-    pub action: String,
-    /// Whether this consent is currently active.
+pub struct ConsentGrant {
+    /// Whether this consent is currently active. `revoke` sets this back to
+    /// `false` rather than removing the entry, so a revoked grant is
+    /// distinguishable from one that was never recorded.
     pub granted: bool,
-    /// Unix epoch milliseconds at which the consent was recorded.
-    pub recorded_at_ms: u64,
-    /// Optional Unix epoch milliseconds after which the consent expires.
-    pub expires_at_ms: Option<u64>,
+    /// Optional Unix epoch milliseconds after which the consent lapses.
+    /// `check` treats an expired grant the same as no grant at all.
+    pub expiry_ms: Option<u64>,
+    /// Optional purpose this consent was scoped to (e.g. "fraud_detection").
+    /// When set, `check` denies any request whose purpose doesn't match
+    /// exactly, including a request that specifies no purpose at all.
+    pub purpose: Option<String>,
 }
 
 /// Result of a [`ConsentManager::check`] evaluation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ConsentResult {
     /// Whether active consent exists for the given agent and action.
     pub permitted: bool,
@@ -217,25 +336,53 @@ pub struct ConsentResult {
 /// The chain is recording-only — there is no anomaly detection or
 /// counterfactual generation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AuditRecord {
     /// Unique record identifier (hex string of the record hash).
     pub id: String,
     /// The governance decision that was made.
     pub decision: Decision,
-    /// SHA-256 hex digest of the serialised `decision` field.
+    /// SHA-256 hex digest of the serialised `decision` field, chained with
+    /// `prev_hash` so any retroactive edit breaks the link.
     pub hash: String,
     /// Hash of the immediately preceding record, or an all-zero string for the
     /// genesis record.
     pub prev_hash: String,
     /// Unix epoch milliseconds at which the record was appended.
     pub timestamp_ms: u64,
+    /// Detached signature over `hash`, hex-encoded. `None` when the storage
+    /// backend is not configured with a signing key — the hash chain alone
+    /// still detects tampering, but signatures additionally bind the chain to
+    /// a specific signer.
+    #[serde(default)]
+    pub signature: Option<String>,
+    /// Copy of `decision.agent_id`, duplicated onto the record itself so a
+    /// [`Storage`](crate::storage::Storage) backend can index agent-scoped
+    /// queries without deserialising `decision`.
+    #[serde(default)]
+    pub agent_id: String,
+    /// Copy of `decision.scope`, duplicated for the same reason as
+    /// `agent_id`.
+    #[serde(default)]
+    pub scope: String,
+    /// Copy of `decision.policy_epoch`, duplicated for the same reason as
+    /// `agent_id`.
+    #[serde(default)]
+    pub policy_epoch: u64,
+    /// Copy of `decision.policy_hash`, duplicated for the same reason as
+    /// `agent_id`.
+    #[serde(default)]
+    pub policy_hash: String,
 }
 
 /// Filter used to narrow the results of [`AuditLogger::query`].
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AuditFilter {
     /// If set, only return records for this agent.
     pub agent_id: Option<String>,
+    /// If set, only return records whose `scope` matches exactly.
+    pub scope: Option<String>,
     /// If set, only return records where `decision.action` matches exactly.
     pub action: Option<String>,
     /// If set, only return records at or after this Unix epoch millisecond.
@@ -252,6 +399,7 @@ pub struct AuditFilter {
 
 /// The action submitted to [`GovernanceEngine::check`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Context {
     /// Stable identifier for the AI agent requesting the action.
     pub agent_id: String,
@@ -274,6 +422,7 @@ pub struct Context {
 /// The sequential evaluation pipeline always produces exactly one `Decision`.
 /// All decisions — both permits and denials — are appended to the audit log.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Decision {
     /// `true` if all governance checks passed; `false` on the first failure.
     pub permitted: bool,
@@ -285,10 +434,27 @@ pub struct Decision {
     pub consent: ConsentResult,
     /// Human-readable name of the action that was evaluated.
     pub action: String,
+    /// Stable identifier of the agent the decision was evaluated for, copied
+    /// from `Context::agent_id`.
+    pub agent_id: String,
+    /// Scope label the decision was evaluated under, copied from
+    /// `Context::scope`. Used to restrict which viewers may see this record
+    /// in a role-scoped audit query.
+    pub scope: String,
     /// Unix epoch milliseconds at which the decision was produced.
     pub timestamp_ms: u64,
     /// The gate that produced the final verdict, or "PERMIT" on success.
     pub reason: String,
+    /// [`PolicySet::epoch`](crate::policy::PolicySet::epoch) in force when
+    /// this decision was produced. `0` for a decision evaluated without a
+    /// [`PolicySet`](crate::policy::PolicySet) wired in.
+    #[serde(default)]
+    pub policy_epoch: u64,
+    /// [`PolicySet::hash`](crate::policy::PolicySet::hash) in force when
+    /// this decision was produced. Empty for a decision evaluated without a
+    /// [`PolicySet`](crate::policy::PolicySet) wired in.
+    #[serde(default)]
+    pub policy_hash: String,
 }
 
 /// Collect audit records into a [`Vec`] for return from query operations.
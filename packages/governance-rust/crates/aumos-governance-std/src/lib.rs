@@ -7,7 +7,10 @@
 //!
 //! This crate provides [`FileStorage`], a JSON file-backed implementation of
 //! the [`Storage`] trait suitable for CLI tools, local agents, and server-side
-//! deployments that do not need a full database.
+//! deployments that do not need a full database, and
+//! [`ReplicatedStorage`](storage::replicated::ReplicatedStorage), which
+//! shards and replicates state across a zone-aware ring of other `Storage`
+//! backends for larger, multi-node deployments.
 //!
 //! ## Quick Start
 //!
@@ -24,3 +27,4 @@
 pub mod storage;
 
 pub use storage::file::FileStorage;
+pub use storage::replicated::ReplicatedStorage;
@@ -9,17 +9,38 @@
 //!
 //! ## Layout
 //!
-//! The JSON file has the shape:
+//! The JSON file is a versioned envelope wrapping the snapshot:
 //!
 //! ```json
 //! {
-//!   "trust":     { "<agent_id>:<scope>": TrustAssignment, ... },
-//!   "envelopes": { "<category>":         Envelope,         ... },
-//!   "consent":   { "<agent_id>:<action>": true | false,    ... },
-//!   "audit":     [ AuditRecord, ... ]
+//!   "schema_version": 2,
+//!   "data": {
+//!     "trust":     { "<agent_id>:<scope>": TrustAssignment, ... },
+//!     "envelopes": { "<category>":         Envelope,         ... },
+//!     "consent":   { "<agent_id>:<action>": ConsentGrant,    ... },
+//!     "delegation": { "<delegate>:<action>": "<grantor agent id>", ... },
+//!     "authority": { "<principal>:<op>:<scope>": true | false, ... },
+//!     "trust_delegations": { "<agent_id>:<scope>": [TrustDelegation, ...], ... },
+//!     "authority_thresholds": { "<agent_id>:<scope>": 2.0, ... },
+//!     "audit":     [ AuditRecord, ... ]
+//!   }
 //! }
 //! ```
 //!
+//! ## Schema migrations
+//!
+//! Files written before the envelope existed have no `schema_version` key;
+//! these are treated as schema version `0` and upgraded on open (see
+//! [`storage::migrations`](super::migrations)). [`FileStorage::open`] and
+//! [`FileStorage::open_with_signing_key`] both migrate using the built-in
+//! chain; [`FileStorage::open_with_migrations`] accepts a caller-supplied
+//! chain instead. Whenever a migration actually runs, the pre-migration file
+//! is preserved beside it with its extension replaced by `.bak`
+//! (`governance.json` → `governance.bak`) before the upgraded snapshot is
+//! written back.
+//! Opening a file whose `schema_version` is newer than
+//! [`migrations::CURRENT_SCHEMA_VERSION`] fails loudly rather than guessing.
+//!
 //! ## Caveats
 //!
 //! * [`FileStorage`] holds the full in-memory state and flushes on every
@@ -27,22 +48,54 @@
 //! * Concurrent access from multiple processes is not supported.  Use a
 //!   proper database-backed storage implementation for multi-process
 //!   deployments.
+//!
+//! ## Tamper evidence
+//!
+//! Every appended [`AuditRecord`] is chained to its predecessor via
+//! `prev_hash`/`hash` (see `aumos_governance_core::audit`). [`FileStorage::open`]
+//! walks the stored chain and recomputes each link, failing with
+//! [`io::ErrorKind::InvalidData`] if any record has been edited, reordered, or
+//! removed from the front or middle of the log. Records cut from the end of
+//! the log leave a shorter chain that still verifies.
+//!
+//! Operators may additionally configure an Ed25519 signing key via
+//! [`FileStorage::open_with_signing_key`]. When set, every appended record
+//! carries a detached signature over its `hash`, and the corresponding
+//! verifying key is stored in the snapshot so `open` can re-verify every
+//! signature as well as the hash chain itself.
 
 use std::collections::HashMap;
 use std::io;
 use std::path::{Path, PathBuf};
 
 use aumos_governance_core::storage::Storage;
-use aumos_governance_core::types::{AuditFilter, AuditRecord, Envelope, TrustAssignment};
+use aumos_governance_core::types::{
+    AuditFilter, AuditRecord, ConsentGrant, Envelope, TrustAssignment, TrustDelegation,
+};
+use ed25519_dalek::{Signer, Verifier, SigningKey, VerifyingKey, Signature};
 use serde::{Deserialize, Serialize};
 
+use super::migrations::{self, Migration};
+
 /// Snapshot of all governance state, serialised to / deserialised from disk.
 #[derive(Debug, Default, Serialize, Deserialize)]
 struct StorageSnapshot {
     trust:     HashMap<String, TrustAssignment>,
     envelopes: HashMap<String, Envelope>,
-    consent:   HashMap<String, bool>,
+    consent:   HashMap<String, ConsentGrant>,
+    #[serde(default)]
+    delegation: HashMap<String, String>,
+    #[serde(default)]
+    authority: HashMap<String, bool>,
+    #[serde(default)]
+    trust_delegations: HashMap<String, Vec<TrustDelegation>>,
+    #[serde(default)]
+    authority_thresholds: HashMap<String, f64>,
     audit:     Vec<AuditRecord>,
+    /// Hex-encoded Ed25519 verifying key used to check `AuditRecord::signature`
+    /// on every record, if signing was configured when the file was written.
+    #[serde(default)]
+    verifying_key: Option<String>,
 }
 
 /// A file-backed [`Storage`] implementation that persists state as JSON.
@@ -52,41 +105,116 @@ struct StorageSnapshot {
 /// ```rust,no_run
 /// use aumos_governance_std::storage::file::FileStorage;
 /// use aumos_governance_core::Storage;
+/// use aumos_governance_core::types::ConsentGrant;
 ///
 /// let mut storage = FileStorage::open("/tmp/governance.json")
 ///     .expect("could not open storage");
 ///
-/// storage.set_consent("agent-001", "read_pii", true);
-/// assert!(storage.get_consent("agent-001", "read_pii"));
+/// storage.set_consent("agent-001", "read_pii", ConsentGrant { granted: true, expiry_ms: None, purpose: None });
+/// assert!(storage.get_consent("agent-001", "read_pii").unwrap().granted);
 /// ```
 pub struct FileStorage {
     path: PathBuf,
     data: StorageSnapshot,
+    /// Ed25519 signing key used to sign newly appended records. `None` when
+    /// the store was opened without [`FileStorage::open_with_signing_key`].
+    signing_key: Option<SigningKey>,
 }
 
 impl FileStorage {
     /// Open an existing JSON storage file, or create a new empty one if the
     /// path does not exist.
     ///
+    /// The stored hash chain is verified on open: every record's `hash` is
+    /// recomputed from its `prev_hash` and decision payload, and any mismatch
+    /// (edited, reordered, or removed records, other than records cut from
+    /// the end of the log) fails with
+    /// [`io::ErrorKind::InvalidData`]. If the snapshot carries a
+    /// `verifying_key`, every record's detached signature is checked as well.
+    ///
     /// # Errors
     ///
-    /// Returns an [`io::Error`] if the file exists but cannot be read or if
-    /// the JSON is malformed.
+    /// Returns an [`io::Error`] if the file exists but cannot be read, if the
+    /// JSON is malformed, or if chain/signature verification fails.
     pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::open_inner(path, None)
+    }
+
+    /// Like [`open`](Self::open), but also configures an Ed25519 signing key
+    /// for subsequently appended audit records.
+    ///
+    /// The corresponding verifying key is written into the snapshot so future
+    /// calls to `open` (with or without a signing key) can verify every
+    /// record's signature. Changing the signing key between opens is
+    /// detected: existing signatures verify against the *stored* verifying
+    /// key, not a newly supplied one.
+    pub fn open_with_signing_key<P: AsRef<Path>>(
+        path: P,
+        signing_key: SigningKey,
+    ) -> io::Result<Self> {
+        Self::open_inner(path, Some(signing_key))
+    }
+
+    /// Like [`open`](Self::open), but runs the on-disk file through
+    /// `migrations` instead of the built-in chain ([`migrations::BUILTIN_MIGRATIONS`]).
+    ///
+    /// Use this when a consumer crate has registered its own migrators ahead
+    /// of a schema change that hasn't shipped in `aumos-governance-std` yet.
+    pub fn open_with_migrations<P: AsRef<Path>>(
+        path: P,
+        migrations: &[Migration],
+    ) -> io::Result<Self> {
+        Self::open_inner_with_migrations(path, None, migrations)
+    }
+
+    /// The schema version this build of `FileStorage` writes and fully
+    /// understands. See [`migrations::CURRENT_SCHEMA_VERSION`].
+    pub fn current_schema_version() -> u32 {
+        migrations::CURRENT_SCHEMA_VERSION
+    }
+
+    fn open_inner<P: AsRef<Path>>(path: P, signing_key: Option<SigningKey>) -> io::Result<Self> {
+        Self::open_inner_with_migrations(path, signing_key, migrations::BUILTIN_MIGRATIONS)
+    }
+
+    fn open_inner_with_migrations<P: AsRef<Path>>(
+        path: P,
+        signing_key: Option<SigningKey>,
+        migration_chain: &[Migration],
+    ) -> io::Result<Self> {
         let path = path.as_ref().to_path_buf();
-        let data = if path.exists() {
+        let mut data: StorageSnapshot = if path.exists() {
             let raw = std::fs::read_to_string(&path)?;
-            serde_json::from_str(&raw).map_err(|error| {
+            let (value, migrated) = migrations::load_and_migrate(&raw, migration_chain)?;
+            let snapshot: StorageSnapshot = serde_json::from_value(value).map_err(|error| {
                 io::Error::new(
                     io::ErrorKind::InvalidData,
                     format!("governance storage JSON parse error: {}", error),
                 )
-            })?
+            })?;
+
+            if migrated {
+                // Preserve the pre-migration bytes before the upgraded
+                // snapshot overwrites the file, then persist the upgrade
+                // immediately so later opens see the current schema version.
+                std::fs::write(path.with_extension("bak"), &raw)?;
+                migrations::write_versioned_atomic(&path, &snapshot)?;
+            }
+
+            snapshot
         } else {
             StorageSnapshot::default()
         };
 
-        Ok(Self { path, data })
+        if data.verifying_key.is_none() {
+            if let Some(ref key) = signing_key {
+                data.verifying_key = Some(hex_encode(key.verifying_key().as_bytes()));
+            }
+        }
+
+        verify_audit_chain(&data)?;
+
+        Ok(Self { path, data, signing_key })
     }
 
     /// Flush the current in-memory state to disk using an atomic write-rename.
@@ -99,17 +227,7 @@ impl FileStorage {
     /// Returns an [`io::Error`] if serialisation fails or the file cannot be
     /// written or renamed.
     pub fn flush(&self) -> io::Result<()> {
-        let json = serde_json::to_string_pretty(&self.data).map_err(|error| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("governance storage serialisation error: {}", error),
-            )
-        })?;
-
-        let tmp_path = self.path.with_extension("tmp");
-        std::fs::write(&tmp_path, json)?;
-        std::fs::rename(&tmp_path, &self.path)?;
-        Ok(())
+        migrations::write_versioned_atomic(&self.path, &self.data)
     }
 
     /// Composite key used for both trust and consent maps.
@@ -118,6 +236,113 @@ impl FileStorage {
     }
 }
 
+/// Recompute the hash chain (and, if configured, verify every detached
+/// signature) over `snapshot.audit`.
+///
+/// Returns an [`io::ErrorKind::InvalidData`] error naming the first record
+/// whose `prev_hash`/`hash` link or signature fails to verify. Run
+/// automatically by [`FileStorage::open`]. This is a superset of
+/// [`Storage::verify_chain`](aumos_governance_core::storage::Storage::verify_chain)
+/// — it also checks detached signatures and reports a descriptive error
+/// rather than a bare index — for callers who want signature verification at
+/// load time; `Storage::verify_chain` is the lighter, signature-agnostic
+/// check callers can run on demand at any point in a `FileStorage`'s life.
+fn verify_audit_chain(snapshot: &StorageSnapshot) -> io::Result<()> {
+    let verifying_key = match &snapshot.verifying_key {
+        Some(hex) => Some(decode_verifying_key(hex)?),
+        None => None,
+    };
+
+    let mut expected_prev_hash = "0".repeat(64);
+
+    for (index, record) in snapshot.audit.iter().enumerate() {
+        if record.prev_hash != expected_prev_hash {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "audit chain broken at record {} ({}): prev_hash mismatch",
+                    index, record.id
+                ),
+            ));
+        }
+
+        let recomputed = aumos_governance_core::audit::recompute_hash(&record.decision, &record.prev_hash);
+        if recomputed != record.hash {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "audit chain broken at record {} ({}): hash does not match its decision payload",
+                    index, record.id
+                ),
+            ));
+        }
+
+        if let Some(ref key) = verifying_key {
+            let signature_hex = record.signature.as_deref().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "audit chain broken at record {} ({}): missing signature but a verifying key is configured",
+                        index, record.id
+                    ),
+                )
+            })?;
+            let signature = decode_signature(signature_hex)?;
+            key.verify(record.hash.as_bytes(), &signature).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "audit chain broken at record {} ({}): signature verification failed",
+                        index, record.id
+                    ),
+                )
+            })?;
+        }
+
+        expected_prev_hash = record.hash.clone();
+    }
+
+    Ok(())
+}
+
+fn decode_verifying_key(hex: &str) -> io::Result<VerifyingKey> {
+    let bytes = hex_decode(hex)?;
+    let array: [u8; 32] = bytes.as_slice().try_into().map_err(|_| {
+        io::Error::new(io::ErrorKind::InvalidData, "verifying key must be 32 bytes")
+    })?;
+    VerifyingKey::from_bytes(&array)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error.to_string()))
+}
+
+fn decode_signature(hex: &str) -> io::Result<Signature> {
+    let bytes = hex_decode(hex)?;
+    let array: [u8; 64] = bytes.as_slice().try_into().map_err(|_| {
+        io::Error::new(io::ErrorKind::InvalidData, "signature must be 64 bytes")
+    })?;
+    Ok(Signature::from_bytes(&array))
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        out.push_str(&format!("{:02x}", byte));
+    }
+    out
+}
+
+fn hex_decode(hex: &str) -> io::Result<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "odd-length hex string"));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid hex digit"))
+        })
+        .collect()
+}
+
 impl Storage for FileStorage {
     fn get_trust(&self, agent_id: &str, scope: &str) -> Option<TrustAssignment> {
         let key = Self::composite_key(agent_id, scope);
@@ -141,18 +366,70 @@ impl Storage for FileStorage {
         let _ = self.flush();
     }
 
-    fn get_consent(&self, agent_id: &str, action: &str) -> bool {
+    fn get_consent(&self, agent_id: &str, action: &str) -> Option<ConsentGrant> {
         let key = Self::composite_key(agent_id, action);
-        self.data.consent.get(&key).copied().unwrap_or(false)
+        self.data.consent.get(&key).cloned()
     }
 
-    fn set_consent(&mut self, agent_id: &str, action: &str, granted: bool) {
+    fn set_consent(&mut self, agent_id: &str, action: &str, grant: ConsentGrant) {
         let key = Self::composite_key(agent_id, action);
-        self.data.consent.insert(key, granted);
+        self.data.consent.insert(key, grant);
         let _ = self.flush();
     }
 
-    fn append_audit(&mut self, record: AuditRecord) {
+    fn get_delegation(&self, delegate: &str, action: &str) -> Option<String> {
+        let key = Self::composite_key(delegate, action);
+        self.data.delegation.get(&key).cloned()
+    }
+
+    fn set_delegation(&mut self, delegate: &str, action: &str, grantor: &str) {
+        let key = Self::composite_key(delegate, action);
+        self.data.delegation.insert(key, grantor.to_string());
+        let _ = self.flush();
+    }
+
+    fn remove_delegation(&mut self, delegate: &str, action: &str) {
+        let key = Self::composite_key(delegate, action);
+        self.data.delegation.remove(&key);
+        let _ = self.flush();
+    }
+
+    fn get_authority(&self, key: &str) -> bool {
+        self.data.authority.get(key).copied().unwrap_or(false)
+    }
+
+    fn set_authority(&mut self, key: &str, granted: bool) {
+        self.data.authority.insert(key.to_string(), granted);
+        let _ = self.flush();
+    }
+
+    fn get_trust_delegations(&self, agent_id: &str, scope: &str) -> Vec<TrustDelegation> {
+        let key = Self::composite_key(agent_id, scope);
+        self.data.trust_delegations.get(&key).cloned().unwrap_or_default()
+    }
+
+    fn add_trust_delegation(&mut self, agent_id: &str, scope: &str, delegation: TrustDelegation) {
+        let key = Self::composite_key(agent_id, scope);
+        self.data.trust_delegations.entry(key).or_default().push(delegation);
+        let _ = self.flush();
+    }
+
+    fn get_authority_threshold(&self, agent_id: &str, scope: &str) -> Option<f64> {
+        let key = Self::composite_key(agent_id, scope);
+        self.data.authority_thresholds.get(&key).copied()
+    }
+
+    fn set_authority_threshold(&mut self, agent_id: &str, scope: &str, threshold: f64) {
+        let key = Self::composite_key(agent_id, scope);
+        self.data.authority_thresholds.insert(key, threshold);
+        let _ = self.flush();
+    }
+
+    fn append_audit(&mut self, mut record: AuditRecord) {
+        if let Some(ref key) = self.signing_key {
+            let signature: Signature = key.sign(record.hash.as_bytes());
+            record.signature = Some(hex_encode(&signature.to_bytes()));
+        }
         self.data.audit.push(record);
         let _ = self.flush();
     }
@@ -163,7 +440,12 @@ impl Storage for FileStorage {
             .iter()
             .filter(|record| {
                 if let Some(ref agent_id) = filter.agent_id {
-                    if !record.id.starts_with(agent_id.as_str()) {
+                    if &record.agent_id != agent_id {
+                        return false;
+                    }
+                }
+                if let Some(ref scope) = filter.scope {
+                    if &record.scope != scope {
                         return false;
                     }
                 }
@@ -188,4 +470,23 @@ impl Storage for FileStorage {
             .cloned()
             .collect()
     }
+
+    fn verify_chain(&self) -> Result<(), usize> {
+        let mut expected_prev_hash = "0".repeat(64);
+
+        for (index, record) in self.data.audit.iter().enumerate() {
+            if record.prev_hash != expected_prev_hash {
+                return Err(index);
+            }
+
+            let recomputed = aumos_governance_core::audit::recompute_hash(&record.decision, &record.prev_hash);
+            if recomputed != record.hash {
+                return Err(index);
+            }
+
+            expected_prev_hash = record.hash.clone();
+        }
+
+        Ok(())
+    }
 }
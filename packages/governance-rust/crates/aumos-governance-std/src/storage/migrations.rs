@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! Versioned on-disk format and migration runner for [`FileStorage`](super::file::FileStorage).
+//!
+//! Every file `FileStorage` writes is wrapped in an envelope
+//! `{"schema_version": N, "data": {...}}`, so a future change to the
+//! persisted layout can be applied by an ordered, one-way migrator rather
+//! than silently failing to deserialise an older file.
+//!
+//! Files written before this envelope existed have no `schema_version` key
+//! at all -- these are treated as schema version `0`, and
+//! [`BUILTIN_MIGRATIONS[0]`](BUILTIN_MIGRATIONS) upgrades them to version 1
+//! (version 1's `data` payload is byte-for-byte the same as the legacy flat snapshot, so
+//! that first migrator is the identity function). Version 1 stored each
+//! `consent` entry as a bare `true`/`false`; [`BUILTIN_MIGRATIONS[1]`](BUILTIN_MIGRATIONS)
+//! upgrades version 1 to 2 by wrapping each flag in a `ConsentGrant` with no
+//! expiry or purpose, so existing grants keep behaving exactly as before.
+
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// One-way upgrade from schema version `i` to `i + 1`. Migrators operate on
+/// the `data` payload only, not the `schema_version` wrapper.
+pub type Migration = fn(serde_json::Value) -> io::Result<serde_json::Value>;
+
+/// The schema version this binary writes and fully understands. Opening a
+/// file stamped with a higher version fails loudly rather than guessing.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// Built-in migration chain. Entry `i` upgrades schema version `i` to `i + 1`.
+pub const BUILTIN_MIGRATIONS: &[Migration] = &[migrate_v0_to_v1, migrate_v1_to_v2];
+
+/// Legacy (pre-versioning) files serialised the snapshot directly as the
+/// file's top-level object, which is exactly version 1's `data` payload.
+fn migrate_v0_to_v1(legacy_data: serde_json::Value) -> io::Result<serde_json::Value> {
+    Ok(legacy_data)
+}
+
+/// Upgrade each `consent` entry from a bare `true`/`false` flag to a
+/// `ConsentGrant` object with no expiry or purpose, preserving its meaning.
+fn migrate_v1_to_v2(mut data: serde_json::Value) -> io::Result<serde_json::Value> {
+    if let Some(consent) = data.get_mut("consent").and_then(|value| value.as_object_mut()) {
+        for grant in consent.values_mut() {
+            if let Some(granted) = grant.as_bool() {
+                *grant = serde_json::json!({
+                    "granted": granted,
+                    "expiry_ms": null,
+                    "purpose": null,
+                });
+            }
+        }
+    }
+    Ok(data)
+}
+
+/// The versioned on-disk envelope.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VersionedEnvelope {
+    pub schema_version: u32,
+    pub data: serde_json::Value,
+}
+
+/// Parse `raw` as either a legacy unversioned snapshot or a
+/// [`VersionedEnvelope`], and run the result through `migrations` up to
+/// [`CURRENT_SCHEMA_VERSION`].
+///
+/// Returns the migrated `data` payload and whether any migration actually
+/// ran, so the caller can decide whether to keep a `.bak` of the
+/// pre-migration file before rewriting it.
+///
+/// # Errors
+///
+/// Fails with [`io::ErrorKind::InvalidData`] if `raw` is not valid JSON, the
+/// envelope is malformed, the stored version is newer than
+/// [`CURRENT_SCHEMA_VERSION`], or no migrator is registered for a version
+/// found along the way.
+pub fn load_and_migrate(raw: &str, migrations: &[Migration]) -> io::Result<(serde_json::Value, bool)> {
+    let value: serde_json::Value = serde_json::from_str(raw).map_err(|error| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("governance storage JSON parse error: {}", error),
+        )
+    })?;
+
+    let (mut version, mut data) = match &value {
+        serde_json::Value::Object(map) if map.contains_key("schema_version") => {
+            let envelope: VersionedEnvelope = serde_json::from_value(value).map_err(|error| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("malformed schema envelope: {}", error),
+                )
+            })?;
+            (envelope.schema_version, envelope.data)
+        }
+        _ => (0, value),
+    };
+
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "governance storage file is schema version {}, but this binary only understands up to version {}",
+                version, CURRENT_SCHEMA_VERSION
+            ),
+        ));
+    }
+
+    let migrated = version != CURRENT_SCHEMA_VERSION;
+    while version < CURRENT_SCHEMA_VERSION {
+        let migrate = migrations.get(version as usize).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("no migration registered to upgrade schema version {}", version),
+            )
+        })?;
+        data = migrate(data)?;
+        version += 1;
+    }
+
+    Ok((data, migrated))
+}
+
+/// Write `data` (already at [`CURRENT_SCHEMA_VERSION`]) to `path` as a
+/// versioned envelope, atomically via temp-file + rename.
+pub fn write_versioned_atomic<T: Serialize>(path: &Path, data: &T) -> io::Result<()> {
+    let envelope = VersionedEnvelope {
+        schema_version: CURRENT_SCHEMA_VERSION,
+        data: serde_json::to_value(data).map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("governance storage serialisation error: {}", error),
+            )
+        })?,
+    };
+    let json = serde_json::to_string_pretty(&envelope).map_err(|error| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("governance storage serialisation error: {}", error),
+        )
+    })?;
+
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, json)?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
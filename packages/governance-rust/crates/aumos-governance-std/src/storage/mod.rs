@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! Storage backend implementations.
+
+pub mod file;
+pub mod migrations;
+pub mod replicated;
@@ -0,0 +1,492 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! Zone-aware, replicated [`Storage`] backend.
+//!
+//! [`ReplicatedStorage`] shards governance state across a fixed ring of
+//! partitions and replicates each partition to `N` distinct backend nodes,
+//! reusing [`Storage`] itself as the per-node interface — any existing
+//! backend (e.g. [`FileStorage`](super::file::FileStorage), or another
+//! `ReplicatedStorage`) can serve as a replica.
+//!
+//! ## Partition assignment
+//!
+//! [`LayoutPlanner::plan`] assigns each of `P` partitions to `N` nodes using
+//! weighted rendezvous hashing (HRW): for partition `p` and node `n`, a
+//! deterministic score is derived from `hash(p, n.id)` and `n.capacity`, and
+//! the `N` highest-scoring nodes are chosen, preferring to spread the
+//! selection across distinct [`Zone`]s before repeating one. Because each
+//! node's score is computed independently of every other node, adding or
+//! removing a node only changes the scores (and therefore the winners) for
+//! partitions that node itself was competing in — every other partition's
+//! assignment is untouched. This gives the "reassign the minimum number of
+//! partition slots needed to rebalance" property for free, without a
+//! separate incremental algorithm: `plan` is a pure function of the current
+//! node list, and [`PartitionTable::diff`] lets an operator compare it
+//! against the table currently in use before committing to it.
+//!
+//! ## Reads and writes
+//!
+//! A key (e.g. `"trust:agent-001:scope"`) is hashed to a partition, which
+//! resolves to an ordered list of replica node ids. Reads walk that list: a
+//! keyed lookup returns the first value any reachable replica holds, while
+//! authority flags, delegation votes and the audit log are read from the
+//! first reachable replica alone. Writes fan out to every
+//! reachable replica in the list. The audit log is treated as a single
+//! logical key so the whole chain is replicated and read back consistently.
+
+use std::collections::{HashMap, HashSet};
+
+use aumos_governance_core::storage::Storage;
+use aumos_governance_core::types::{
+    AuditFilter, AuditRecord, ConsentGrant, Envelope, TrustAssignment, TrustDelegation,
+};
+
+/// Opaque identifier for a backend node, e.g. `"node-us-east-1a-03"`.
+pub type NodeId = String;
+
+/// Opaque availability-zone identifier, e.g. `"us-east-1a"`.
+pub type Zone = String;
+
+/// Synthetic routing key the audit log is sharded under. The log is a single
+/// ordered chain, not a keyed value, so it is replicated as one logical
+/// entity rather than split across the ring.
+const AUDIT_KEY: &str = "__audit_log__";
+
+// ---------------------------------------------------------------------------
+// NodeSpec
+// ---------------------------------------------------------------------------
+
+/// A backend node's declared identity, availability zone, and relative
+/// capacity, as supplied to [`LayoutPlanner::plan`].
+#[derive(Debug, Clone)]
+pub struct NodeSpec {
+    /// Stable identifier used to look the node up in a [`ReplicatedStorage`].
+    pub id: NodeId,
+    /// Availability zone the node runs in. The planner spreads a
+    /// partition's replicas across as many distinct zones as it can.
+    pub zone: Zone,
+    /// Relative capacity weight. Nodes with higher capacity win more
+    /// partitions on average; the scale is arbitrary (only ratios matter).
+    pub capacity: f64,
+}
+
+// ---------------------------------------------------------------------------
+// PartitionTable
+// ---------------------------------------------------------------------------
+
+/// The output of [`LayoutPlanner::plan`]: for each partition index, the
+/// ordered list of replica node ids responsible for it (first entry is
+/// queried first on reads).
+#[derive(Debug, Clone, Default)]
+pub struct PartitionTable {
+    partitions: Vec<Vec<NodeId>>,
+}
+
+impl PartitionTable {
+    /// Number of partitions in the ring.
+    pub fn partition_count(&self) -> usize {
+        self.partitions.len()
+    }
+
+    /// The replica node ids responsible for `partition`, in priority order.
+    ///
+    /// Returns an empty slice if `partition` is out of range.
+    pub fn replicas(&self, partition: usize) -> &[NodeId] {
+        self.partitions.get(partition).map_or(&[], |replicas| replicas.as_slice())
+    }
+
+    /// Compare this table against `other`, reporting which partitions were
+    /// reassigned and how many individual replica slots changed.
+    ///
+    /// Intended to let an operator preview the churn a re-[`plan`](LayoutPlanner::plan)
+    /// would cause before adopting it.
+    pub fn diff(&self, other: &PartitionTable) -> LayoutDiff {
+        let count = self.partition_count().max(other.partition_count());
+        let mut changed_partitions = Vec::new();
+        let mut reassigned_replica_count = 0;
+
+        for partition in 0..count {
+            let before: HashSet<&NodeId> = self.replicas(partition).iter().collect();
+            let after: HashSet<&NodeId> = other.replicas(partition).iter().collect();
+            if before != after {
+                changed_partitions.push(partition);
+                reassigned_replica_count += before.symmetric_difference(&after).count();
+            }
+        }
+
+        LayoutDiff {
+            changed_partitions,
+            reassigned_replica_count,
+        }
+    }
+}
+
+/// Summary of how two [`PartitionTable`]s differ, returned by
+/// [`PartitionTable::diff`].
+#[derive(Debug, Clone, Default)]
+pub struct LayoutDiff {
+    /// Indices of partitions whose replica set changed at all.
+    pub changed_partitions: Vec<usize>,
+    /// Total number of replica slots added or removed across every changed
+    /// partition — a rough measure of how much data would need to move.
+    pub reassigned_replica_count: usize,
+}
+
+impl LayoutDiff {
+    /// Whether adopting the new table would move any data at all.
+    pub fn is_empty(&self) -> bool {
+        self.changed_partitions.is_empty()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// LayoutPlanner
+// ---------------------------------------------------------------------------
+
+/// Produces a [`PartitionTable`] for a fixed-size partition ring and
+/// replication factor.
+///
+/// # Examples
+///
+/// ```rust
+/// use aumos_governance_std::storage::replicated::{LayoutPlanner, NodeSpec};
+///
+/// let nodes = vec![
+///     NodeSpec { id: "a".into(), zone: "us-east-1a".into(), capacity: 1.0 },
+///     NodeSpec { id: "b".into(), zone: "us-east-1b".into(), capacity: 1.0 },
+///     NodeSpec { id: "c".into(), zone: "us-east-1c".into(), capacity: 1.0 },
+/// ];
+///
+/// let planner = LayoutPlanner::new(256, 2);
+/// let table = planner.plan(&nodes);
+/// assert_eq!(table.partition_count(), 256);
+/// assert_eq!(table.replicas(0).len(), 2);
+///
+/// // Removing a node only disturbs the partitions it was serving.
+/// let fewer_nodes = vec![nodes[0].clone(), nodes[1].clone()];
+/// let new_table = planner.plan(&fewer_nodes);
+/// let diff = table.diff(&new_table);
+/// assert!(diff.changed_partitions.len() <= table.partition_count());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LayoutPlanner {
+    partitions: usize,
+    replication_factor: usize,
+}
+
+impl LayoutPlanner {
+    /// Create a planner for a ring of `partitions` slots, each replicated to
+    /// `replication_factor` nodes (fewer, if there aren't enough nodes).
+    pub fn new(partitions: usize, replication_factor: usize) -> Self {
+        Self {
+            partitions,
+            replication_factor,
+        }
+    }
+
+    /// Assign every partition to up to `replication_factor` of `nodes`,
+    /// spreading replicas across distinct zones where possible.
+    pub fn plan(&self, nodes: &[NodeSpec]) -> PartitionTable {
+        let target = self.replication_factor.min(nodes.len());
+        let mut partitions = Vec::with_capacity(self.partitions);
+
+        for partition in 0..self.partitions {
+            let mut by_score: Vec<&NodeSpec> = nodes.iter().collect();
+            by_score.sort_by(|a, b| {
+                let score_a = rendezvous_score(partition, a);
+                let score_b = rendezvous_score(partition, b);
+                score_b
+                    .partial_cmp(&score_a)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| a.id.cmp(&b.id))
+            });
+
+            let mut replicas = Vec::with_capacity(target);
+            let mut used_zones = HashSet::new();
+
+            // First pass: prefer a node from a zone not yet represented.
+            for node in &by_score {
+                if replicas.len() == target {
+                    break;
+                }
+                if used_zones.insert(node.zone.clone()) {
+                    replicas.push(node.id.clone());
+                }
+            }
+
+            // Second pass: not enough distinct zones to fill every slot —
+            // take the next highest-scoring nodes regardless of zone.
+            if replicas.len() < target {
+                for node in &by_score {
+                    if replicas.len() == target {
+                        break;
+                    }
+                    if !replicas.contains(&node.id) {
+                        replicas.push(node.id.clone());
+                    }
+                }
+            }
+
+            partitions.push(replicas);
+        }
+
+        PartitionTable { partitions }
+    }
+}
+
+/// Weighted rendezvous (HRW) score for `node` competing for `partition`.
+///
+/// Higher is better. Computed independently per `(partition, node)` pair so
+/// that adding or removing an unrelated node never changes this score.
+fn rendezvous_score(partition: usize, node: &NodeSpec) -> f64 {
+    let hash = hash_partition_node(partition, &node.id);
+    // Normalise to the open interval (0, 1) so `ln` is finite and negative.
+    let unit = ((hash as f64) + 1.0) / ((u64::MAX as f64) + 2.0);
+    node.capacity.max(f64::MIN_POSITIVE) / -unit.ln()
+}
+
+/// Deterministic FNV-1a 64-bit hash over `(partition, node_id)`. Kept local
+/// and dependency-free since the ring only needs a stable, uniform hash, not
+/// a cryptographic one.
+fn hash_partition_node(partition: usize, node_id: &str) -> u64 {
+    const FNV_OFFSET: u64 = 14_695_981_039_346_656_037;
+    const FNV_PRIME: u64 = 1_099_511_628_211;
+
+    let mut hash = FNV_OFFSET;
+    for byte in partition.to_le_bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    for byte in node_id.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Hash an arbitrary storage key down to a partition index in `0..partitions`.
+fn partition_for_key(key: &str, partitions: usize) -> usize {
+    if partitions == 0 {
+        return 0;
+    }
+    (hash_partition_node(0, key) % partitions as u64) as usize
+}
+
+// ---------------------------------------------------------------------------
+// ReplicatedStorage
+// ---------------------------------------------------------------------------
+
+/// A [`Storage`] implementation that shards and replicates its state across
+/// other [`Storage`] backends, according to a [`PartitionTable`].
+///
+/// # Examples
+///
+/// ```rust
+/// use aumos_governance_std::storage::replicated::{LayoutPlanner, NodeSpec, ReplicatedStorage};
+/// use aumos_governance_core::storage::{InMemoryStorage, Storage};
+/// use aumos_governance_core::types::{TrustAssignment, TrustLevel};
+/// use std::collections::HashMap;
+///
+/// let nodes_spec = vec![
+///     NodeSpec { id: "a".into(), zone: "us-east-1a".into(), capacity: 1.0 },
+///     NodeSpec { id: "b".into(), zone: "us-east-1b".into(), capacity: 1.0 },
+/// ];
+/// let table = LayoutPlanner::new(256, 2).plan(&nodes_spec);
+///
+/// let mut nodes = HashMap::new();
+/// nodes.insert("a".to_string(), InMemoryStorage::new());
+/// nodes.insert("b".to_string(), InMemoryStorage::new());
+///
+/// let mut storage = ReplicatedStorage::new(nodes, table);
+/// storage.set_trust("agent-001", "scope", TrustAssignment {
+///     agent_id: "agent-001".into(),
+///     level: TrustLevel::Monitor,
+///     scope: "scope".into(),
+///     assigned_at_ms: 0,
+///     expires_at_ms: None,
+///     assigned_by: "owner".into(),
+///     signatures: Vec::new(),
+/// });
+/// assert!(storage.get_trust("agent-001", "scope").is_some());
+/// ```
+pub struct ReplicatedStorage<S: Storage> {
+    nodes: HashMap<NodeId, S>,
+    table: PartitionTable,
+}
+
+impl<S: Storage> ReplicatedStorage<S> {
+    /// Create a [`ReplicatedStorage`] over `nodes`, routed by `table`.
+    ///
+    /// `table` is typically produced by [`LayoutPlanner::plan`] over the
+    /// same node ids present in `nodes`; a replica id in `table` with no
+    /// matching entry in `nodes` is simply treated as unreachable.
+    pub fn new(nodes: HashMap<NodeId, S>, table: PartitionTable) -> Self {
+        Self { nodes, table }
+    }
+
+    /// Borrow the current partition table.
+    pub fn table(&self) -> &PartitionTable {
+        &self.table
+    }
+
+    /// Adopt a new partition table (e.g. after a topology change), without
+    /// moving any data. Callers responsible for a real migration should
+    /// copy data between old and new replicas for any partition named in
+    /// `self.table().diff(&new_table)` before calling this.
+    pub fn set_table(&mut self, table: PartitionTable) {
+        self.table = table;
+    }
+
+    fn replicas_for(&self, key: &str) -> &[NodeId] {
+        let partition = partition_for_key(key, self.table.partition_count());
+        self.table.replicas(partition)
+    }
+}
+
+impl<S: Storage> Storage for ReplicatedStorage<S> {
+    fn get_trust(&self, agent_id: &str, scope: &str) -> Option<TrustAssignment> {
+        let key = format!("trust:{}:{}", agent_id, scope);
+        self.replicas_for(&key)
+            .iter()
+            .find_map(|node_id| self.nodes.get(node_id)?.get_trust(agent_id, scope))
+    }
+
+    fn set_trust(&mut self, agent_id: &str, scope: &str, assignment: TrustAssignment) {
+        let key = format!("trust:{}:{}", agent_id, scope);
+        for node_id in self.replicas_for(&key).to_vec() {
+            if let Some(node) = self.nodes.get_mut(&node_id) {
+                node.set_trust(agent_id, scope, assignment.clone());
+            }
+        }
+    }
+
+    fn get_envelope(&self, category: &str) -> Option<Envelope> {
+        let key = format!("envelope:{}", category);
+        self.replicas_for(&key)
+            .iter()
+            .find_map(|node_id| self.nodes.get(node_id)?.get_envelope(category))
+    }
+
+    fn set_envelope(&mut self, category: &str, envelope: Envelope) {
+        let key = format!("envelope:{}", category);
+        for node_id in self.replicas_for(&key).to_vec() {
+            if let Some(node) = self.nodes.get_mut(&node_id) {
+                node.set_envelope(category, envelope.clone());
+            }
+        }
+    }
+
+    fn get_consent(&self, agent_id: &str, action: &str) -> Option<ConsentGrant> {
+        let key = format!("consent:{}:{}", agent_id, action);
+        self.replicas_for(&key)
+            .iter()
+            .find_map(|node_id| self.nodes.get(node_id)?.get_consent(agent_id, action))
+    }
+
+    fn set_consent(&mut self, agent_id: &str, action: &str, grant: ConsentGrant) {
+        let key = format!("consent:{}:{}", agent_id, action);
+        for node_id in self.replicas_for(&key).to_vec() {
+            if let Some(node) = self.nodes.get_mut(&node_id) {
+                node.set_consent(agent_id, action, grant.clone());
+            }
+        }
+    }
+
+    fn get_delegation(&self, delegate: &str, action: &str) -> Option<String> {
+        let key = format!("delegation:{}:{}", delegate, action);
+        self.replicas_for(&key)
+            .iter()
+            .find_map(|node_id| self.nodes.get(node_id)?.get_delegation(delegate, action))
+    }
+
+    fn set_delegation(&mut self, delegate: &str, action: &str, grantor: &str) {
+        let key = format!("delegation:{}:{}", delegate, action);
+        for node_id in self.replicas_for(&key).to_vec() {
+            if let Some(node) = self.nodes.get_mut(&node_id) {
+                node.set_delegation(delegate, action, grantor);
+            }
+        }
+    }
+
+    fn remove_delegation(&mut self, delegate: &str, action: &str) {
+        let key = format!("delegation:{}:{}", delegate, action);
+        for node_id in self.replicas_for(&key).to_vec() {
+            if let Some(node) = self.nodes.get_mut(&node_id) {
+                node.remove_delegation(delegate, action);
+            }
+        }
+    }
+
+    fn get_authority(&self, key: &str) -> bool {
+        let routing_key = format!("authority:{}", key);
+        self.replicas_for(&routing_key)
+            .iter()
+            .find_map(|node_id| self.nodes.get(node_id))
+            .is_some_and(|node| node.get_authority(key))
+    }
+
+    fn set_authority(&mut self, key: &str, granted: bool) {
+        let routing_key = format!("authority:{}", key);
+        for node_id in self.replicas_for(&routing_key).to_vec() {
+            if let Some(node) = self.nodes.get_mut(&node_id) {
+                node.set_authority(key, granted);
+            }
+        }
+    }
+
+    fn get_trust_delegations(&self, agent_id: &str, scope: &str) -> Vec<TrustDelegation> {
+        let key = format!("trust_delegation:{}:{}", agent_id, scope);
+        self.replicas_for(&key)
+            .iter()
+            .find_map(|node_id| self.nodes.get(node_id))
+            .map_or_else(Vec::new, |node| node.get_trust_delegations(agent_id, scope))
+    }
+
+    fn add_trust_delegation(&mut self, agent_id: &str, scope: &str, delegation: TrustDelegation) {
+        let key = format!("trust_delegation:{}:{}", agent_id, scope);
+        for node_id in self.replicas_for(&key).to_vec() {
+            if let Some(node) = self.nodes.get_mut(&node_id) {
+                node.add_trust_delegation(agent_id, scope, delegation.clone());
+            }
+        }
+    }
+
+    fn get_authority_threshold(&self, agent_id: &str, scope: &str) -> Option<f64> {
+        let key = format!("authority_threshold:{}:{}", agent_id, scope);
+        self.replicas_for(&key)
+            .iter()
+            .find_map(|node_id| self.nodes.get(node_id)?.get_authority_threshold(agent_id, scope))
+    }
+
+    fn set_authority_threshold(&mut self, agent_id: &str, scope: &str, threshold: f64) {
+        let key = format!("authority_threshold:{}:{}", agent_id, scope);
+        for node_id in self.replicas_for(&key).to_vec() {
+            if let Some(node) = self.nodes.get_mut(&node_id) {
+                node.set_authority_threshold(agent_id, scope, threshold);
+            }
+        }
+    }
+
+    fn append_audit(&mut self, record: AuditRecord) {
+        for node_id in self.replicas_for(AUDIT_KEY).to_vec() {
+            if let Some(node) = self.nodes.get_mut(&node_id) {
+                node.append_audit(record.clone());
+            }
+        }
+    }
+
+    fn query_audit(&self, filter: &AuditFilter) -> Vec<AuditRecord> {
+        self.replicas_for(AUDIT_KEY)
+            .iter()
+            .find_map(|node_id| self.nodes.get(node_id))
+            .map_or_else(Vec::new, |node| node.query_audit(filter))
+    }
+
+    fn verify_chain(&self) -> Result<(), usize> {
+        self.replicas_for(AUDIT_KEY)
+            .iter()
+            .find_map(|node_id| self.nodes.get(node_id))
+            .map_or(Ok(()), |node| node.verify_chain())
+    }
+}
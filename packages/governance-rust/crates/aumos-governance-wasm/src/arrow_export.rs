@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: BSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! Arrow IPC columnar export of the audit trail.
+//!
+//! Only compiled when the `arrow-export` feature is enabled. The per-record
+//! JSON returned by `get_audit_trail`/`query_audit` is cheap to produce but
+//! slow to parse at scale -- tens of thousands of `AuditRecord`s means tens
+//! of thousands of heap allocations on the JS side. This module flattens the
+//! filtered trail into a single Arrow `RecordBatch` and serialises it as an
+//! Arrow IPC stream, so a JS host can hand the raw bytes straight to
+//! Arrow-JS or DuckDB-WASM and query them without per-record parsing.
+
+use std::sync::Arc;
+
+use arrow::array::{BooleanArray, Float64Array, StringArray, TimestampMillisecondArray};
+use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow::ipc::writer::StreamWriter;
+use arrow::record_batch::RecordBatch;
+
+use aumos_governance_core::types::AuditRecord;
+
+/// Exact reason text the engine's budget gate stamps on a skipped check (see
+/// `skipped_budget_result` in `aumos-governance-core::engine`), used here to
+/// decide whether a record's `cost` column is null rather than `0.0`.
+const BUDGET_SKIPPED_REASON: &str = "Budget gate skipped (no cost specified).";
+
+/// The fixed columnar schema written by [`encode`] and described (as JSON)
+/// by `audit_arrow_schema` in `lib.rs`.
+pub fn schema() -> Schema {
+    Schema::new(vec![
+        Field::new("timestamp", DataType::Timestamp(TimeUnit::Millisecond, None), false),
+        Field::new("agent_id", DataType::Utf8, false),
+        Field::new("action", DataType::Utf8, false),
+        Field::new("scope", DataType::Utf8, false),
+        Field::new("permitted", DataType::Boolean, false),
+        Field::new("reason", DataType::Utf8, false),
+        Field::new("cost", DataType::Float64, true),
+    ])
+}
+
+/// Encode `records` as a single-batch Arrow IPC stream.
+///
+/// Returns an empty `Vec<u8>` if `records` is empty (an empty `RecordBatch`
+/// still carries a valid schema, but there is nothing to query; callers
+/// should treat an empty result the same way as `get_audit_trail`'s `"[]"`).
+pub fn encode(records: &[AuditRecord]) -> Vec<u8> {
+    if records.is_empty() {
+        return Vec::new();
+    }
+
+    let schema = Arc::new(schema());
+
+    let timestamps: Vec<i64> = records.iter().map(|record| record.timestamp_ms as i64).collect();
+    let agent_ids: Vec<&str> = records.iter().map(|record| record.decision.agent_id.as_str()).collect();
+    let actions: Vec<&str> = records.iter().map(|record| record.decision.action.as_str()).collect();
+    let scopes: Vec<&str> = records.iter().map(|record| record.decision.scope.as_str()).collect();
+    let permitted: Vec<bool> = records.iter().map(|record| record.decision.permitted).collect();
+    let reasons: Vec<&str> = records.iter().map(|record| record.decision.reason.as_str()).collect();
+    let costs: Vec<Option<f64>> = records
+        .iter()
+        .map(|record| {
+            if record.decision.budget.reason == BUDGET_SKIPPED_REASON {
+                None
+            } else {
+                Some(record.decision.budget.requested)
+            }
+        })
+        .collect();
+
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(TimestampMillisecondArray::from(timestamps)),
+            Arc::new(StringArray::from(agent_ids)),
+            Arc::new(StringArray::from(actions)),
+            Arc::new(StringArray::from(scopes)),
+            Arc::new(BooleanArray::from(permitted)),
+            Arc::new(StringArray::from(reasons)),
+            Arc::new(Float64Array::from(costs)),
+        ],
+    )
+    .expect("record batch columns are built from the fixed schema above");
+
+    let mut buffer = Vec::new();
+    {
+        let mut writer = StreamWriter::try_new(&mut buffer, &schema)
+            .expect("schema constructed above is always valid for the IPC writer");
+        writer.write(&batch).expect("batch matches the writer's own schema");
+        writer.finish().expect("finishing an in-memory stream writer cannot fail");
+    }
+    buffer
+}
@@ -29,9 +29,25 @@
 //! | `create_budget`             | Create a spending envelope                             |
 //! | `record_consent`            | Record a consent grant                                 |
 //! | `revoke_consent`            | Revoke a consent grant                                 |
+//! | `load_consent_policy`       | Install a declarative consent policy from a JSON rule list |
 //! | `get_audit_trail`           | Return the full audit trail as a JSON array            |
 //! | `query_audit`               | Query the audit trail with a JSON filter               |
+//! | `export_provenance`         | Export the audit trail as a W3C PROV-JSON document     |
+//! | `get_audit_root`            | Merkle root over the audit trail's record hashes       |
+//! | `get_audit_inclusion_proof` | Compact Merkle proof that one record is in the trail   |
 //! | `destroy_engine`            | Release an engine handle and free its memory           |
+//! | `get_schema`                | JSON Schema (draft 2020-12) for one boundary type      |
+//! | `get_all_schemas`           | JSON object of every boundary type's schema             |
+//! | `drain_telemetry`           | Drain buffered metrics/spans as OTLP-JSON (`telemetry` feature) |
+//! | `export_audit_arrow`        | Audit trail as an Arrow IPC stream (`arrow-export` feature) |
+//! | `audit_arrow_schema`        | JSON descriptor of `export_audit_arrow`'s fixed schema  |
+//!
+//! Behind the `telemetry` feature, every [`evaluate`] call records a span
+//! (action, agent, outcome, which gate decided it) and increments counters
+//! (`evaluations_total`, `denials_by_reason`, `budget_rejections`) -- see the
+//! [`telemetry`] module. There is no background exporter; call
+//! [`drain_telemetry`] to pull the buffered data out as an OTLP-JSON payload
+//! and POST it to a collector yourself.
 //!
 //! ## JavaScript Usage
 //!
@@ -60,7 +76,7 @@
 //! console.log('Trusted:', trusted); // true
 //!
 //! // Quick budget check
-//! const affordable = check_budget(handle, 'financial', 50.0);
+//! const affordable = check_budget(handle, 'financial', 50.0, Date.now());
 //! console.log('Affordable:', affordable); // true
 //!
 //! // Full governance evaluation
@@ -84,14 +100,24 @@
 
 use aumos_governance_core::{
     config::Config,
+    consent::{ConsentRule, DeclarativePolicy},
     engine::GovernanceEngine,
     storage::InMemoryStorage,
-    types::{AuditFilter, Context, TrustLevel},
+    types::{AuditFilter, Context, Decision, TrustLevel},
 };
 use std::cell::RefCell;
 use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 
+#[cfg(feature = "telemetry")]
+pub mod telemetry;
+
+#[cfg(feature = "arrow-export")]
+pub mod arrow_export;
+
+#[cfg(feature = "telemetry")]
+use js_sys::Date;
+
 // ---------------------------------------------------------------------------
 // Engine registry
 // ---------------------------------------------------------------------------
@@ -101,6 +127,8 @@ thread_local! {
     static ENGINES: RefCell<HashMap<u32, GovernanceEngine<InMemoryStorage>>> =
         RefCell::new(HashMap::new());
     static NEXT_HANDLE: RefCell<u32> = RefCell::new(0);
+    #[cfg(feature = "telemetry")]
+    static TELEMETRY: RefCell<HashMap<u32, telemetry::Telemetry>> = RefCell::new(HashMap::new());
 }
 
 /// Allocate a new engine handle. Handles wrap around at `u32::MAX - 1` to
@@ -158,6 +186,10 @@ pub fn create_engine() -> u32 {
     ENGINES.with(|engines| {
         engines.borrow_mut().insert(handle, engine);
     });
+    #[cfg(feature = "telemetry")]
+    TELEMETRY.with(|registry| {
+        registry.borrow_mut().insert(handle, telemetry::Telemetry::default());
+    });
     handle
 }
 
@@ -185,6 +217,10 @@ pub fn create_engine_with_config(config_json: &str) -> u32 {
     ENGINES.with(|engines| {
         engines.borrow_mut().insert(handle, engine);
     });
+    #[cfg(feature = "telemetry")]
+    TELEMETRY.with(|registry| {
+        registry.borrow_mut().insert(handle, telemetry::Telemetry::default());
+    });
     handle
 }
 
@@ -196,6 +232,10 @@ pub fn destroy_engine(handle: u32) {
     ENGINES.with(|engines| {
         engines.borrow_mut().remove(&handle);
     });
+    #[cfg(feature = "telemetry")]
+    TELEMETRY.with(|registry| {
+        registry.borrow_mut().remove(&handle);
+    });
 }
 
 // ---------------------------------------------------------------------------
@@ -269,14 +309,15 @@ pub fn create_budget(
     });
 }
 
-/// Check whether `amount` fits within the remaining headroom for `envelope_id`.
+/// Check whether `amount` fits within the remaining headroom for `envelope_id`
+/// as of `now_ms` (the caller supplies the clock, e.g. `Date.now()` from JS).
 ///
 /// Returns `false` if the handle is unknown or the envelope does not exist
 /// and the engine is in strict mode.
 #[wasm_bindgen]
-pub fn check_budget(handle: u32, envelope_id: &str, amount: f64) -> bool {
+pub fn check_budget(handle: u32, envelope_id: &str, amount: f64, now_ms: f64) -> bool {
     with_engine(handle, |engine| {
-        let result = engine.budget.check(envelope_id, amount);
+        let result = engine.budget.check(envelope_id, amount, now_ms as u64);
         result.permitted
     })
     .unwrap_or(false)
@@ -290,7 +331,7 @@ pub fn check_budget(handle: u32, envelope_id: &str, amount: f64) -> bool {
 #[wasm_bindgen]
 pub fn record_consent(handle: u32, agent_id: &str, action: &str) {
     let _ = with_engine_mut(handle, |engine| {
-        engine.consent.record(agent_id, action);
+        engine.consent.record(agent_id, action, None, None);
     });
 }
 
@@ -302,6 +343,41 @@ pub fn revoke_consent(handle: u32, agent_id: &str, action: &str) {
     });
 }
 
+/// Install a [`DeclarativePolicy`] built from `rules_json` into the engine's
+/// consent manager, so conditional consent can be expressed as data instead
+/// of a recompiled Rust policy.
+///
+/// `rules_json` must be a JSON array matching the [`ConsentRule`] shape:
+///
+/// ```json
+/// [
+///   {
+///     "action": "read_pii",
+///     "purpose": "support",
+///     "scope": "eu",
+///     "data_type": null,
+///     "verdict": "Allow"
+///   }
+/// ]
+/// ```
+///
+/// `verdict` is either the string `"Allow"`, the string `"NotApplicable"`,
+/// or an object `{"Deny": "reason"}`. Rules are evaluated in array order;
+/// the first match wins. The policy is appended after any policies already
+/// installed on this handle. Returns `false` on parse error or unknown
+/// handle, `true` otherwise.
+#[wasm_bindgen]
+pub fn load_consent_policy(handle: u32, rules_json: &str) -> bool {
+    let rules: Vec<ConsentRule> = match serde_json::from_str(rules_json) {
+        Ok(rules) => rules,
+        Err(_) => return false,
+    };
+    with_engine_mut(handle, |engine| {
+        engine.consent.add_policy(DeclarativePolicy::new(rules));
+    })
+    .is_ok()
+}
+
 // ---------------------------------------------------------------------------
 // Governance evaluation
 // ---------------------------------------------------------------------------
@@ -320,12 +396,29 @@ pub fn evaluate(handle: u32, action: &str, action_json: &str) -> String {
         }
     };
 
-    match with_engine_mut(handle, |engine| {
-        let decision = engine.check(action, &context);
-        serde_json::to_string(&decision)
-            .unwrap_or_else(|error| format!("{{\"error\":\"serialisation error: {}\"}}", error))
-    }) {
-        Ok(json) => json,
+    #[cfg(feature = "telemetry")]
+    let start_ms = Date::now();
+
+    match with_engine_mut(handle, |engine| engine.check(action, &context)) {
+        Ok(decision) => {
+            #[cfg(feature = "telemetry")]
+            {
+                let end_ms = Date::now();
+                TELEMETRY.with(|registry| {
+                    if let Some(telemetry) = registry.borrow_mut().get_mut(&handle) {
+                        telemetry.record_evaluation(
+                            action,
+                            &context.agent_id,
+                            &decision,
+                            start_ms,
+                            end_ms,
+                        );
+                    }
+                });
+            }
+            serde_json::to_string(&decision)
+                .unwrap_or_else(|error| format!("{{\"error\":\"serialisation error: {}\"}}", error))
+        }
         Err(error) => format!("{{\"error\":\"{}\"}}", error),
     }
 }
@@ -365,6 +458,164 @@ pub fn query_audit(handle: u32, filter_json: &str) -> String {
     .unwrap_or_else(|_| "[]".into())
 }
 
+/// Return the audit trail's current Merkle root (see [`AuditLogger::root`]),
+/// hex-encoded. Returns 64 zeros for an unknown handle or an empty trail.
+#[wasm_bindgen]
+pub fn get_audit_root(handle: u32) -> String {
+    with_engine(handle, |engine| engine.audit.root()).unwrap_or_else(|_| "0".repeat(64))
+}
+
+/// Build a compact [`InclusionProof`] for `record_id` and return it
+/// JSON-serialised, so a verifier holding only [`get_audit_root`]'s output
+/// and the record in question can confirm it via [`audit::verify`] without
+/// the rest of the trail.
+///
+/// Returns `"null"` if `record_id` doesn't exist or the handle is unknown.
+#[wasm_bindgen]
+pub fn get_audit_inclusion_proof(handle: u32, record_id: &str) -> String {
+    with_engine(handle, |engine| {
+        engine
+            .audit
+            .prove(record_id)
+            .and_then(|proof| serde_json::to_string(&proof).ok())
+            .unwrap_or_else(|| "null".into())
+    })
+    .unwrap_or_else(|_| "null".into())
+}
+
+/// Export the audit trail as a W3C PROV-JSON document, so downstream
+/// provenance/compliance tooling can ingest governance decisions as a
+/// standard graph instead of the bespoke [`AuditRecord`] shape.
+///
+/// Delegates to [`AuditLogger::export_prov`](aumos_governance_core::audit::AuditLogger::export_prov)
+/// — see that module's docs for the exact PROV-JSON shape (each record
+/// becomes a `prov:Activity`/`prov:Agent`/`prov:Entity` triple, with
+/// consecutive records additionally linked via `wasDerivedFrom` carrying
+/// the audit chain's own `prev_hash`/`hash`). This binding used to build
+/// its own, independent PROV-JSON tree with a different node-ID scheme;
+/// that produced a structurally different document for the same audit log
+/// depending on which side exported it, so it now defers entirely to the
+/// one core implementation.
+///
+/// `filter_json` must be a JSON string matching the [`AuditFilter`] shape
+/// (see [`query_audit`]); pass `"{}"` to export the whole trail. Returns
+/// `{"error":"..."}` for an unknown handle or a serialisation failure;
+/// [`get_audit_trail`] and [`query_audit`] are unaffected.
+#[wasm_bindgen]
+pub fn export_provenance(handle: u32, filter_json: &str) -> String {
+    let filter: AuditFilter = serde_json::from_str(filter_json).unwrap_or_default();
+
+    let doc = match with_engine(handle, |engine| engine.audit.export_prov(&filter)) {
+        Ok(doc) => doc,
+        Err(error) => return format!("{{\"error\":\"{}\"}}", error),
+    };
+
+    doc.to_prov_json()
+        .unwrap_or_else(|error| format!("{{\"error\":\"{}\"}}", error))
+}
+
+/// Encode the filtered audit trail as a single-batch Arrow IPC stream, for
+/// zero-copy-ish transfer into Arrow-JS or DuckDB-WASM.
+///
+/// `filter_json` must be a JSON string matching the [`AuditFilter`] shape
+/// (see [`query_audit`]); pass `"{}"` to export the whole trail. The column
+/// layout is fixed -- see [`audit_arrow_schema`]. Returns an empty byte
+/// array for an unknown handle or an empty filtered trail.
+#[cfg(feature = "arrow-export")]
+#[wasm_bindgen]
+pub fn export_audit_arrow(handle: u32, filter_json: &str) -> Vec<u8> {
+    let filter: AuditFilter = serde_json::from_str(filter_json).unwrap_or_default();
+    with_engine(handle, |engine| {
+        let records = engine.audit.query(&filter);
+        arrow_export::encode(&records)
+    })
+    .unwrap_or_default()
+}
+
+/// Return [`export_audit_arrow`]'s fixed Arrow schema as a JSON array of
+/// `{name, type, nullable}` descriptors, so a JS host can validate (or
+/// auto-generate) a matching Arrow-JS schema without hand-maintaining it.
+#[cfg(feature = "arrow-export")]
+#[wasm_bindgen]
+pub fn audit_arrow_schema() -> String {
+    let schema = arrow_export::schema();
+    let fields: Vec<serde_json::Value> = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            serde_json::json!({
+                "name": field.name(),
+                "type": format!("{:?}", field.data_type()),
+                "nullable": field.is_nullable(),
+            })
+        })
+        .collect();
+    serde_json::Value::Array(fields).to_string()
+}
+
+// ---------------------------------------------------------------------------
+// Telemetry
+// ---------------------------------------------------------------------------
+
+/// Drain the buffered metrics and spans accumulated by [`evaluate`] calls on
+/// `handle` since the last drain, and return them as an OTLP-JSON payload.
+///
+/// Only available under the `telemetry` feature. Resets the counters and
+/// span buffer to empty, so repeated polling never double-counts. Returns
+/// `{"error":"..."}` for an unknown handle.
+#[cfg(feature = "telemetry")]
+#[wasm_bindgen]
+pub fn drain_telemetry(handle: u32) -> String {
+    TELEMETRY.with(|registry| {
+        match registry.borrow_mut().get_mut(&handle) {
+            Some(telemetry) => telemetry.drain(),
+            None => format!("{{\"error\":\"unknown engine handle {}\"}}", handle),
+        }
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Schema export
+// ---------------------------------------------------------------------------
+
+/// The boundary types a JS/TS consumer can request a schema for, in the
+/// order [`get_all_schemas`] emits them.
+const SCHEMA_TYPES: &[&str] = &["Context", "Config", "AuditFilter", "Decision"];
+
+/// Return the JSON Schema (draft 2020-12) for one JSON-boundary type, so
+/// consumers can validate a payload (or auto-generate a `.d.ts` type) before
+/// ever calling [`evaluate`] or [`create_engine_with_config`].
+///
+/// `type_name` must be one of `"Context"`, `"Config"`, `"AuditFilter"`, or
+/// `"Decision"`. Returns `{"error":"..."}` for an unrecognised name.
+#[wasm_bindgen]
+pub fn get_schema(type_name: &str) -> String {
+    let schema = match type_name {
+        "Context" => serde_json::to_value(schemars::schema_for!(Context)),
+        "Config" => serde_json::to_value(schemars::schema_for!(Config)),
+        "AuditFilter" => serde_json::to_value(schemars::schema_for!(AuditFilter)),
+        "Decision" => serde_json::to_value(schemars::schema_for!(Decision)),
+        _ => return format!("{{\"error\":\"unknown schema type '{}'\"}}", type_name),
+    };
+    match schema {
+        Ok(value) => value.to_string(),
+        Err(error) => format!("{{\"error\":\"schema serialisation error: {}\"}}", error),
+    }
+}
+
+/// Return every boundary type's JSON Schema as a single JSON object keyed by
+/// type name (see [`SCHEMA_TYPES`]), for build-time `.d.ts` generation.
+#[wasm_bindgen]
+pub fn get_all_schemas() -> String {
+    let mut schemas = serde_json::Map::new();
+    for &type_name in SCHEMA_TYPES {
+        if let Ok(schema) = serde_json::from_str(&get_schema(type_name)) {
+            schemas.insert(type_name.to_string(), schema);
+        }
+    }
+    serde_json::Value::Object(schemas).to_string()
+}
+
 // ---------------------------------------------------------------------------
 // wasm-bindgen-test stubs
 // ---------------------------------------------------------------------------
@@ -416,8 +667,8 @@ mod wasm_tests {
         let handle = create_engine();
         create_budget(handle, "financial", 500.0, 0, 0);
 
-        assert!(check_budget(handle, "financial", 250.0));
-        assert!(!check_budget(handle, "financial", 501.0));
+        assert!(check_budget(handle, "financial", 250.0, 0.0));
+        assert!(!check_budget(handle, "financial", 501.0, 0.0));
 
         destroy_engine(handle);
     }
@@ -504,8 +755,8 @@ mod native_tests {
     fn test_budget_check() {
         let handle = create_engine();
         create_budget(handle, "tokens", 100.0, 0, 0);
-        assert!(check_budget(handle, "tokens", 99.0));
-        assert!(!check_budget(handle, "tokens", 101.0));
+        assert!(check_budget(handle, "tokens", 99.0, 0.0));
+        assert!(!check_budget(handle, "tokens", 101.0, 0.0));
         destroy_engine(handle);
     }
 
@@ -524,4 +775,204 @@ mod native_tests {
         assert_eq!(trail, "[]");
         destroy_engine(handle);
     }
+
+    #[test]
+    fn test_get_schema_returns_valid_json_schema() {
+        for type_name in ["Context", "Config", "AuditFilter", "Decision"] {
+            let schema = get_schema(type_name);
+            let value: serde_json::Value =
+                serde_json::from_str(&schema).expect("schema should be valid JSON");
+            assert!(value.get("$schema").is_some(), "missing $schema for {type_name}");
+        }
+    }
+
+    #[test]
+    fn test_get_schema_unknown_type_returns_error() {
+        let schema = get_schema("NotARealType");
+        assert!(schema.contains("error"));
+    }
+
+    #[test]
+    fn test_get_all_schemas_includes_every_boundary_type() {
+        let schemas = get_all_schemas();
+        let value: serde_json::Value =
+            serde_json::from_str(&schemas).expect("schemas should be valid JSON");
+        for type_name in SCHEMA_TYPES {
+            assert!(value.get(*type_name).is_some(), "missing schema for {type_name}");
+        }
+    }
+
+    #[test]
+    fn test_export_provenance_shape() {
+        let handle = create_engine();
+        set_trust_level(handle, "agent-001", "default", 3, "owner");
+
+        let context_json = r#"{
+            "agent_id":       "agent-001",
+            "scope":          "default",
+            "required_trust": "Suggest",
+            "cost":           null,
+            "category":       "default",
+            "data_type":      null,
+            "purpose":        null
+        }"#;
+        let _ = evaluate(handle, "test_action", context_json);
+
+        let prov = export_provenance(handle, "{}");
+        let value: serde_json::Value =
+            serde_json::from_str(&prov).expect("provenance export should be valid JSON");
+
+        assert_eq!(value["activity"].as_object().unwrap().len(), 1);
+        assert_eq!(value["agent"].as_object().unwrap().len(), 1);
+        assert_eq!(value["entity"].as_object().unwrap().len(), 4); // decision + trust + budget + consent
+        assert_eq!(value["wasGeneratedBy"].as_object().unwrap().len(), 1);
+        assert_eq!(value["wasAssociatedWith"].as_object().unwrap().len(), 1);
+        assert_eq!(value["wasAttributedTo"].as_object().unwrap().len(), 1);
+        assert_eq!(value["used"].as_object().unwrap().len(), 3);
+
+        destroy_engine(handle);
+    }
+
+    #[test]
+    fn test_export_provenance_ids_are_deterministic() {
+        let handle = create_engine();
+        set_trust_level(handle, "agent-001", "default", 3, "owner");
+
+        let context_json = r#"{
+            "agent_id":       "agent-001",
+            "scope":          "default",
+            "required_trust": "Suggest",
+            "cost":           null,
+            "category":       "default",
+            "data_type":      null,
+            "purpose":        null
+        }"#;
+        let _ = evaluate(handle, "test_action", context_json);
+
+        let first = export_provenance(handle, "{}");
+        let second = export_provenance(handle, "{}");
+        assert_eq!(first, second);
+
+        destroy_engine(handle);
+    }
+
+    #[test]
+    fn test_export_provenance_unknown_handle_returns_error() {
+        let result = export_provenance(99999, "{}");
+        assert!(result.contains("error"));
+    }
+
+    #[cfg(feature = "telemetry")]
+    #[test]
+    fn test_drain_telemetry_counts_permits_and_denials() {
+        let handle = create_engine();
+        set_trust_level(handle, "agent-001", "default", 3, "owner");
+
+        let permit_json = r#"{
+            "agent_id":       "agent-001",
+            "scope":          "default",
+            "required_trust": "Suggest",
+            "cost":           null,
+            "category":       "default",
+            "data_type":      null,
+            "purpose":        null
+        }"#;
+        let _ = evaluate(handle, "ok_action", permit_json);
+
+        let deny_json = r#"{
+            "agent_id":       "agent-001",
+            "scope":          "default",
+            "required_trust": "Autonomous",
+            "cost":           null,
+            "category":       "default",
+            "data_type":      null,
+            "purpose":        null
+        }"#;
+        let _ = evaluate(handle, "denied_action", deny_json);
+
+        let payload = drain_telemetry(handle);
+        let value: serde_json::Value =
+            serde_json::from_str(&payload).expect("telemetry payload should be valid JSON");
+
+        let metrics = value["scopeMetrics"][0]["metrics"].as_array().unwrap();
+        let evaluations_total = metrics
+            .iter()
+            .find(|m| m["name"] == "governance.evaluations_total")
+            .unwrap();
+        assert_eq!(evaluations_total["sum"]["dataPoints"][0]["asInt"], "2");
+
+        let spans = value["scopeSpans"][0]["spans"].as_array().unwrap();
+        assert_eq!(spans.len(), 2);
+
+        // Draining again resets the counters.
+        let second = drain_telemetry(handle);
+        let second_value: serde_json::Value = serde_json::from_str(&second).unwrap();
+        let second_metrics = second_value["scopeMetrics"][0]["metrics"].as_array().unwrap();
+        let second_total = second_metrics
+            .iter()
+            .find(|m| m["name"] == "governance.evaluations_total")
+            .unwrap();
+        assert_eq!(second_total["sum"]["dataPoints"][0]["asInt"], "0");
+
+        destroy_engine(handle);
+    }
+
+    #[cfg(feature = "telemetry")]
+    #[test]
+    fn test_drain_telemetry_unknown_handle_returns_error() {
+        let result = drain_telemetry(99999);
+        assert!(result.contains("error"));
+    }
+
+    #[cfg(feature = "arrow-export")]
+    #[test]
+    fn test_export_audit_arrow_produces_nonempty_stream() {
+        let handle = create_engine();
+        set_trust_level(handle, "agent-001", "default", 3, "owner");
+
+        let context_json = r#"{
+            "agent_id":       "agent-001",
+            "scope":          "default",
+            "required_trust": "Suggest",
+            "cost":           null,
+            "category":       "default",
+            "data_type":      null,
+            "purpose":        null
+        }"#;
+        let _ = evaluate(handle, "test_action", context_json);
+
+        let bytes = export_audit_arrow(handle, "{}");
+        assert!(!bytes.is_empty());
+        // Arrow IPC streams start with a continuation marker (0xFFFFFFFF).
+        assert_eq!(&bytes[0..4], &[0xFF, 0xFF, 0xFF, 0xFF]);
+
+        destroy_engine(handle);
+    }
+
+    #[cfg(feature = "arrow-export")]
+    #[test]
+    fn test_export_audit_arrow_empty_trail_is_empty_bytes() {
+        let handle = create_engine();
+        let bytes = export_audit_arrow(handle, "{}");
+        assert!(bytes.is_empty());
+        destroy_engine(handle);
+    }
+
+    #[cfg(feature = "arrow-export")]
+    #[test]
+    fn test_audit_arrow_schema_lists_all_columns() {
+        let schema = audit_arrow_schema();
+        let value: serde_json::Value =
+            serde_json::from_str(&schema).expect("schema descriptor should be valid JSON");
+        let names: Vec<&str> = value
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["timestamp", "agent_id", "action", "scope", "permitted", "reason", "cost"]
+        );
+    }
 }
@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: BSL-1.1
+// Copyright (c) 2026 MuVeraAI Corporation
+
+//! In-process OpenTelemetry-shaped metrics and spans for the `evaluate` path.
+//!
+//! Only compiled when the `telemetry` feature is enabled. Unlike
+//! `aumos-governance-cf`'s `telemetry` module -- which pushes to a live OTLP
+//! collector via `opentelemetry-otlp` -- a WASM module running in a browser
+//! or edge worker has no background exporter thread and no gRPC transport.
+//! So this module accumulates counters and spans in memory and hands them
+//! back to the JS host on demand via [`drain_telemetry`](crate::drain_telemetry),
+//! which the host can `fetch()`/`POST` to a collector itself. Same shape of
+//! data as the CF middleware's instrumentation, pull instead of push.
+//!
+//! Span and counter state is bounded: [`Telemetry::record_evaluation`] keeps
+//! at most [`MAX_SPANS`] spans, dropping the oldest, so a long-lived engine
+//! handle that never drains its telemetry cannot grow this buffer without
+//! bound.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+use aumos_governance_core::types::Decision;
+
+/// Maximum number of spans buffered between drains. Oldest spans are
+/// dropped once this bound is reached.
+const MAX_SPANS: usize = 256;
+
+/// One buffered span covering a single `evaluate` call.
+struct SpanRecord {
+    action: String,
+    agent_id: String,
+    outcome: &'static str,
+    gate: &'static str,
+    start_ms: f64,
+    end_ms: f64,
+}
+
+/// Accumulates evaluation counters and spans for one engine handle, drained
+/// on demand into an OTLP-JSON payload.
+#[derive(Default)]
+pub struct Telemetry {
+    evaluations_total: u64,
+    denials_by_reason: HashMap<&'static str, u64>,
+    budget_rejections: u64,
+    spans: VecDeque<SpanRecord>,
+}
+
+impl Telemetry {
+    /// Record one completed `evaluate` call: increments `evaluations_total`,
+    /// and on denial increments `denials_by_reason` (and `budget_rejections`
+    /// when the budget gate was the cause), then buffers a span describing
+    /// the call.
+    pub fn record_evaluation(
+        &mut self,
+        action: &str,
+        agent_id: &str,
+        decision: &Decision,
+        start_ms: f64,
+        end_ms: f64,
+    ) {
+        self.evaluations_total += 1;
+
+        let (outcome, gate) = if decision.permitted {
+            ("permit", "none")
+        } else if !decision.trust.permitted {
+            ("deny", "trust")
+        } else if !decision.budget.permitted {
+            ("deny", "budget")
+        } else {
+            ("deny", "consent")
+        };
+
+        if outcome == "deny" {
+            *self.denials_by_reason.entry(gate).or_insert(0) += 1;
+            if gate == "budget" {
+                self.budget_rejections += 1;
+            }
+        }
+
+        if self.spans.len() >= MAX_SPANS {
+            self.spans.pop_front();
+        }
+        self.spans.push_back(SpanRecord {
+            action: action.to_string(),
+            agent_id: agent_id.to_string(),
+            outcome,
+            gate,
+            start_ms,
+            end_ms,
+        });
+    }
+
+    /// Drain all accumulated counters and spans, resetting this instance to
+    /// empty, and return them as an OTLP-JSON payload (`resource` +
+    /// `scopeMetrics` + `scopeSpans`) a JS host can POST to a collector.
+    pub fn drain(&mut self) -> String {
+        let evaluations_total = self.evaluations_total;
+        let budget_rejections = self.budget_rejections;
+        let denials_by_reason = std::mem::take(&mut self.denials_by_reason);
+        let spans = std::mem::take(&mut self.spans);
+        self.evaluations_total = 0;
+        self.budget_rejections = 0;
+
+        let denial_data_points: Vec<serde_json::Value> = denials_by_reason
+            .iter()
+            .map(|(reason, count)| {
+                serde_json::json!({
+                    "asInt": count.to_string(),
+                    "attributes": [{"key": "reason", "value": {"stringValue": reason}}],
+                })
+            })
+            .collect();
+
+        let metrics = serde_json::json!([
+            {
+                "name": "governance.evaluations_total",
+                "sum": {
+                    "isMonotonic": true,
+                    "aggregationTemporality": 2,
+                    "dataPoints": [{"asInt": evaluations_total.to_string(), "attributes": []}],
+                },
+            },
+            {
+                "name": "governance.denials_by_reason",
+                "sum": {
+                    "isMonotonic": true,
+                    "aggregationTemporality": 2,
+                    "dataPoints": denial_data_points,
+                },
+            },
+            {
+                "name": "governance.budget_rejections",
+                "sum": {
+                    "isMonotonic": true,
+                    "aggregationTemporality": 2,
+                    "dataPoints": [{"asInt": budget_rejections.to_string(), "attributes": []}],
+                },
+            },
+        ]);
+
+        let span_values: Vec<serde_json::Value> = spans
+            .iter()
+            .map(|span| {
+                serde_json::json!({
+                    "name": format!("governance.evaluate:{}", span.action),
+                    "startTimeUnixNano": (span.start_ms * 1_000_000.0) as u64,
+                    "endTimeUnixNano": (span.end_ms * 1_000_000.0) as u64,
+                    "attributes": [
+                        {"key": "agent_id", "value": {"stringValue": span.agent_id}},
+                        {"key": "action", "value": {"stringValue": span.action}},
+                        {"key": "outcome", "value": {"stringValue": span.outcome}},
+                        {"key": "gate", "value": {"stringValue": span.gate}},
+                    ],
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "resource": {
+                "attributes": [
+                    {"key": "service.name", "value": {"stringValue": "aumos-governance-wasm"}},
+                ],
+            },
+            "scopeMetrics": [
+                {"scope": {"name": "aumos-governance-wasm"}, "metrics": metrics},
+            ],
+            "scopeSpans": [
+                {"scope": {"name": "aumos-governance-wasm"}, "spans": span_values},
+            ],
+        })
+        .to_string()
+    }
+}
@@ -73,6 +73,7 @@ fn build_engine() -> GovernanceEngine<InMemoryStorage> {
         default_observer_on_missing: false,
         pass_on_missing_envelope: true,
         require_consent: false,
+        ..Config::default()
     };
 
     let mut engine = GovernanceEngine::new(config, storage);
@@ -63,7 +63,7 @@ fn main() {
     // -----------------------------------------------------------------------
     // 4. Record consent
     // -----------------------------------------------------------------------
-    engine.consent.record("agent-finance-001", "process_pii");
+    engine.consent.record("agent-finance-001", "process_pii", None, None);
     println!("Consent recorded: agent-finance-001 → process_pii\n");
 
     // -----------------------------------------------------------------------